@@ -0,0 +1,135 @@
+//! Runtime selection of Base64 encodings.
+
+use crate::{
+    variant::Variant, Base64, Base64Bcrypt, Base64Crypt, Base64Unpadded, Base64Url,
+    Base64UrlUnpadded, Encoding, Error, InvalidLengthError, StrictError,
+};
+use core::{fmt::Debug, marker::PhantomData};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Object-safe counterpart to [`Encoding`].
+///
+/// Allows selecting a Base64 variant at runtime, e.g. for formats which
+/// declare their encoding by name. Use [`encoding_by_name`] to look up an
+/// encoding.
+pub trait DynEncoding: Debug + Send + Sync {
+    /// Name this encoding is registered under.
+    fn name(&self) -> &'static str;
+
+    /// Is this encoding padded?
+    fn is_padded(&self) -> bool;
+
+    /// Decode a Base64 string into the provided destination buffer.
+    ///
+    /// See [`Encoding::decode`].
+    fn decode<'o>(&self, src: &[u8], dst: &'o mut [u8]) -> Result<&'o [u8], Error>;
+
+    /// Decode a Base64 string into the provided destination buffer, rejecting
+    /// any input which is not the canonical encoding of the decoded bytes.
+    ///
+    /// See [`Encoding::decode_strict`].
+    fn decode_strict<'o>(&self, src: &[u8], dst: &'o mut [u8]) -> Result<&'o [u8], StrictError>;
+
+    /// Decode a Base64 string into a byte vector.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn decode_vec(&self, input: &str) -> Result<Vec<u8>, Error>;
+
+    /// Encode the input byte slice as Base64.
+    ///
+    /// See [`Encoding::encode`].
+    fn encode<'o>(&self, src: &[u8], dst: &'o mut [u8]) -> Result<&'o str, InvalidLengthError>;
+
+    /// Encode input byte slice into a [`String`] containing Base64.
+    ///
+    /// # Panics
+    /// If `input` length is greater than `usize::MAX/4`.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn encode_string(&self, input: &[u8]) -> String;
+
+    /// Get the length of Base64 produced by encoding the given bytes.
+    ///
+    /// WARNING: this function will return `0` for lengths greater than `usize::MAX/4`!
+    fn encoded_len(&self, bytes: &[u8]) -> usize;
+}
+
+/// Look up a Base64 encoding by name.
+///
+/// # Supported names
+/// - `standard`: [`Base64`]
+/// - `standard-unpadded`: [`Base64Unpadded`]
+/// - `url`: [`Base64Url`]
+/// - `url-unpadded`: [`Base64UrlUnpadded`]
+/// - `bcrypt`: [`Base64Bcrypt`]
+/// - `crypt`: [`Base64Crypt`]
+pub fn encoding_by_name(name: &str) -> Option<&'static dyn DynEncoding> {
+    ENCODINGS
+        .iter()
+        .copied()
+        .find(|encoding| encoding.name() == name)
+}
+
+/// Registered encodings.
+static ENCODINGS: &[&dyn DynEncoding] = &[
+    &Named::<Base64>::new("standard"),
+    &Named::<Base64Unpadded>::new("standard-unpadded"),
+    &Named::<Base64Url>::new("url"),
+    &Named::<Base64UrlUnpadded>::new("url-unpadded"),
+    &Named::<Base64Bcrypt>::new("bcrypt"),
+    &Named::<Base64Crypt>::new("crypt"),
+];
+
+/// [`Encoding`] registered under a name.
+#[derive(Debug)]
+struct Named<E> {
+    name: &'static str,
+    encoding: PhantomData<E>,
+}
+
+impl<E> Named<E> {
+    const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            encoding: PhantomData,
+        }
+    }
+}
+
+impl<E: Variant> DynEncoding for Named<E> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn is_padded(&self) -> bool {
+        E::PADDED
+    }
+
+    fn decode<'o>(&self, src: &[u8], dst: &'o mut [u8]) -> Result<&'o [u8], Error> {
+        E::decode(src, dst)
+    }
+
+    fn decode_strict<'o>(&self, src: &[u8], dst: &'o mut [u8]) -> Result<&'o [u8], StrictError> {
+        E::decode_strict(src, dst)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn decode_vec(&self, input: &str) -> Result<Vec<u8>, Error> {
+        E::decode_vec(input)
+    }
+
+    fn encode<'o>(&self, src: &[u8], dst: &'o mut [u8]) -> Result<&'o str, InvalidLengthError> {
+        E::encode(src, dst)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn encode_string(&self, input: &[u8]) -> String {
+        E::encode_string(input)
+    }
+
+    fn encoded_len(&self, bytes: &[u8]) -> usize {
+        E::encoded_len(bytes)
+    }
+}
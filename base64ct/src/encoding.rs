@@ -1,7 +1,7 @@
 //! Base64 encodings
 
 use crate::{
-    errors::{Error, InvalidEncodingError, InvalidLengthError},
+    errors::{Error, InvalidEncodingError, InvalidLengthError, StrictError},
     variant::Variant,
 };
 use core::{fmt::Debug, str};
@@ -20,6 +20,15 @@ pub trait Encoding: 'static + Copy + Debug + Eq + Send + Sized + Sync {
     /// Decode a Base64 string into the provided destination buffer.
     fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error>;
 
+    /// Decode a Base64 string into the provided destination buffer, rejecting
+    /// any input which is not the canonical encoding of the decoded bytes.
+    ///
+    /// In addition to the checks performed by [`Encoding::decode`], this
+    /// ensures the unused trailing bits of the final quantum are zero (also
+    /// for unpadded variants) and reports missing or extra padding as
+    /// distinct errors.
+    fn decode_strict(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], StrictError>;
+
     /// Decode a Base64 string in-place.
     ///
     /// NOTE: this method does not (yet) validate that padding is well-formed,
@@ -31,6 +40,14 @@ pub trait Encoding: 'static + Copy + Debug + Eq + Send + Sized + Sync {
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     fn decode_vec(input: &str) -> Result<Vec<u8>, Error>;
 
+    /// Decode a Base64 string into a byte vector, rejecting any input which
+    /// is not the canonical encoding of the decoded bytes.
+    ///
+    /// See [`Encoding::decode_strict`] for the checks performed.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn decode_vec_strict(input: &str) -> Result<Vec<u8>, StrictError>;
+
     /// Encode the input byte slice as Base64.
     ///
     /// Writes the result into the provided destination slice, returning an
@@ -53,42 +70,42 @@ pub trait Encoding: 'static + Copy + Debug + Eq + Send + Sized + Sync {
 
 impl<T: Variant> Encoding for T {
     fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
-        let (src_unpadded, mut err) = if T::PADDED {
+        let (src_unpadded, err) = if T::PADDED {
             let (unpadded_len, e) = decode_padding(src.as_ref())?;
             (&src.as_ref()[..unpadded_len], e)
         } else {
             (src.as_ref(), 0)
         };
 
-        let dlen = decoded_len(src_unpadded.len());
-
-        if dlen > dst.len() {
-            return Err(Error::InvalidLength);
-        }
+        let dst = decode_unpadded::<T>(src_unpadded, dst, err)?;
+        validate_padding::<T>(src.as_ref(), dst)?;
+        Ok(dst)
+    }
 
-        let dst = &mut dst[..dlen];
+    fn decode_strict(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], StrictError> {
+        let src = src.as_ref();
+        let unpadded_len = src.iter().rposition(|&b| b != PAD).map_or(0, |pos| pos + 1);
+        let (src_unpadded, padding) = src.split_at(unpadded_len);
 
-        let mut src_chunks = src_unpadded.chunks_exact(4);
-        let mut dst_chunks = dst.chunks_exact_mut(3);
-        for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
-            err |= Self::decode_3bytes(s, d);
+        if unpadded_len % 4 == 1 {
+            return Err(StrictError::InvalidEncoding);
         }
-        let src_rem = src_chunks.remainder();
-        let dst_rem = dst_chunks.into_remainder();
-
-        err |= !(src_rem.is_empty() || src_rem.len() >= 2) as i16;
-        let mut tmp_out = [0u8; 3];
-        let mut tmp_in = [b'A'; 4];
-        tmp_in[..src_rem.len()].copy_from_slice(src_rem);
-        err |= Self::decode_3bytes(&tmp_in, &mut tmp_out);
-        dst_rem.copy_from_slice(&tmp_out[..dst_rem.len()]);
 
-        if err == 0 {
-            validate_padding::<T>(src.as_ref(), dst)?;
-            Ok(dst)
+        let expected_padding = if T::PADDED {
+            (4 - unpadded_len % 4) % 4
         } else {
-            Err(Error::InvalidEncoding)
+            0
+        };
+
+        if padding.len() < expected_padding {
+            return Err(StrictError::MissingPadding);
+        } else if padding.len() > expected_padding {
+            return Err(StrictError::ExtraPadding);
         }
+
+        let dst = decode_unpadded::<T>(src_unpadded, dst, 0)?;
+        validate_final_quantum::<T>(src_unpadded, dst)?;
+        Ok(dst)
     }
 
     fn decode_in_place(mut buf: &mut [u8]) -> Result<&[u8], InvalidEncodingError> {
@@ -168,6 +185,14 @@ impl<T: Variant> Encoding for T {
         }
     }
 
+    #[cfg(feature = "alloc")]
+    fn decode_vec_strict(input: &str) -> Result<Vec<u8>, StrictError> {
+        let mut output = vec![0u8; decoded_len(input.len())];
+        let len = Self::decode_strict(input, &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+
     fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, InvalidLengthError> {
         let elen = match encoded_len_inner(src.len(), T::PADDED) {
             Some(v) => v,
@@ -234,6 +259,46 @@ impl<T: Variant> Encoding for T {
     }
 }
 
+/// Decode the given *unpadded* Base64 into the provided destination buffer.
+///
+/// The `err` argument carries data-dependent errors from prior steps (i.e.
+/// [`decode_padding`]) so they can be combined before branching.
+#[inline(always)]
+fn decode_unpadded<'a, T: Variant>(
+    src_unpadded: &[u8],
+    dst: &'a mut [u8],
+    mut err: i16,
+) -> Result<&'a [u8], Error> {
+    let dlen = decoded_len(src_unpadded.len());
+
+    if dlen > dst.len() {
+        return Err(Error::InvalidLength);
+    }
+
+    let dst = &mut dst[..dlen];
+
+    let mut src_chunks = src_unpadded.chunks_exact(4);
+    let mut dst_chunks = dst.chunks_exact_mut(3);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        err |= T::decode_3bytes(s, d);
+    }
+    let src_rem = src_chunks.remainder();
+    let dst_rem = dst_chunks.into_remainder();
+
+    err |= !(src_rem.is_empty() || src_rem.len() >= 2) as i16;
+    let mut tmp_out = [0u8; 3];
+    let mut tmp_in = [b'A'; 4];
+    tmp_in[..src_rem.len()].copy_from_slice(src_rem);
+    err |= T::decode_3bytes(&tmp_in, &mut tmp_out);
+    dst_rem.copy_from_slice(&tmp_out[..dst_rem.len()]);
+
+    if err == 0 {
+        Ok(dst)
+    } else {
+        Err(Error::InvalidEncoding)
+    }
+}
+
 /// Validate padding is of the expected length compute unpadded length.
 ///
 /// Note that this method does not explicitly check that the padded data
@@ -314,6 +379,36 @@ fn validate_padding<T: Variant>(encoded: &[u8], decoded: &[u8]) -> Result<(), Er
     }
 }
 
+/// Check that the final (partial) quantum of an *unpadded* Base64 string is
+/// the canonical encoding of the decoded bytes, i.e. that its unused trailing
+/// bits are zero.
+fn validate_final_quantum<T: Variant>(encoded: &[u8], decoded: &[u8]) -> Result<(), StrictError> {
+    let rem = decoded.len() % 3;
+
+    if rem == 0 {
+        return Ok(());
+    }
+
+    let encoded = encoded
+        .get(encoded.len() - (rem + 1)..)
+        .ok_or(StrictError::InvalidEncoding)?;
+
+    let mut buf = [0u8; 4];
+    T::encode(&decoded[decoded.len() - rem..], &mut buf)?;
+
+    // Non-short-circuiting comparison of the final quantum
+    if encoded
+        .iter()
+        .zip(buf.iter())
+        .fold(0, |acc, (a, b)| acc | (a ^ b))
+        == 0
+    {
+        Ok(())
+    } else {
+        Err(StrictError::NonCanonical)
+    }
+}
+
 /// Get the length of the output from decoding the provided *unpadded*
 /// Base64-encoded input (use [`unpadded_len_ct`] to compute this value for
 /// a padded input)
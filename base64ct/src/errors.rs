@@ -4,6 +4,9 @@ use core::fmt;
 
 const INVALID_ENCODING_MSG: &str = "invalid Base64 encoding";
 const INVALID_LENGTH_MSG: &str = "invalid Base64 length";
+const MISSING_PADDING_MSG: &str = "missing Base64 padding";
+const EXTRA_PADDING_MSG: &str = "extra Base64 padding";
+const NON_CANONICAL_MSG: &str = "non-canonical Base64 encoding";
 
 /// Insufficient output buffer length.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -72,6 +75,72 @@ impl From<core::str::Utf8Error> for Error {
     }
 }
 
+/// Errors returned by [`Encoding::decode_strict`][`crate::Encoding::decode_strict`].
+///
+/// Distinguishes the ways in which an otherwise decodable Base64 string can
+/// fail to be the canonical encoding of its contents.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StrictError {
+    /// Invalid encoding of provided Base64 string.
+    InvalidEncoding,
+
+    /// Insufficient output buffer length.
+    InvalidLength,
+
+    /// Padded encoding is missing some or all of its padding.
+    MissingPadding,
+
+    /// Encoding has more padding than required, or padding where none is
+    /// expected (i.e. for unpadded variants).
+    ExtraPadding,
+
+    /// Unused trailing bits of the final quantum are nonzero.
+    NonCanonical,
+}
+
+impl fmt::Display for StrictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let s = match self {
+            Self::InvalidEncoding => INVALID_ENCODING_MSG,
+            Self::InvalidLength => INVALID_LENGTH_MSG,
+            Self::MissingPadding => MISSING_PADDING_MSG,
+            Self::ExtraPadding => EXTRA_PADDING_MSG,
+            Self::NonCanonical => NON_CANONICAL_MSG,
+        };
+        f.write_str(s)
+    }
+}
+
+impl From<Error> for StrictError {
+    #[inline]
+    fn from(err: Error) -> StrictError {
+        match err {
+            Error::InvalidEncoding => StrictError::InvalidEncoding,
+            Error::InvalidLength => StrictError::InvalidLength,
+        }
+    }
+}
+
+impl From<InvalidLengthError> for StrictError {
+    #[inline]
+    fn from(_: InvalidLengthError) -> StrictError {
+        StrictError::InvalidLength
+    }
+}
+
+impl From<StrictError> for Error {
+    #[inline]
+    fn from(err: StrictError) -> Error {
+        match err {
+            StrictError::InvalidLength => Error::InvalidLength,
+            _ => Error::InvalidEncoding,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StrictError {}
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl From<Error> for std::io::Error {
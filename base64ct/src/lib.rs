@@ -72,6 +72,7 @@ extern crate alloc;
 extern crate std;
 
 mod decoder;
+mod dyn_encoding;
 mod encoder;
 mod encoding;
 mod errors;
@@ -83,9 +84,10 @@ mod test_vectors;
 
 pub use crate::{
     decoder::Decoder,
+    dyn_encoding::{encoding_by_name, DynEncoding},
     encoder::Encoder,
     encoding::Encoding,
-    errors::{Error, InvalidEncodingError, InvalidLengthError},
+    errors::{Error, InvalidEncodingError, InvalidLengthError, StrictError},
     line_ending::LineEnding,
     variant::{
         bcrypt::Base64Bcrypt,
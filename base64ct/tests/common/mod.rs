@@ -37,6 +37,9 @@ macro_rules! impl_tests {
                 let out = <$encoding>::decode(vector.b64, &mut buf).unwrap();
                 assert_eq!(vector.raw, &out[..]);
 
+                let out = <$encoding>::decode_strict(vector.b64, &mut buf).unwrap();
+                assert_eq!(vector.raw, &out[..]);
+
                 let n = vector.b64.len();
                 buf[..n].copy_from_slice(vector.b64.as_bytes());
                 let out = <$encoding>::decode_in_place(&mut buf[..n]).unwrap();
@@ -46,6 +49,9 @@ macro_rules! impl_tests {
                 {
                     let out = <$encoding>::decode_vec(vector.b64).unwrap();
                     assert_eq!(vector.raw, &out[..]);
+
+                    let out = <$encoding>::decode_vec_strict(vector.b64).unwrap();
+                    assert_eq!(vector.raw, &out[..]);
                 }
             }
         }
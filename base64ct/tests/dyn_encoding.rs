@@ -0,0 +1,66 @@
+//! Runtime-selected encoding tests
+
+use base64ct::{encoding_by_name, Base64Bcrypt, Base64Url, Encoding};
+
+#[test]
+fn lookup_by_name() {
+    for name in [
+        "standard",
+        "standard-unpadded",
+        "url",
+        "url-unpadded",
+        "bcrypt",
+        "crypt",
+    ] {
+        assert_eq!(encoding_by_name(name).unwrap().name(), name);
+    }
+
+    assert!(encoding_by_name("Standard").is_none());
+    assert!(encoding_by_name("base32").is_none());
+}
+
+#[test]
+fn padding() {
+    assert!(encoding_by_name("standard").unwrap().is_padded());
+    assert!(encoding_by_name("url").unwrap().is_padded());
+    assert!(!encoding_by_name("url-unpadded").unwrap().is_padded());
+    assert!(!encoding_by_name("bcrypt").unwrap().is_padded());
+}
+
+#[test]
+fn matches_static_encoding() {
+    let bytes = b"\xFF\xEF\xFE\xFF\xEF";
+    let mut buf = [0u8; 16];
+    let mut static_buf = [0u8; 16];
+
+    let encoding = encoding_by_name("url").unwrap();
+    let encoded = encoding.encode(bytes, &mut buf).unwrap();
+    assert_eq!(encoded, Base64Url::encode(bytes, &mut static_buf).unwrap());
+    assert_eq!(encoding.encoded_len(bytes), encoded.len());
+
+    let mut decoded = [0u8; 5];
+    let encoded = encoded.as_bytes().to_vec();
+    assert_eq!(encoding.decode(&encoded, &mut decoded).unwrap(), bytes);
+    assert_eq!(
+        encoding.decode_strict(&encoded, &mut decoded).unwrap(),
+        bytes
+    );
+
+    let encoding = encoding_by_name("bcrypt").unwrap();
+    assert_eq!(
+        encoding.encode(bytes, &mut buf).unwrap(),
+        Base64Bcrypt::encode(bytes, &mut static_buf).unwrap()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn allocating() {
+    let encoding = encoding_by_name("standard").unwrap();
+    let encoded = encoding.encode_string(b"example bytestring!");
+    assert_eq!(encoded, "ZXhhbXBsZSBieXRlc3RyaW5nIQ==");
+    assert_eq!(
+        encoding.decode_vec(&encoded).unwrap(),
+        b"example bytestring!"
+    );
+}
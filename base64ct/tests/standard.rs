@@ -6,7 +6,7 @@ mod common;
 /// Standard Base64 with `=` padding
 mod padded {
     use crate::common::*;
-    use base64ct::Base64;
+    use base64ct::{Base64, StrictError};
 
     const TEST_VECTORS: &[TestVector] = &[
         TestVector { raw: b"", b64: "" },
@@ -62,12 +62,84 @@ mod padded {
         let mut buf = [0u8; 1024];
         assert_eq!(Base64::decode(input, &mut buf), Err(Error::InvalidEncoding));
     }
+
+    #[test]
+    fn strict_reject_non_canonical() {
+        let mut buf = [0u8; 1024];
+        assert_eq!(
+            Base64::decode_strict("AB==", &mut buf),
+            Err(StrictError::NonCanonical)
+        );
+        assert_eq!(
+            Base64::decode_strict("AQIDBB==", &mut buf),
+            Err(StrictError::NonCanonical)
+        );
+        assert_eq!(
+            Base64::decode_strict("ra2tra1=", &mut buf),
+            Err(StrictError::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn strict_reject_missing_padding() {
+        let mut buf = [0u8; 1024];
+        assert_eq!(
+            Base64::decode_strict("AA", &mut buf),
+            Err(StrictError::MissingPadding)
+        );
+        assert_eq!(
+            Base64::decode_strict("AA=", &mut buf),
+            Err(StrictError::MissingPadding)
+        );
+        assert_eq!(
+            Base64::decode_strict("ra2tra0", &mut buf),
+            Err(StrictError::MissingPadding)
+        );
+    }
+
+    #[test]
+    fn strict_reject_extra_padding() {
+        let mut buf = [0u8; 1024];
+        assert_eq!(
+            Base64::decode_strict("AA===", &mut buf),
+            Err(StrictError::ExtraPadding)
+        );
+        assert_eq!(
+            Base64::decode_strict("Kioq====", &mut buf),
+            Err(StrictError::ExtraPadding)
+        );
+        assert_eq!(
+            Base64::decode_strict("====", &mut buf),
+            Err(StrictError::ExtraPadding)
+        );
+    }
+
+    #[test]
+    fn strict_reject_invalid_encoding() {
+        let mut buf = [0u8; 1024];
+        assert_eq!(
+            Base64::decode_strict("A===", &mut buf),
+            Err(StrictError::InvalidEncoding)
+        );
+        assert_eq!(
+            Base64::decode_strict("AA=A", &mut buf),
+            Err(StrictError::InvalidEncoding)
+        );
+        assert_eq!(
+            Base64::decode_strict("Kioq\n", &mut buf),
+            Err(StrictError::InvalidEncoding)
+        );
+        assert_eq!(
+            Base64::decode_strict("Kioq", &mut [0u8; 2]),
+            Err(StrictError::InvalidLength)
+        );
+    }
 }
 
 /// Standard Base64 *without* padding
 mod unpadded {
     use crate::common::*;
-    use base64ct::Base64Unpadded;
+    use base64ct::{Base64Unpadded, StrictError};
 
     const TEST_VECTORS: &[TestVector] = &[
         TestVector { raw: b"", b64: "" },
@@ -129,4 +201,33 @@ mod unpadded {
             Err(Error::InvalidEncoding)
         );
     }
+
+    #[test]
+    fn strict_reject_non_canonical() {
+        let mut buf = [0u8; 1024];
+
+        // Accepted by the non-strict decoder
+        assert_eq!(Base64Unpadded::decode("AB", &mut buf), Ok(&[0u8][..]));
+        assert_eq!(
+            Base64Unpadded::decode_strict("AB", &mut buf),
+            Err(StrictError::NonCanonical)
+        );
+        assert_eq!(
+            Base64Unpadded::decode_strict("ra2tra1", &mut buf),
+            Err(StrictError::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn strict_reject_padding() {
+        let mut buf = [0u8; 1024];
+        assert_eq!(
+            Base64Unpadded::decode_strict("AA==", &mut buf),
+            Err(StrictError::ExtraPadding)
+        );
+        assert_eq!(
+            Base64Unpadded::decode_strict("ra2tra0=", &mut buf),
+            Err(StrictError::ExtraPadding)
+        );
+    }
 }
@@ -29,6 +29,33 @@ pub use crate::{
 use crate::encoder::Encoder;
 use core::{fmt, str::FromStr};
 
+/// Parse an [`ObjectIdentifier`] from the dot-delimited string form at
+/// compile time.
+///
+/// The OID is always evaluated in a const context, so an invalid OID results
+/// in a compile error rather than a run-time panic, even when the macro is
+/// used outside of a `const` item:
+///
+/// ```
+/// use const_oid::{oid, ObjectIdentifier};
+///
+/// pub const MY_OID: ObjectIdentifier = oid!("1.2.840.113549.1.1.1");
+///
+/// let ec_public_key = oid!("1.2.840.10045.2.1");
+/// assert_eq!(ec_public_key.to_string(), "1.2.840.10045.2.1");
+/// ```
+///
+/// ```compile_fail
+/// let invalid = const_oid::oid!("1.2.840.");
+/// ```
+#[macro_export]
+macro_rules! oid {
+    ($oid:expr) => {{
+        const OID: $crate::ObjectIdentifier = $crate::ObjectIdentifier::new_unwrap($oid);
+        OID
+    }};
+}
+
 /// A trait which associates an OID with a type.
 pub trait AssociatedOid {
     /// The OID associated with this type.
@@ -80,7 +107,8 @@ impl ObjectIdentifier {
     /// In future versions of Rust it should be possible to replace this with
     /// `ObjectIdentifier::new(...).unwrap()`.
     ///
-    /// Use [`ObjectIdentifier::new`] for fallible parsing.
+    /// See also the [`oid!`] macro, which ensures the OID is parsed at compile
+    /// time. Use [`ObjectIdentifier::new`] for fallible parsing.
     // TODO(tarcieri): remove this when `Result::unwrap` is `const fn`
     pub const fn new_unwrap(s: &str) -> Self {
         match Self::new(s) {
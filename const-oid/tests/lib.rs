@@ -28,6 +28,13 @@ const EXAMPLE_OID_LARGE_ARC_BER: &[u8] = &hex!("0992268993F22C640101");
 const EXAMPLE_OID_LARGE_ARC: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("0.9.2342.19200300.100.1.1");
 
+#[test]
+fn oid_macro() {
+    const OID: ObjectIdentifier = const_oid::oid!("1.2.840.10045.2.1");
+    assert_eq!(OID, EXAMPLE_OID_1);
+    assert_eq!(const_oid::oid!(EXAMPLE_OID_2_STR), EXAMPLE_OID_2);
+}
+
 #[test]
 fn from_bytes() {
     let oid0 = ObjectIdentifier::from_bytes(EXAMPLE_OID_0_BER).unwrap();
@@ -45,6 +45,24 @@
 //! The default is `EXPLICIT`, so the attribute only needs to be added when
 //! a particular module is declared `IMPLICIT`.
 //!
+//! This makes it possible to transcribe types from modules declared with
+//! `DEFINITIONS IMPLICIT TAGS` (e.g. LDAP, Kerberos, SNMP) without annotating
+//! every `CONTEXT-SPECIFIC` field. Individual fields or variants can still
+//! override the module default using the field-level `tag_mode` attribute,
+//! e.g. for components which are explicitly tagged in the ASN.1 definition:
+//!
+//! ```ignore
+//! #[derive(Sequence)]
+//! #[asn1(tag_mode = "IMPLICIT")]
+//! pub struct Example {
+//!     #[asn1(context_specific = "0")]
+//!     pub version: u8,
+//!
+//!     #[asn1(context_specific = "1", tag_mode = "EXPLICIT")]
+//!     pub flag: bool,
+//! }
+//! ```
+//!
 //! ## Field-level attributes
 //!
 //! The following attributes can be added to either the fields of a particular
@@ -109,6 +109,12 @@ impl DeriveSequence {
                         #(#encode_body),*
                     ])
                 }
+
+                fn fields_len(&self) -> ::der::Result<::der::Length> {
+                    let mut len = ::der::Length::ZERO;
+                    #(len = (len + ::der::Encodable::encoded_len(#encode_body)?)?;)*
+                    Ok(len)
+                }
             }
         }
     }
@@ -3,46 +3,72 @@
 
 mod any;
 mod bit_string;
+mod bmp_string;
 mod boolean;
 mod choice;
 mod context_specific;
+mod date;
+mod duration;
+mod general_string;
 mod generalized_time;
+mod graphic_string;
 mod ia5_string;
 mod integer;
+mod iso_duration;
 mod null;
 mod octet_string;
 #[cfg(feature = "oid")]
 mod oid;
 mod optional;
 mod printable_string;
+mod real;
+mod relative_oid;
 mod sequence;
 mod sequence_of;
 mod set_of;
+mod teletex_string;
+mod time;
+mod time_of_day;
+mod universal_string;
 mod utc_time;
 mod utf8_string;
+mod visible_string;
 
 pub use self::{
     any::Any,
     bit_string::{BitString, BitStringIter},
+    bmp_string::{BmpChars, BmpString},
     choice::Choice,
     context_specific::{ContextSpecific, ContextSpecificRef},
+    date::Date,
+    general_string::GeneralString,
     generalized_time::GeneralizedTime,
+    graphic_string::GraphicString,
     ia5_string::Ia5String,
     integer::bigint::UIntBytes,
+    iso_duration::IsoDuration,
     null::Null,
     octet_string::OctetString,
     optional::OptionalRef,
     printable_string::PrintableString,
+    relative_oid::{RelativeOid, RelativeOidArcs},
     sequence::{Sequence, SequenceRef},
     sequence_of::{SequenceOf, SequenceOfIter},
     set_of::{SetOf, SetOfIter},
+    teletex_string::{TeletexChars, TeletexString},
+    time::Time,
+    time_of_day::TimeOfDay,
+    universal_string::{UniversalChars, UniversalString},
     utc_time::UtcTime,
     utf8_string::Utf8String,
+    visible_string::VisibleString,
 };
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub use self::set_of::SetOfVec;
+pub use self::{
+    any::AnyOwned, bit_string::BitStringOwned, octet_string::OctetStringOwned, set_of::SetOfVec,
+};
 
 #[cfg(feature = "oid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
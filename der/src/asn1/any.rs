@@ -6,6 +6,9 @@ use crate::{
 };
 use core::cmp::Ordering;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "oid")]
 use crate::asn1::ObjectIdentifier;
 
@@ -18,6 +21,13 @@ use crate::asn1::ObjectIdentifier;
 /// Nevertheless, this crate defines an [`Any`] type as it remains a familiar
 /// and useful concept which is still extensively used in things like
 /// PKI-related RFCs.
+///
+/// The value of an [`Any`] is retained verbatim and is never interpreted, so
+/// re-encoding a decoded [`Any`] reproduces its original TLV bytes exactly,
+/// even if the value itself is not a canonical DER encoding of its type.
+/// This makes it safe to pass signature-covered data (e.g. attributes or
+/// extensions) through [`Any`] fields. The tag and length are always
+/// reproduced exactly since the decoder only accepts their DER encoding.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Any<'a> {
     /// Tag representing the type of the encoded value.
@@ -76,6 +86,11 @@ impl<'a> Any<'a> {
         self.try_into()
     }
 
+    /// Attempt to decode an ASN.1 `BMPString`.
+    pub fn bmp_string(self) -> Result<BmpString<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode an ASN.1 `CONTEXT-SPECIFIC` field.
     pub fn context_specific<T>(self) -> Result<ContextSpecific<T>>
     where
@@ -84,11 +99,21 @@ impl<'a> Any<'a> {
         self.try_into()
     }
 
+    /// Attempt to decode an ASN.1 `GeneralString`.
+    pub fn general_string(self) -> Result<GeneralString<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode an ASN.1 `GeneralizedTime`.
     pub fn generalized_time(self) -> Result<GeneralizedTime> {
         self.try_into()
     }
 
+    /// Attempt to decode an ASN.1 `GraphicString`.
+    pub fn graphic_string(self) -> Result<GraphicString<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode an ASN.1 `IA5String`.
     pub fn ia5_string(self) -> Result<Ia5String<'a>> {
         self.try_into()
@@ -123,6 +148,11 @@ impl<'a> Any<'a> {
         self.try_into()
     }
 
+    /// Attempt to decode an ASN.1 `RELATIVE-OID`.
+    pub fn relative_oid(self) -> Result<RelativeOid<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode this value an ASN.1 `SEQUENCE`, creating a new
     /// nested [`Decoder`] and calling the provided argument with it.
     pub fn sequence<F, T>(self, f: F) -> Result<T>
@@ -135,6 +165,21 @@ impl<'a> Any<'a> {
         seq_decoder.finish(result)
     }
 
+    /// Attempt to decode an ASN.1 `TeletexString`.
+    pub fn teletex_string(self) -> Result<TeletexString<'a>> {
+        self.try_into()
+    }
+
+    /// Attempt to decode an ASN.1 `TIME`.
+    pub fn time(self) -> Result<Time<'a>> {
+        self.try_into()
+    }
+
+    /// Attempt to decode an ASN.1 `UniversalString`.
+    pub fn universal_string(self) -> Result<UniversalString<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode an ASN.1 `UTCTime`.
     pub fn utc_time(self) -> Result<UtcTime> {
         self.try_into()
@@ -144,6 +189,11 @@ impl<'a> Any<'a> {
     pub fn utf8_string(self) -> Result<Utf8String<'a>> {
         self.try_into()
     }
+
+    /// Attempt to decode an ASN.1 `VisibleString`.
+    pub fn visible_string(self) -> Result<VisibleString<'a>> {
+        self.try_into()
+    }
 }
 
 impl<'a> Choice<'a> for Any<'a> {
@@ -197,3 +247,196 @@ impl<'a> TryFrom<&'a [u8]> for Any<'a> {
         Any::from_der(bytes)
     }
 }
+
+/// ASN.1 `ANY` which owns its value.
+///
+/// Counterpart to [`Any`] which stores a copy of the value on the heap, so it
+/// isn't bound to the lifetime of the buffer it was decoded from and can e.g.
+/// be sent to another thread. Use [`AnyOwned::as_any`] to borrow it as an
+/// [`Any`], e.g. to decode the inner value.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct AnyOwned {
+    /// Tag representing the type of the encoded value.
+    tag: Tag,
+
+    /// Inner value encoded as bytes.
+    value: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl AnyOwned {
+    /// Create a new [`AnyOwned`] from the provided [`Tag`] and bytes.
+    pub fn new(tag: Tag, bytes: Vec<u8>) -> Result<Self> {
+        Length::try_from(bytes.len()).map_err(|_| ErrorKind::Length { tag })?;
+        Ok(Self { tag, value: bytes })
+    }
+
+    /// Get the raw value for this [`AnyOwned`] type as a byte slice.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Borrow this value as an [`Any`].
+    pub fn as_any(&self) -> Any<'_> {
+        let value = ByteSlice::new(&self.value).expect("length invariant violated");
+        Any::from_tag_and_value(self.tag, value)
+    }
+
+    /// Attempt to decode this [`AnyOwned`] type into the inner value.
+    pub fn decode_into<'a, T>(&'a self) -> Result<T>
+    where
+        T: DecodeValue<'a> + FixedTag,
+    {
+        self.as_any().decode_into()
+    }
+
+    /// Convert this [`AnyOwned`] into its raw value.
+    pub fn into_value(self) -> Vec<u8> {
+        self.value
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> Choice<'a> for AnyOwned {
+    fn can_decode(_: Tag) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> Decodable<'a> for AnyOwned {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<AnyOwned> {
+        Any::decode(decoder).map(Self::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for AnyOwned {
+    fn value_len(&self) -> Result<Length> {
+        self.as_any().value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(self.value())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Tagged for AnyOwned {
+    fn tag(&self) -> Tag {
+        self.tag
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl ValueOrd for AnyOwned {
+    fn value_cmp(&self, other: &Self) -> Result<Ordering> {
+        self.as_any().value_cmp(&other.as_any())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<Any<'_>> for AnyOwned {
+    fn from(any: Any<'_>) -> AnyOwned {
+        AnyOwned {
+            tag: any.tag,
+            value: any.value().to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> From<&'a AnyOwned> for Any<'a> {
+    fn from(any: &'a AnyOwned) -> Any<'a> {
+        any.as_any()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl TryFrom<&[u8]> for AnyOwned {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<AnyOwned> {
+        AnyOwned::from_der(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Any;
+    use crate::{Decodable, Encodable, Result, Tag, Tagged};
+    use hex_literal::hex;
+
+    /// Decode the given TLV as [`Any`] and assert it re-encodes verbatim.
+    fn assert_round_trip(tlv: &[u8]) -> Result<()> {
+        let any = Any::from_der(tlv)?;
+        let mut buf = [0u8; 32];
+        assert_eq!(tlv, any.encode_to_slice(&mut buf)?);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        assert_round_trip(&hex!("0500"))?;
+        assert_round_trip(&hex!("040401020304"))?;
+        assert_round_trip(&hex!("A1050403010203"))
+    }
+
+    #[test]
+    fn round_trip_non_canonical_value() -> Result<()> {
+        // `BOOLEAN` TRUE must be encoded as `FF` in DER
+        let any = Any::from_der(&hex!("010101"))?;
+        assert_eq!(any.tag(), Tag::Boolean);
+        assert!(any.decode_into::<bool>().is_err());
+        assert_round_trip(&hex!("010101"))?;
+
+        // `INTEGER` with a redundant leading zero
+        assert_round_trip(&hex!("02020001"))?;
+
+        // `SEQUENCE` containing both of the above
+        assert_round_trip(&hex!("300701010102020001"))
+    }
+
+    #[test]
+    fn reject_non_canonical_length() {
+        assert!(Any::from_der(&hex!("04810100")).is_err());
+        assert!(Any::from_der(&hex!("048000000000")).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_outlives_input() -> Result<()> {
+        use super::AnyOwned;
+        use crate::asn1::OctetString;
+        use alloc::vec::Vec;
+
+        fn assert_send<T: Send + 'static>(value: T) -> T {
+            value
+        }
+
+        let der = Vec::from(hex!("040401020304"));
+        let owned = assert_send(AnyOwned::from_der(&der)?);
+        drop(der);
+
+        assert_eq!(owned.tag(), Tag::OctetString);
+        assert_eq!(owned.value(), &hex!("01020304"));
+        assert_eq!(owned.to_vec()?, hex!("040401020304"));
+        assert_eq!(AnyOwned::from(owned.as_any()), owned);
+        assert_eq!(
+            owned.decode_into::<OctetString<'_>>()?.as_bytes(),
+            &hex!("01020304")
+        );
+        Ok(())
+    }
+}
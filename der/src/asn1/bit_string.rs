@@ -6,6 +6,9 @@ use crate::{
 };
 use core::{cmp::Ordering, iter::FusedIterator};
 
+#[cfg(feature = "alloc")]
+use {crate::asn1::AnyOwned, alloc::vec::Vec};
+
 /// ASN.1 `BIT STRING` type.
 ///
 /// This type contains a sequence of any number of bits, modeled internally as
@@ -293,6 +296,165 @@ where
     }
 }
 
+/// ASN.1 `BIT STRING` which owns its contents.
+///
+/// Counterpart to [`BitString`] which stores its contents on the heap, so it
+/// isn't bound to the lifetime of the buffer it was decoded from.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct BitStringOwned {
+    /// Number of unused bits in the final octet.
+    unused_bits: u8,
+
+    /// Bitstring represented as a vector of bytes.
+    inner: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl BitStringOwned {
+    /// Create a new ASN.1 `BIT STRING` from the given bytes.
+    ///
+    /// Accepts an optional number of "unused bits" (0-7) which are omitted
+    /// from the final octet. This number is 0 if the value is octet-aligned.
+    pub fn new(unused_bits: u8, bytes: Vec<u8>) -> Result<Self> {
+        BitString::new(unused_bits, &bytes)?;
+
+        Ok(Self {
+            unused_bits,
+            inner: bytes,
+        })
+    }
+
+    /// Create a new ASN.1 `BIT STRING` from the given bytes.
+    ///
+    /// The "unused bits" are set to 0.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self> {
+        Self::new(0, bytes)
+    }
+
+    /// Borrow this value as a [`BitString`].
+    pub fn as_bit_string(&self) -> BitString<'_> {
+        BitString::new(self.unused_bits, &self.inner).expect("BIT STRING invariant violated")
+    }
+
+    /// Get the number of unused bits in the final octet.
+    pub fn unused_bits(&self) -> u8 {
+        self.unused_bits
+    }
+
+    /// Is the number of unused bits a value other than 0?
+    pub fn has_unused_bits(&self) -> bool {
+        self.unused_bits != 0
+    }
+
+    /// Get the length of this `BIT STRING` in bits.
+    pub fn bit_len(&self) -> usize {
+        self.as_bit_string().bit_len()
+    }
+
+    /// Is the inner byte vector empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Borrow the inner bytes.
+    ///
+    /// Returns `None` if the number of unused bits is *not* equal to zero,
+    /// i.e. if the `BIT STRING` is not octet aligned.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        self.as_bit_string().as_bytes()
+    }
+
+    /// Borrow the raw bytes of this `BIT STRING`, including any unused bits
+    /// in the final octet.
+    pub fn raw_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+
+    /// Iterator over the bits of this `BIT STRING`.
+    pub fn bits(&self) -> BitStringIter<'_> {
+        self.as_bit_string().bits()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> DecodeValue<'a> for BitStringOwned {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        BitString::decode_value(decoder, header).map(Self::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for BitStringOwned {
+    fn value_len(&self) -> Result<Length> {
+        self.as_bit_string().value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.as_bit_string().encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl FixedTag for BitStringOwned {
+    const TAG: Tag = Tag::BitString;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl ValueOrd for BitStringOwned {
+    fn value_cmp(&self, other: &Self) -> Result<Ordering> {
+        self.as_bit_string().value_cmp(&other.as_bit_string())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<BitString<'_>> for BitStringOwned {
+    fn from(bit_string: BitString<'_>) -> BitStringOwned {
+        BitStringOwned {
+            unused_bits: bit_string.unused_bits(),
+            inner: bit_string.raw_bytes().to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> From<&'a BitStringOwned> for BitString<'a> {
+    fn from(bit_string: &'a BitStringOwned) -> BitString<'a> {
+        bit_string.as_bit_string()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl TryFrom<Any<'_>> for BitStringOwned {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<BitStringOwned> {
+        any.decode_into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl TryFrom<BitStringOwned> for AnyOwned {
+    type Error = Error;
+
+    fn try_from(bit_string: BitStringOwned) -> Result<AnyOwned> {
+        let mut value = Vec::with_capacity(bit_string.inner.len() + 1);
+        value.push(bit_string.unused_bits);
+        value.extend_from_slice(&bit_string.inner);
+        AnyOwned::new(Tag::BitString, value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{BitString, Result, Tag};
@@ -342,4 +504,26 @@ mod tests {
             Tag::BitString.value_error().kind()
         )
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_round_trip() -> Result<()> {
+        use super::BitStringOwned;
+        use crate::{Decodable, Encodable};
+        use alloc::vec::Vec;
+
+        let der = Vec::from(hex!("0304066e5dc0"));
+        let owned = BitStringOwned::from_der(&der)?;
+        drop(der);
+
+        assert_eq!(owned.unused_bits(), 6);
+        assert_eq!(owned.bit_len(), 18);
+        assert_eq!(owned.raw_bytes(), &hex!("6e5dc0"));
+        assert_eq!(owned.as_bytes(), None);
+        assert_eq!(owned.bits().filter(|&bit| bit).count(), 12);
+        assert_eq!(owned.to_vec()?, hex!("0304066e5dc0"));
+        assert_eq!(BitStringOwned::from(owned.as_bit_string()), owned);
+        assert!(BitStringOwned::new(8, Vec::from(hex!("00"))).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,244 @@
+//! ASN.1 `BMPString` support.
+
+use crate::{
+    asn1::Any, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error,
+    ErrorKind, FixedTag, Header, Length, Result, Tag,
+};
+use core::{
+    fmt::{self, Write},
+    iter::FusedIterator,
+};
+
+/// ASN.1 `BMPString` type.
+///
+/// Supports the characters of the [Basic Multilingual Plane (BMP)] of
+/// Unicode, encoded as UCS-2: two big endian octets per character. This is
+/// the same as UTF-16BE, except that surrogate pairs (and therefore
+/// characters outside of the BMP) aren't allowed.
+///
+/// `BMPString` is used by e.g. the `friendlyName` attribute of PKCS#12. For
+/// new structures, use [`Utf8String`][`crate::asn1::Utf8String`].
+///
+/// [Basic Multilingual Plane (BMP)]: https://en.wikipedia.org/wiki/Plane_(Unicode)#Basic_Multilingual_Plane
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct BmpString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> BmpString<'a> {
+    /// Create a new `BMPString` from its UCS-2 encoding.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        if input.len() % 2 != 0 {
+            return Err(Self::TAG.length_error());
+        }
+
+        // Validate all code units are characters rather than surrogates
+        if input
+            .chunks_exact(2)
+            .any(|unit| (0xD8..=0xDF).contains(&unit[0]))
+        {
+            return Err(Self::TAG.value_error());
+        }
+
+        ByteSlice::new(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Encode the given string as a `BMPString` into the provided buffer.
+    ///
+    /// Returns an error if the string contains characters outside of the
+    /// Basic Multilingual Plane, or if the buffer is too small.
+    pub fn from_str_in(s: &str, buf: &'a mut [u8]) -> Result<Self> {
+        let mut len = 0;
+
+        for c in s.chars() {
+            let unit = u16::try_from(u32::from(c)).map_err(|_| Self::TAG.value_error())?;
+            let end = len + 2;
+
+            buf.get_mut(len..end)
+                .ok_or(ErrorKind::Overlength)?
+                .copy_from_slice(&unit.to_be_bytes());
+
+            len = end;
+        }
+
+        Self::new(&buf[..len])
+    }
+
+    /// Iterate over the characters of this string.
+    pub fn chars(&self) -> BmpChars<'a> {
+        BmpChars {
+            bytes: self.as_bytes(),
+        }
+    }
+
+    /// Borrow the UCS-2 encoding of the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for BmpString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for BmpString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for BmpString<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> FixedTag for BmpString<'a> {
+    const TAG: Tag = Tag::BmpString;
+}
+
+impl OrdIsValueOrd for BmpString<'_> {}
+
+impl<'a> From<&BmpString<'a>> for BmpString<'a> {
+    fn from(value: &BmpString<'a>) -> BmpString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for BmpString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<BmpString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<BmpString<'a>> for Any<'a> {
+    fn from(bmp_string: BmpString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::BmpString, bmp_string.inner)
+    }
+}
+
+impl<'a> From<BmpString<'a>> for &'a [u8] {
+    fn from(bmp_string: BmpString<'a>) -> &'a [u8] {
+        bmp_string.as_bytes()
+    }
+}
+
+impl<'a> fmt::Display for BmpString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|c| f.write_char(c))
+    }
+}
+
+impl<'a> fmt::Debug for BmpString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BmpString(\"")?;
+        self.chars()
+            .flat_map(|c| c.escape_debug())
+            .try_for_each(|c| f.write_char(c))?;
+        f.write_str("\")")
+    }
+}
+
+/// Iterator over the characters of a [`BmpString`].
+#[derive(Clone, Debug)]
+pub struct BmpChars<'a> {
+    /// Remaining UCS-2 encoded bytes.
+    bytes: &'a [u8],
+}
+
+impl Iterator for BmpChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match *self.bytes {
+            [hi, lo, ref rest @ ..] => {
+                self.bytes = rest;
+
+                // Surrogates were rejected by `BmpString::new`
+                char::from_u32(u32::from(u16::from_be_bytes([hi, lo])))
+            }
+            _ => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bytes.len() / 2;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for BmpChars<'_> {}
+
+impl FusedIterator for BmpChars<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::BmpString;
+    use crate::{Decodable, Encodable, Result, Tag};
+    use hex_literal::hex;
+
+    /// `BMPString` "Hello, ₿" (includes a character outside of Latin-1)
+    const EXAMPLE: &[u8] = &hex!("1E10 00480065006C006C006F002C002020BF");
+
+    #[test]
+    fn parse_bytes() -> Result<()> {
+        let bmp_string = BmpString::from_der(EXAMPLE)?;
+        assert!(bmp_string.chars().eq("Hello, \u{20BF}".chars()));
+        assert_eq!(bmp_string.chars().len(), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn from_str_round_trip() -> Result<()> {
+        let mut buf = [0u8; 16];
+        let bmp_string = BmpString::from_str_in("Hello, \u{20BF}", &mut buf)?;
+
+        let mut der = [0u8; 18];
+        assert_eq!(bmp_string.encode_to_slice(&mut der)?, EXAMPLE);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_non_bmp_chars() {
+        let mut buf = [0u8; 16];
+        assert!(BmpString::from_str_in("\u{1F600}", &mut buf).is_err());
+        assert!(BmpString::from_str_in("too long for buf", &mut buf[..4]).is_err());
+    }
+
+    #[test]
+    fn reject_invalid_encodings() {
+        // Odd number of bytes
+        assert!(BmpString::from_der(&hex!("1E03 004100")).is_err());
+        // Surrogate pair
+        assert!(BmpString::from_der(&hex!("1E04 D83DDE00")).is_err());
+        // Wrong tag number: BMPString is universal tag 30
+        assert!(BmpString::from_der(&hex!("1D02 0041")).is_err());
+        assert_eq!(Tag::BmpString.octet(), 0x1E);
+    }
+}
@@ -0,0 +1,198 @@
+//! ASN.1 `DATE` support.
+
+use crate::{
+    asn1::{time::SubtypeTag, Time},
+    datetime,
+    ord::OrdIsValueOrd,
+    ByteSlice, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, Header,
+    Length, Result, Tag,
+};
+use core::fmt;
+
+/// Minimum year of a `DATE`.
+const MIN_YEAR: u16 = 1582;
+
+/// Maximum year of a `DATE`.
+const MAX_YEAR: u16 = 9999;
+
+/// ASN.1 `DATE` type: a calendar date in the `YYYY-MM-DD` format, with a
+/// year between 1582 and 9999.
+///
+/// `DATE` is defined as a subtype of `TIME` with the universal tag 31, so
+/// its identifier octets are `1F 1F`. This tag can't be represented by
+/// [`Tag`], so this type doesn't impl [`FixedTag`][`crate::FixedTag`] and
+/// can't be used with [`Any`][`crate::asn1::Any`]. A `TIME` field restricted to dates can be
+/// parsed with `Date::try_from(time)`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Date {
+    /// Full year (e.g. 2000).
+    year: u16,
+
+    /// Month (1-12)
+    month: u8,
+
+    /// Day of the month (1-31)
+    day: u8,
+}
+
+impl Date {
+    /// Length of an ASN.1 DER-encoded [`Date`].
+    pub const LENGTH: Length = Length::new(10);
+
+    /// Create a new [`Date`] from the given components.
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year)
+            || !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+        {
+            return Err(Tag::Time.value_error());
+        }
+
+        Ok(Self { year, month, day })
+    }
+
+    /// Get the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Get the month.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Get the day of the month.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Parse a [`Date`] from its `YYYY-MM-DD` representation.
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        match *bytes {
+            [y1, y2, y3, y4, b'-', mon1, mon2, b'-', day1, day2] => {
+                let year = datetime::decode_decimal(Tag::Time, y1, y2)? as u16 * 100
+                    + datetime::decode_decimal(Tag::Time, y3, y4)? as u16;
+                let month = datetime::decode_decimal(Tag::Time, mon1, mon2)?;
+                let day = datetime::decode_decimal(Tag::Time, day1, day2)?;
+                Self::new(year, month, day)
+            }
+            _ => Err(Tag::Time.value_error()),
+        }
+    }
+}
+
+impl DecodeValue<'_> for Date {
+    fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
+        Self::from_bytes(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for Date {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Self::LENGTH)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        datetime::encode_decimal(encoder, Tag::Time, (self.year / 100) as u8)?;
+        datetime::encode_decimal(encoder, Tag::Time, (self.year % 100) as u8)?;
+        encoder.byte(b'-')?;
+        datetime::encode_decimal(encoder, Tag::Time, self.month)?;
+        encoder.byte(b'-')?;
+        datetime::encode_decimal(encoder, Tag::Time, self.day)
+    }
+}
+
+impl OrdIsValueOrd for Date {}
+
+impl Decodable<'_> for Date {
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
+        SubtypeTag::DATE.decode(decoder)
+    }
+}
+
+impl Encodable for Date {
+    fn encoded_len(&self) -> Result<Length> {
+        SubtypeTag::DATE.encoded_len(self)
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        SubtypeTag::DATE.encode(self, encoder)
+    }
+}
+
+impl TryFrom<Time<'_>> for Date {
+    type Error = Error;
+
+    fn try_from(time: Time<'_>) -> Result<Date> {
+        Self::from_bytes(time.as_bytes())
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Get the number of days in the given month.
+fn days_in_month(year: u16, month: u8) -> u8 {
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+
+    match month {
+        2 if is_leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Date;
+    use crate::{asn1::Time, Decodable, Encodable, ErrorKind, Result, Tag};
+    use hex_literal::hex;
+
+    /// `DATE` 2012-12-21
+    const EXAMPLE: &[u8] = &hex!("1F1F 0A 323031322D31322D3231");
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let date = Date::from_der(EXAMPLE)?;
+        assert_eq!((date.year(), date.month(), date.day()), (2012, 12, 21));
+
+        let mut buf = [0u8; 13];
+        assert_eq!(date.encode_to_slice(&mut buf)?, EXAMPLE);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid() {
+        assert!(Date::new(2001, 2, 29).is_err());
+        assert!(Date::new(2000, 2, 29).is_ok());
+        assert!(Date::new(1000, 1, 1).is_err());
+        assert!(Date::from_der(&hex!("1F1F 08 3230313231323231")).is_err());
+    }
+
+    #[test]
+    fn reject_other_tags() {
+        let err = Date::from_der(&hex!("0E0A 323031322D31322D3231")).err();
+        assert_eq!(
+            err.map(|e| e.kind()),
+            Some(ErrorKind::TagUnexpected {
+                expected: None,
+                actual: Tag::Time
+            })
+        );
+
+        let err = Date::from_der(&hex!("1F20 0A 323031322D31322D3231")).err();
+        assert_eq!(err.map(|e| e.kind()), Some(ErrorKind::TagNumberInvalid));
+    }
+
+    #[test]
+    fn from_time() -> Result<()> {
+        let time = Time::from_der(&hex!("0E0A 323031322D31322D3231"))?;
+        assert_eq!(Date::try_from(time)?, Date::from_der(EXAMPLE)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+//! ASN.1 `INTEGER` support for [`Duration`].
+//!
+//! Durations are mapped to a non-negative `INTEGER` number of seconds, which
+//! is how protocols using DER conventionally carry them (e.g. lifetimes and
+//! caching hints). Durations with sub-second precision can't be encoded.
+//!
+//! For the ASN.1 `DURATION` type, use [`IsoDuration`][`crate::asn1::IsoDuration`].
+
+use crate::{
+    asn1::Any, DecodeValue, Decoder, EncodeValue, Encoder, Error, FixedTag, Header, Length, Result,
+    Tag, ValueOrd,
+};
+use core::{cmp::Ordering, time::Duration};
+
+impl<'a> DecodeValue<'a> for Duration {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        u64::decode_value(decoder, header).map(Duration::from_secs)
+    }
+}
+
+impl EncodeValue for Duration {
+    fn value_len(&self) -> Result<Length> {
+        whole_secs(self)?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        whole_secs(self)?.encode_value(encoder)
+    }
+}
+
+impl FixedTag for Duration {
+    const TAG: Tag = Tag::Integer;
+}
+
+impl ValueOrd for Duration {
+    fn value_cmp(&self, other: &Self) -> Result<Ordering> {
+        whole_secs(self)?.value_cmp(&whole_secs(other)?)
+    }
+}
+
+impl TryFrom<Any<'_>> for Duration {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<Self> {
+        any.decode_into()
+    }
+}
+
+/// Get the number of seconds in a duration, returning an error if it has
+/// sub-second precision.
+fn whole_secs(duration: &Duration) -> Result<u64> {
+    if duration.subsec_nanos() != 0 {
+        return Err(Tag::Integer.value_error());
+    }
+
+    Ok(duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decodable, Encodable, ErrorKind, Result, Tag};
+    use core::time::Duration;
+    use hex_literal::hex;
+
+    #[test]
+    fn decode_secs() -> Result<()> {
+        assert_eq!(Duration::from_secs(0), Duration::from_der(&hex!("020100"))?);
+        assert_eq!(
+            Duration::from_secs(86400),
+            Duration::from_der(&hex!("0203015180"))?
+        );
+        assert_eq!(
+            Duration::from_secs(u64::MAX),
+            Duration::from_der(&hex!("020900FFFFFFFFFFFFFFFF"))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn encode_secs() -> Result<()> {
+        let mut buf = [0u8; 11];
+        assert_eq!(
+            &hex!("0203015180"),
+            Duration::from_secs(86400).encode_to_slice(&mut buf)?
+        );
+        assert_eq!(
+            &hex!("020900FFFFFFFFFFFFFFFF"),
+            Duration::from_secs(u64::MAX).encode_to_slice(&mut buf)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reject_negative() {
+        assert!(Duration::from_der(&hex!("0201FF")).is_err());
+    }
+
+    #[test]
+    fn reject_subsec() {
+        let mut buf = [0u8; 11];
+        let err = Duration::from_millis(1500).encode_to_slice(&mut buf).err();
+        assert_eq!(
+            Some(ErrorKind::Value { tag: Tag::Integer }),
+            err.map(|e| e.kind())
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! ASN.1 `GeneralString` support.
+
+use crate::{
+    asn1::Any, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error,
+    FixedTag, Header, Length, Result, Tag,
+};
+use core::{
+    fmt::{self, Write},
+    str,
+};
+
+/// ASN.1 `GeneralString` type.
+///
+/// `GeneralString` can contain characters from any of the character sets
+/// registered for use with ISO 2022, switched between with escape
+/// sequences, so this type accepts any contents. Protocols typically
+/// constrain it further: e.g. Kerberos' `KerberosString` is a
+/// `GeneralString` restricted to IA5 characters, which can be checked with
+/// [`GeneralString::validate`].
+///
+/// For UTF-8, use [`Utf8String`][`crate::asn1::Utf8String`].
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GeneralString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> GeneralString<'a> {
+    /// Create a new `GeneralString` from its contents, which may be arbitrary bytes.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        ByteSlice::new(input.as_ref())
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Validate the contents of this string against a character set,
+    /// returning an error if `is_valid` returns `false` for any byte.
+    ///
+    /// For example, `|byte| byte.is_ascii()` checks that the string only
+    /// contains IA5 characters.
+    pub fn validate<F>(self, is_valid: F) -> Result<Self>
+    where
+        F: FnMut(&u8) -> bool,
+    {
+        if self.as_bytes().iter().all(is_valid) {
+            Ok(self)
+        } else {
+            Err(Self::TAG.value_error())
+        }
+    }
+
+    /// Borrow the string as a `str`, if it only contains ASCII characters.
+    pub fn as_str(&self) -> Result<&'a str> {
+        let bytes = self.as_bytes();
+
+        if !bytes.is_ascii() {
+            return Err(Self::TAG.value_error());
+        }
+
+        str::from_utf8(bytes).map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Borrow the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for GeneralString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for GeneralString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for GeneralString<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> FixedTag for GeneralString<'a> {
+    const TAG: Tag = Tag::GeneralString;
+}
+
+impl OrdIsValueOrd for GeneralString<'_> {}
+
+impl<'a> From<&GeneralString<'a>> for GeneralString<'a> {
+    fn from(value: &GeneralString<'a>) -> GeneralString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for GeneralString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<GeneralString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<GeneralString<'a>> for Any<'a> {
+    fn from(general_string: GeneralString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::GeneralString, general_string.inner)
+    }
+}
+
+impl<'a> From<GeneralString<'a>> for &'a [u8] {
+    fn from(general_string: GeneralString<'a>) -> &'a [u8] {
+        general_string.as_bytes()
+    }
+}
+
+/// Writes ASCII characters as-is, and substitutes
+/// [`char::REPLACEMENT_CHARACTER`] for other bytes.
+impl<'a> fmt::Display for GeneralString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_bytes().iter().try_for_each(|&byte| {
+            if byte.is_ascii() {
+                f.write_char(char::from(byte))
+            } else {
+                f.write_char(char::REPLACEMENT_CHARACTER)
+            }
+        })
+    }
+}
+
+impl<'a> fmt::Debug for GeneralString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_str() {
+            Ok(s) => write!(f, "GeneralString({:?})", s),
+            Err(_) => write!(f, "GeneralString({:02X?})", self.as_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeneralString;
+    use crate::{Decodable, Result};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() -> Result<()> {
+        // KerberosString "EXAMPLE.COM"
+        let general_string =
+            GeneralString::from_der(&hex!("1b 0b 45 58 41 4d 50 4c 45 2e 43 4f 4d"))?;
+        assert_eq!(
+            general_string.validate(|b| b.is_ascii())?.as_str()?,
+            "EXAMPLE.COM"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn validate_charset() -> Result<()> {
+        let general_string = GeneralString::from_der(&hex!("1b 02 41 e9"))?;
+        assert!(general_string.as_str().is_err());
+        assert!(general_string.validate(|b| b.is_ascii()).is_err());
+        assert!(general_string.validate(|&b| b >= 0x20).is_ok());
+        Ok(())
+    }
+}
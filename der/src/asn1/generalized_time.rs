@@ -4,8 +4,8 @@ use crate::{
     asn1::Any,
     datetime::{self, DateTime},
     ord::OrdIsValueOrd,
-    ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, FixedTag, Header, Length, Result,
-    Tag,
+    ByteSlice, Decodable, DecodeValue, Decoder, EncodeValue, Encoder, Error, FixedTag, Header,
+    Length, Result, Tag,
 };
 use core::time::Duration;
 
@@ -13,7 +13,7 @@ use core::time::Duration;
 use std::time::SystemTime;
 
 #[cfg(feature = "time")]
-use time::PrimitiveDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime};
 
 /// ASN.1 `GeneralizedTime` type.
 ///
@@ -71,25 +71,86 @@ impl GeneralizedTime {
     pub fn to_system_time(&self) -> SystemTime {
         self.0.to_system_time()
     }
+
+    /// Decode a [`GeneralizedTime`] which doesn't necessarily follow the
+    /// RFC 5280 profile, as emitted by some non-conforming CAs.
+    ///
+    /// In addition to `YYYYMMDDHHMMSSZ`, this accepts:
+    ///
+    /// - fractional seconds, e.g. `YYYYMMDDHHMMSS.fffZ`, which are truncated
+    /// - offsets from UTC, e.g. `YYYYMMDDHHMMSS+hhmm`, which are normalized
+    ///   to UTC
+    ///
+    /// The decoded value is always re-encoded in the RFC 5280 form.
+    ///
+    /// See also [`Decoder::with_lenient_generalized_time`], which enables
+    /// this for every `GeneralizedTime` in a structure.
+    pub fn decode_lenient(decoder: &mut Decoder<'_>) -> Result<Self> {
+        let header = Header::decode(decoder)?;
+        header.tag.assert_eq(Self::TAG)?;
+        Self::from_lenient_bytes(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+
+    /// Parse the contents of a `GeneralizedTime` as with
+    /// [`GeneralizedTime::decode_lenient`].
+    fn from_lenient_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 15 {
+            return Err(Self::TAG.value_error());
+        }
+
+        let (datetime, mut rest) = bytes.split_at(14);
+        let datetime = decode_date_time(datetime)?;
+
+        // Skip fractional seconds
+        if let [b'.' | b',', fraction @ ..] = rest {
+            let digits = fraction.iter().take_while(|c| c.is_ascii_digit()).count();
+
+            if digits == 0 {
+                return Err(Self::TAG.value_error());
+            }
+
+            rest = &fraction[digits..];
+        }
+
+        let unix_duration = datetime.unix_duration();
+        let unix_duration = match *rest {
+            [b'Z'] => Some(unix_duration),
+            [sign @ (b'+' | b'-'), hour1, hour2, min1, min2] => {
+                let hours = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
+                let minutes = datetime::decode_decimal(Self::TAG, min1, min2)?;
+
+                if hours > 23 || minutes > 59 {
+                    return Err(Self::TAG.value_error());
+                }
+
+                let offset = Duration::from_secs(u64::from(hours) * 3600 + u64::from(minutes) * 60);
+
+                // Local time is ahead of UTC for positive offsets
+                if sign == b'+' {
+                    unix_duration.checked_sub(offset)
+                } else {
+                    unix_duration.checked_add(offset)
+                }
+            }
+            _ => None,
+        };
+
+        Self::from_unix_duration(unix_duration.ok_or_else(|| Self::TAG.value_error())?)
+    }
 }
 
 impl DecodeValue<'_> for GeneralizedTime {
     fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
-        match *ByteSlice::decode_value(decoder, header)?.as_bytes() {
+        let bytes = ByteSlice::decode_value(decoder, header)?.as_bytes();
+
+        if decoder.is_lenient_generalized_time() {
+            return Self::from_lenient_bytes(bytes);
+        }
+
+        match *bytes {
             // RFC 5280 requires mandatory seconds and Z-normalized time zone
-            [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
-                let year = datetime::decode_decimal(Self::TAG, y1, y2)? as u16 * 100
-                    + datetime::decode_decimal(Self::TAG, y3, y4)? as u16;
-                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
-                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
-                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
-                let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
-                let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
-
-                DateTime::new(year, month, day, hour, minute, second)
-                    .map_err(|_| Self::TAG.value_error())
-                    .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
-            }
+            [ref datetime @ .., b'Z'] => decode_date_time(datetime)
+                .and_then(|dt| Self::from_unix_duration(dt.unix_duration())),
             _ => Err(Self::TAG.value_error()),
         }
     }
@@ -317,10 +378,49 @@ impl TryFrom<GeneralizedTime> for PrimitiveDateTime {
     }
 }
 
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<GeneralizedTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: GeneralizedTime) -> Result<OffsetDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<OffsetDateTime> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<GeneralizedTime> {
+        Ok(GeneralizedTime::from_date_time(DateTime::try_from(time)?))
+    }
+}
+
+/// Decode the `YYYYMMDDHHMMSS` part of a [`GeneralizedTime`].
+fn decode_date_time(bytes: &[u8]) -> Result<DateTime> {
+    match *bytes {
+        [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2] => {
+            let tag = GeneralizedTime::TAG;
+            let year = datetime::decode_decimal(tag, y1, y2)? as u16 * 100
+                + datetime::decode_decimal(tag, y3, y4)? as u16;
+            let month = datetime::decode_decimal(tag, mon1, mon2)?;
+            let day = datetime::decode_decimal(tag, day1, day2)?;
+            let hour = datetime::decode_decimal(tag, hour1, hour2)?;
+            let minute = datetime::decode_decimal(tag, min1, min2)?;
+            let second = datetime::decode_decimal(tag, sec1, sec2)?;
+
+            DateTime::new(year, month, day, hour, minute, second).map_err(|_| tag.value_error())
+        }
+        _ => Err(GeneralizedTime::TAG.value_error()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::GeneralizedTime;
-    use crate::{Decodable, Encodable, Encoder};
+    use crate::{Decodable, Decoder, Encodable, Encoder, Result};
     use hex_literal::hex;
 
     #[test]
@@ -334,4 +434,72 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    /// Decode `GeneralizedTime` contents with [`GeneralizedTime::decode_lenient`].
+    fn decode_lenient(contents: &[u8]) -> Result<GeneralizedTime> {
+        let mut der = [0u8; 32];
+        der[0] = 0x18;
+        der[1] = contents.len() as u8;
+        der[2..(2 + contents.len())].copy_from_slice(contents);
+
+        let mut decoder = Decoder::new(&der[..(2 + contents.len())])?;
+        let time = GeneralizedTime::decode_lenient(&mut decoder)?;
+        decoder.finish(time)
+    }
+
+    #[test]
+    fn decode_lenient_fractional_seconds() -> Result<()> {
+        assert!(GeneralizedTime::from_der(&hex!(
+            "18 13 31 39 39 31 30 35 30 36 32 33 34 35 34 30 2e 31 32 33 5a"
+        ))
+        .is_err());
+
+        for contents in [
+            &b"19910506234540Z"[..],
+            b"19910506234540.123Z",
+            b"19910506234540,5Z",
+        ] {
+            assert_eq!(
+                decode_lenient(contents)?.to_unix_duration().as_secs(),
+                673573540
+            );
+        }
+
+        assert!(decode_lenient(b"19910506234540.Z").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn decode_lenient_offsets() -> Result<()> {
+        assert_eq!(
+            decode_lenient(b"19910507014540+0200")?
+                .to_unix_duration()
+                .as_secs(),
+            673573540
+        );
+        assert_eq!(
+            decode_lenient(b"19910506221040.9-0135")?
+                .to_unix_duration()
+                .as_secs(),
+            673573540
+        );
+        assert!(decode_lenient(b"19910506234540+2400").is_err());
+        assert!(decode_lenient(b"19910506234540").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn decoder_lenient_generalized_time() -> Result<()> {
+        // `SEQUENCE { GeneralizedTime 19910507014540+0200 }`
+        let example_bytes =
+            hex!("30 15 18 13 31 39 39 31 30 35 30 37 30 31 34 35 34 30 2b 30 32 30 30");
+        assert!(Decoder::new(&example_bytes)?
+            .sequence(|decoder| decoder.generalized_time())
+            .is_err());
+
+        let mut decoder = Decoder::new(&example_bytes)?.with_lenient_generalized_time(true);
+        let time = decoder.sequence(|decoder| decoder.generalized_time())?;
+        assert_eq!(time.to_unix_duration().as_secs(), 673573540);
+        decoder.finish(())
+    }
 }
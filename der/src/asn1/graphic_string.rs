@@ -0,0 +1,180 @@
+//! ASN.1 `GraphicString` support.
+
+use crate::{
+    asn1::Any, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error,
+    FixedTag, Header, Length, Result, Tag,
+};
+use core::{
+    fmt::{self, Write},
+    str,
+};
+
+/// ASN.1 `GraphicString` type.
+///
+/// `GraphicString` can contain the graphic characters of any of the
+/// character sets registered for use with ISO 2022, switched between with
+/// escape sequences, so this type accepts any contents. Protocols which
+/// restrict it to a specific character set can check it with
+/// [`GraphicString::validate`].
+///
+/// For UTF-8, use [`Utf8String`][`crate::asn1::Utf8String`].
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GraphicString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> GraphicString<'a> {
+    /// Create a new `GraphicString` from its contents, which may be arbitrary bytes.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        ByteSlice::new(input.as_ref())
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Validate the contents of this string against a character set,
+    /// returning an error if `is_valid` returns `false` for any byte.
+    ///
+    /// For example, `|byte| byte.is_ascii()` checks that the string only
+    /// contains IA5 characters.
+    pub fn validate<F>(self, is_valid: F) -> Result<Self>
+    where
+        F: FnMut(&u8) -> bool,
+    {
+        if self.as_bytes().iter().all(is_valid) {
+            Ok(self)
+        } else {
+            Err(Self::TAG.value_error())
+        }
+    }
+
+    /// Borrow the string as a `str`, if it only contains ASCII characters.
+    pub fn as_str(&self) -> Result<&'a str> {
+        let bytes = self.as_bytes();
+
+        if !bytes.is_ascii() {
+            return Err(Self::TAG.value_error());
+        }
+
+        str::from_utf8(bytes).map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Borrow the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for GraphicString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for GraphicString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for GraphicString<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> FixedTag for GraphicString<'a> {
+    const TAG: Tag = Tag::GraphicString;
+}
+
+impl OrdIsValueOrd for GraphicString<'_> {}
+
+impl<'a> From<&GraphicString<'a>> for GraphicString<'a> {
+    fn from(value: &GraphicString<'a>) -> GraphicString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for GraphicString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<GraphicString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<GraphicString<'a>> for Any<'a> {
+    fn from(graphic_string: GraphicString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::GraphicString, graphic_string.inner)
+    }
+}
+
+impl<'a> From<GraphicString<'a>> for &'a [u8] {
+    fn from(graphic_string: GraphicString<'a>) -> &'a [u8] {
+        graphic_string.as_bytes()
+    }
+}
+
+/// Writes ASCII characters as-is, and substitutes
+/// [`char::REPLACEMENT_CHARACTER`] for other bytes.
+impl<'a> fmt::Display for GraphicString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_bytes().iter().try_for_each(|&byte| {
+            if byte.is_ascii() {
+                f.write_char(char::from(byte))
+            } else {
+                f.write_char(char::REPLACEMENT_CHARACTER)
+            }
+        })
+    }
+}
+
+impl<'a> fmt::Debug for GraphicString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_str() {
+            Ok(s) => write!(f, "GraphicString({:?})", s),
+            Err(_) => write!(f, "GraphicString({:02X?})", self.as_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphicString;
+    use crate::{Decodable, Result};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() -> Result<()> {
+        let graphic_string = GraphicString::from_der(&hex!("19 05 48 65 6c 6c 6f"))?;
+        assert_eq!(graphic_string.as_str()?, "Hello");
+        Ok(())
+    }
+
+    #[test]
+    fn validate_charset() -> Result<()> {
+        // ISO 2022 escape sequence designating ISO 8859-1 as G1, then "é"
+        let graphic_string = GraphicString::from_der(&hex!("19 04 1b 2d 41 e9"))?;
+        assert!(graphic_string.as_str().is_err());
+        assert!(graphic_string.validate(u8::is_ascii_graphic).is_err());
+        assert!(graphic_string.validate(|&b| b >= 0x20 || b == 0x1b).is_ok());
+        Ok(())
+    }
+}
@@ -0,0 +1,424 @@
+//! ASN.1 `DURATION` support.
+
+use crate::{
+    asn1::{time::SubtypeTag, Time},
+    ord::OrdIsValueOrd,
+    ByteSlice, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, Header,
+    Length, Result, Tag,
+};
+use core::{fmt, time::Duration};
+
+/// Maximum length of the representation of an [`IsoDuration`]: `P`, `T`,
+/// and six components of up to 10 digits plus a designator.
+const MAX_LEN: usize = 68;
+
+/// ASN.1 `DURATION` type: an ISO 8601 duration such as `P1Y2M3DT4H5M6S`
+/// or `P2W`.
+///
+/// Named to avoid confusion with [`Duration`], which this crate encodes as
+/// an `INTEGER` number of seconds. Durations with fractional components
+/// aren't supported.
+///
+/// `DURATION` is defined as a subtype of `TIME` with the universal tag 34,
+/// so its identifier octets are `1F 22`. As with
+/// [`Date`][`crate::asn1::Date`], this type doesn't impl
+/// [`FixedTag`][`crate::FixedTag`].
+///
+/// Components equal to zero are omitted from the encoding, except for the
+/// zero duration which is encoded as `PT0S`. Encodings with other zero
+/// components are rejected as non-canonical.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct IsoDuration {
+    /// Years
+    years: u32,
+
+    /// Months
+    months: u32,
+
+    /// Weeks, which can't be combined with any other component.
+    weeks: u32,
+
+    /// Days
+    days: u32,
+
+    /// Hours
+    hours: u32,
+
+    /// Minutes
+    minutes: u32,
+
+    /// Seconds
+    seconds: u32,
+}
+
+impl IsoDuration {
+    /// Create a new [`IsoDuration`] from the given components.
+    pub fn new(years: u32, months: u32, days: u32, hours: u32, minutes: u32, seconds: u32) -> Self {
+        Self {
+            years,
+            months,
+            weeks: 0,
+            days,
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+
+    /// Create a new [`IsoDuration`] of the given number of weeks.
+    pub fn from_weeks(weeks: u32) -> Self {
+        Self {
+            weeks,
+            ..Self::default()
+        }
+    }
+
+    /// Get the years component.
+    pub fn years(&self) -> u32 {
+        self.years
+    }
+
+    /// Get the months component.
+    pub fn months(&self) -> u32 {
+        self.months
+    }
+
+    /// Get the weeks component.
+    pub fn weeks(&self) -> u32 {
+        self.weeks
+    }
+
+    /// Get the days component.
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// Get the hours component.
+    pub fn hours(&self) -> u32 {
+        self.hours
+    }
+
+    /// Get the minutes component.
+    pub fn minutes(&self) -> u32 {
+        self.minutes
+    }
+
+    /// Get the seconds component.
+    pub fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Parse an [`IsoDuration`] from its canonical representation.
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut duration = Self::default();
+        let mut rest = match bytes {
+            [b'P', rest @ ..] if !rest.is_empty() => rest,
+            _ => return Err(Tag::Time.value_error()),
+        };
+
+        // Designators in the order they must appear in, and whether they
+        // belong to the time part (after `T`)
+        let mut designators = [
+            (b'Y', false),
+            (b'M', false),
+            (b'W', false),
+            (b'D', false),
+            (b'H', true),
+            (b'M', true),
+            (b'S', true),
+        ]
+        .iter()
+        .zip(0..);
+
+        let mut in_time = false;
+
+        while let Some((&first, after)) = rest.split_first() {
+            if first == b'T' && !in_time {
+                in_time = true;
+                rest = after;
+                continue;
+            }
+
+            let digits = rest.iter().take_while(|c| c.is_ascii_digit()).count();
+            let (value, designator) = match rest.get(digits) {
+                Some(&designator) if digits > 0 => (parse_decimal(&rest[..digits])?, designator),
+                _ => return Err(Tag::Time.value_error()),
+            };
+
+            let index = designators
+                .find(|&(&(d, time), _)| d == designator && time == in_time)
+                .map(|(_, index)| index)
+                .ok_or_else(|| Tag::Time.value_error())?;
+
+            *duration.component_mut(index) = value;
+            rest = &rest[(digits + 1)..];
+        }
+
+        // Check the representation is canonical, which also rejects weeks
+        // combined with other components and a trailing `T`
+        if duration.to_buffer()?.as_bytes() != bytes {
+            return Err(Tag::Time.non_canonical_error());
+        }
+
+        Ok(duration)
+    }
+
+    /// Get a mutable reference to the component at the given index, in
+    /// order of appearance in the representation.
+    fn component_mut(&mut self, index: usize) -> &mut u32 {
+        match index {
+            0 => &mut self.years,
+            1 => &mut self.months,
+            2 => &mut self.weeks,
+            3 => &mut self.days,
+            4 => &mut self.hours,
+            5 => &mut self.minutes,
+            _ => &mut self.seconds,
+        }
+    }
+
+    /// Encode this duration into a buffer.
+    fn to_buffer(self) -> Result<Buffer> {
+        let mut buf = Buffer::default();
+        fmt::Write::write_fmt(&mut buf, format_args!("{}", self))
+            .map_err(|_| Tag::Time.value_error())?;
+        Ok(buf)
+    }
+}
+
+impl DecodeValue<'_> for IsoDuration {
+    fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
+        Self::from_bytes(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for IsoDuration {
+    fn value_len(&self) -> Result<Length> {
+        Length::try_from(self.to_buffer()?.as_bytes().len())
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(self.to_buffer()?.as_bytes())
+    }
+}
+
+impl OrdIsValueOrd for IsoDuration {}
+
+impl Decodable<'_> for IsoDuration {
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
+        SubtypeTag::DURATION.decode(decoder)
+    }
+}
+
+impl Encodable for IsoDuration {
+    fn encoded_len(&self) -> Result<Length> {
+        SubtypeTag::DURATION.encoded_len(self)
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        SubtypeTag::DURATION.encode(self, encoder)
+    }
+}
+
+impl TryFrom<Time<'_>> for IsoDuration {
+    type Error = Error;
+
+    fn try_from(time: Time<'_>) -> Result<IsoDuration> {
+        Self::from_bytes(time.as_bytes())
+    }
+}
+
+/// Converts the duration into days, hours, minutes and seconds, returning
+/// an error if it has sub-second precision or doesn't fit.
+impl TryFrom<Duration> for IsoDuration {
+    type Error = Error;
+
+    fn try_from(duration: Duration) -> Result<IsoDuration> {
+        if duration.subsec_nanos() != 0 {
+            return Err(Tag::Time.value_error());
+        }
+
+        let secs = duration.as_secs();
+        let days = u32::try_from(secs / 86400).map_err(|_| Tag::Time.value_error())?;
+        let secs = (secs % 86400) as u32;
+
+        Ok(Self::new(
+            0,
+            0,
+            days,
+            secs / 3600,
+            secs % 3600 / 60,
+            secs % 60,
+        ))
+    }
+}
+
+/// Converts the duration into seconds, returning an error if it has a years
+/// or months component, whose length varies.
+impl TryFrom<IsoDuration> for Duration {
+    type Error = Error;
+
+    fn try_from(duration: IsoDuration) -> Result<Duration> {
+        if duration.years != 0 || duration.months != 0 {
+            return Err(Tag::Time.value_error());
+        }
+
+        let secs = u64::from(duration.weeks) * 7 * 86400
+            + u64::from(duration.days) * 86400
+            + u64::from(duration.hours) * 3600
+            + u64::from(duration.minutes) * 60
+            + u64::from(duration.seconds);
+
+        Ok(Duration::from_secs(secs))
+    }
+}
+
+impl fmt::Display for IsoDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("P")?;
+
+        if self.weeks != 0 {
+            return write!(f, "{}W", self.weeks);
+        }
+
+        for &(value, designator) in &[(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if value != 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+
+        let time = [(self.hours, 'H'), (self.minutes, 'M'), (self.seconds, 'S')];
+
+        if time.iter().any(|&(value, _)| value != 0) {
+            f.write_str("T")?;
+
+            for &(value, designator) in &time {
+                if value != 0 {
+                    write!(f, "{}{}", value, designator)?;
+                }
+            }
+        } else if self.years == 0 && self.months == 0 && self.days == 0 {
+            f.write_str("T0S")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse a decimal number of at most 10 digits.
+fn parse_decimal(digits: &[u8]) -> Result<u32> {
+    digits.iter().try_fold(0u32, |acc, &digit| {
+        acc.checked_mul(10)
+            .and_then(|acc| acc.checked_add(u32::from(digit - b'0')))
+            .ok_or_else(|| Tag::Time.value_error())
+    })
+}
+
+/// Buffer holding the representation of an [`IsoDuration`].
+struct Buffer {
+    /// Bytes of the representation.
+    bytes: [u8; MAX_LEN],
+
+    /// Number of bytes written.
+    len: usize,
+}
+
+impl Buffer {
+    /// Borrow the written bytes.
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self {
+            bytes: [0u8; MAX_LEN],
+            len: 0,
+        }
+    }
+}
+
+impl fmt::Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IsoDuration;
+    use crate::{Decodable, Encodable, ErrorKind, Result};
+    use core::time::Duration;
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let mut buf = [0u8; 32];
+
+        for (duration, der) in [
+            (
+                IsoDuration::new(1, 2, 3, 4, 5, 6),
+                &hex!("1F22 0E 503159324d3344543448354d3653")[..],
+            ),
+            (
+                IsoDuration::new(0, 0, 0, 0, 90, 0),
+                &hex!("1F22 05 505439304d"),
+            ),
+            (IsoDuration::from_weeks(2), &hex!("1F22 03 503257")),
+            (IsoDuration::default(), &hex!("1F22 04 50543053")),
+        ] {
+            assert_eq!(duration.encode_to_slice(&mut buf)?, der);
+            assert_eq!(IsoDuration::from_der(der)?, duration);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for der in [
+            // Empty duration
+            &hex!("1F22 01 50")[..],
+            // Trailing `T`
+            &hex!("1F22 04 50314454"),
+            // Components out of order
+            &hex!("1F22 05 5031443159"),
+            // Zero component
+            &hex!("1F22 05 5030593144"),
+            // Leading zero
+            &hex!("1F22 04 50303144"),
+            // Weeks combined with days
+            &hex!("1F22 05 5031573144"),
+            // Fractional seconds
+            &hex!("1F22 06 5054312E3553"),
+            // Overflow
+            &hex!("1F22 0C 50 34323934393637323936 44"),
+        ] {
+            assert!(IsoDuration::from_der(der).is_err(), "{:02X?}", der);
+        }
+    }
+
+    #[test]
+    fn reject_other_tags() {
+        assert!(IsoDuration::from_der(&hex!("0E03 503257")).is_err());
+
+        let err = IsoDuration::from_der(&hex!("1F21 03 503257")).err();
+        assert_eq!(err.map(|e| e.kind()), Some(ErrorKind::TagNumberInvalid));
+    }
+
+    #[test]
+    fn core_duration() -> Result<()> {
+        let duration = IsoDuration::try_from(Duration::from_secs(90061))?;
+        assert_eq!(duration, IsoDuration::new(0, 0, 1, 1, 1, 1));
+        assert_eq!(Duration::try_from(duration)?, Duration::from_secs(90061));
+        assert!(Duration::try_from(IsoDuration::new(1, 0, 0, 0, 0, 0)).is_err());
+        Ok(())
+    }
+}
@@ -5,6 +5,9 @@ use crate::{
     ErrorKind, FixedTag, Header, Length, Result, Tag,
 };
 
+#[cfg(feature = "alloc")]
+use {crate::asn1::AnyOwned, alloc::vec::Vec};
+
 /// ASN.1 `OCTET STRING` type.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct OctetString<'a> {
@@ -90,3 +93,145 @@ impl<'a> From<OctetString<'a>> for &'a [u8] {
         octet_string.as_bytes()
     }
 }
+
+/// ASN.1 `OCTET STRING` which owns its contents.
+///
+/// Counterpart to [`OctetString`] which stores its contents on the heap, so
+/// it isn't bound to the lifetime of the buffer it was decoded from.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct OctetStringOwned {
+    /// Inner value
+    inner: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl OctetStringOwned {
+    /// Create a new ASN.1 `OCTET STRING` from the given bytes.
+    pub fn new(bytes: Vec<u8>) -> Result<Self> {
+        Length::try_from(bytes.len()).map_err(|_| ErrorKind::Length { tag: Self::TAG })?;
+        Ok(Self { inner: bytes })
+    }
+
+    /// Borrow the inner byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+
+    /// Borrow this value as an [`OctetString`].
+    pub fn as_octet_string(&self) -> OctetString<'_> {
+        OctetString::new(&self.inner).expect("length invariant violated")
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.as_octet_string().len()
+    }
+
+    /// Is the inner byte slice empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Convert this [`OctetStringOwned`] into the inner bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl AsRef<[u8]> for OctetStringOwned {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> DecodeValue<'a> for OctetStringOwned {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        OctetString::decode_value(decoder, header).map(Self::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for OctetStringOwned {
+    fn value_len(&self) -> Result<Length> {
+        self.as_octet_string().value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(self.as_bytes())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl FixedTag for OctetStringOwned {
+    const TAG: Tag = Tag::OctetString;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl OrdIsValueOrd for OctetStringOwned {}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<OctetString<'_>> for OctetStringOwned {
+    fn from(octet_string: OctetString<'_>) -> OctetStringOwned {
+        OctetStringOwned {
+            inner: octet_string.as_bytes().to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> From<&'a OctetStringOwned> for OctetString<'a> {
+    fn from(octet_string: &'a OctetStringOwned) -> OctetString<'a> {
+        octet_string.as_octet_string()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl TryFrom<Any<'_>> for OctetStringOwned {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<OctetStringOwned> {
+        any.decode_into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<OctetStringOwned> for AnyOwned {
+    fn from(octet_string: OctetStringOwned) -> AnyOwned {
+        AnyOwned::new(Tag::OctetString, octet_string.inner).expect("length invariant violated")
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{OctetString, OctetStringOwned};
+    use crate::{Decodable, Encodable, Result};
+    use alloc::vec::Vec;
+    use hex_literal::hex;
+
+    #[test]
+    fn owned_round_trip() -> Result<()> {
+        let der = Vec::from(hex!("0403010203"));
+        let owned = OctetStringOwned::from_der(&der)?;
+        drop(der);
+
+        assert_eq!(owned.as_bytes(), &hex!("010203"));
+        assert_eq!(owned.to_vec()?, hex!("0403010203"));
+        assert_eq!(OctetString::from(&owned).as_bytes(), owned.as_bytes());
+        assert_eq!(owned.clone().into_bytes(), hex!("010203"));
+        Ok(())
+    }
+}
@@ -0,0 +1,309 @@
+//! ASN.1 `REAL` support.
+
+use crate::{
+    asn1::Any, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, FixedTag, Header,
+    Length, Result, Tag,
+};
+
+/// Flag in the first contents octet indicating the binary encoding.
+const BINARY_FLAG: u8 = 0b10000000;
+
+/// Flag in the first contents octet of the binary encoding indicating a
+/// negative value.
+const SIGN_FLAG: u8 = 0b01000000;
+
+/// Special value: `PLUS-INFINITY`.
+const PLUS_INFINITY: u8 = 0x40;
+
+/// Special value: `MINUS-INFINITY`.
+const MINUS_INFINITY: u8 = 0x41;
+
+/// Special value: `NOT-A-NUMBER`.
+const NOT_A_NUMBER: u8 = 0x42;
+
+/// Special value: `minus zero`.
+const MINUS_ZERO: u8 = 0x43;
+
+/// Number of bits in the significand of an [`f64`], including the implicit
+/// leading bit.
+const SIGNIFICAND_BITS: u32 = 53;
+
+/// Exponent of the least significant bit of the significand of subnormal
+/// [`f64`] values.
+const MIN_EXPONENT: i32 = -1074;
+
+/// Encoding of [`f64`] as ASN.1 `REAL`, as described in X.690 § 8.5.
+///
+/// Finite non-zero values use the base 2 binary encoding, in the canonical
+/// form required by DER (X.690 § 11.3.1): the mantissa is odd, the scaling
+/// factor is zero, and the exponent is minimally encoded. Zero, infinities
+/// and NaN are encoded as special values.
+///
+/// Decimal and base 8/16 encodings aren't supported, nor are values which
+/// can't be represented exactly as an [`f64`].
+impl<'a> DecodeValue<'a> for f64 {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        let bytes = decoder.bytes(header.length)?;
+
+        let (&first, rest) = match bytes.split_first() {
+            Some(split) => split,
+            None => return Ok(0.0),
+        };
+
+        if first & BINARY_FLAG == 0 {
+            return match (first, rest) {
+                (PLUS_INFINITY, []) => Ok(f64::INFINITY),
+                (MINUS_INFINITY, []) => Ok(f64::NEG_INFINITY),
+                (NOT_A_NUMBER, []) => Ok(f64::NAN),
+                (MINUS_ZERO, []) => Ok(-0.0),
+                _ => Err(Self::TAG.value_error()),
+            };
+        }
+
+        // Base 2 with a scaling factor of zero
+        if first & 0b00111100 != 0 {
+            return Err(Self::TAG.non_canonical_error());
+        }
+
+        let (exponent, mantissa) = match first & 0b11 {
+            0b11 => {
+                let (&len, rest) = rest
+                    .split_first()
+                    .ok_or(ErrorKind::Length { tag: Self::TAG })?;
+                split_at_checked(rest, len.into())?
+            }
+            len => split_at_checked(rest, usize::from(len) + 1)?,
+        };
+
+        let exponent = decode_exponent(exponent)?;
+        let mantissa = decode_mantissa(mantissa)?;
+        let value = to_f64(mantissa, exponent).ok_or_else(|| Self::TAG.value_error())?;
+
+        if first & SIGN_FLAG == 0 {
+            Ok(value)
+        } else {
+            Ok(-value)
+        }
+    }
+}
+
+impl EncodeValue for f64 {
+    fn value_len(&self) -> Result<Length> {
+        match binary_parts(*self) {
+            None if *self == 0.0 && self.is_sign_positive() => Ok(Length::ZERO),
+            None => Ok(Length::ONE),
+            Some((mantissa, exponent)) => {
+                Length::try_from(1 + exponent_len(exponent) + mantissa_len(mantissa))
+            }
+        }
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        let (mantissa, exponent) = match binary_parts(*self) {
+            Some(parts) => parts,
+            None if self.is_nan() => return encoder.byte(NOT_A_NUMBER),
+            None if *self == f64::INFINITY => return encoder.byte(PLUS_INFINITY),
+            None if *self == f64::NEG_INFINITY => return encoder.byte(MINUS_INFINITY),
+            None if self.is_sign_negative() => return encoder.byte(MINUS_ZERO),
+            None => return Ok(()),
+        };
+
+        let exponent_len = exponent_len(exponent);
+        let mut first = BINARY_FLAG | (exponent_len as u8 - 1);
+
+        if self.is_sign_negative() {
+            first |= SIGN_FLAG;
+        }
+
+        encoder.byte(first)?;
+        encoder.bytes(&exponent.to_be_bytes()[(4 - exponent_len)..])?;
+        encoder.bytes(&mantissa.to_be_bytes()[(8 - mantissa_len(mantissa))..])
+    }
+}
+
+impl FixedTag for f64 {
+    const TAG: Tag = Tag::Real;
+}
+
+impl TryFrom<Any<'_>> for f64 {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<f64> {
+        any.decode_into()
+    }
+}
+
+/// Split `bytes` into the first `len` bytes and the rest, returning an error
+/// if there are fewer than `len` bytes.
+fn split_at_checked(bytes: &[u8], len: usize) -> Result<(&[u8], &[u8])> {
+    if bytes.len() < len {
+        return Err(ErrorKind::Length { tag: Tag::Real }.into());
+    }
+
+    Ok(bytes.split_at(len))
+}
+
+/// Decode a minimally encoded two's complement exponent.
+fn decode_exponent(bytes: &[u8]) -> Result<i32> {
+    match bytes {
+        [] => Err(ErrorKind::Length { tag: Tag::Real }.into()),
+        [first, second, ..]
+            if (*first == 0 && *second < 0x80) || (*first == 0xFF && *second >= 0x80) =>
+        {
+            Err(Tag::Real.non_canonical_error())
+        }
+        _ if bytes.len() > 4 => Err(Tag::Real.value_error()),
+        _ => {
+            let sign_extension = if bytes[0] >= 0x80 { 0xFF } else { 0 };
+            let mut buf = [sign_extension; 4];
+            buf[(4 - bytes.len())..].copy_from_slice(bytes);
+            Ok(i32::from_be_bytes(buf))
+        }
+    }
+}
+
+/// Decode a minimally encoded, odd, unsigned mantissa.
+fn decode_mantissa(bytes: &[u8]) -> Result<u64> {
+    match bytes {
+        [] => Err(ErrorKind::Length { tag: Tag::Real }.into()),
+        [0, ..] => Err(Tag::Real.non_canonical_error()),
+        [.., last] if last & 1 == 0 => Err(Tag::Real.non_canonical_error()),
+        _ if bytes.len() > 8 => Err(Tag::Real.value_error()),
+        _ => {
+            let mut buf = [0u8; 8];
+            buf[(8 - bytes.len())..].copy_from_slice(bytes);
+            Ok(u64::from_be_bytes(buf))
+        }
+    }
+}
+
+/// Compute `mantissa * 2^exponent`, if it can be represented exactly.
+fn to_f64(mantissa: u64, exponent: i32) -> Option<f64> {
+    let bits = 64 - mantissa.leading_zeros();
+
+    if bits > SIGNIFICAND_BITS || exponent < MIN_EXPONENT {
+        return None;
+    }
+
+    // Exponent of the leading bit
+    let leading_exponent = exponent.checked_add(bits as i32 - 1)?;
+
+    if leading_exponent > f64::MAX_EXP - 1 {
+        return None;
+    }
+
+    if leading_exponent >= f64::MIN_EXP - 1 {
+        let significand = (mantissa << (SIGNIFICAND_BITS - bits)) & ((1 << 52) - 1);
+        let biased_exponent = (leading_exponent + 1023) as u64;
+        Some(f64::from_bits((biased_exponent << 52) | significand))
+    } else {
+        Some(f64::from_bits(mantissa << (exponent - MIN_EXPONENT)))
+    }
+}
+
+/// Decompose a finite non-zero value into an odd mantissa and an exponent
+/// such that its magnitude is `mantissa * 2^exponent`.
+fn binary_parts(value: f64) -> Option<(u64, i32)> {
+    if !value.is_finite() || value == 0.0 {
+        return None;
+    }
+
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7FF) as i32;
+    let significand = bits & ((1 << 52) - 1);
+
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (significand, MIN_EXPONENT)
+    } else {
+        (significand | (1 << 52), biased_exponent + MIN_EXPONENT - 1)
+    };
+
+    let trailing_zeros = mantissa.trailing_zeros();
+    Some((mantissa >> trailing_zeros, exponent + trailing_zeros as i32))
+}
+
+/// Length of the minimal two's complement encoding of an exponent.
+///
+/// The exponents of [`f64`] values always fit in two bytes.
+fn exponent_len(exponent: i32) -> usize {
+    match exponent {
+        -0x80..=0x7F => 1,
+        _ => 2,
+    }
+}
+
+/// Length of the minimal encoding of a non-zero mantissa.
+fn mantissa_len(mantissa: u64) -> usize {
+    8 - (mantissa.leading_zeros() / 8) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decodable, Encodable, Result};
+    use hex_literal::hex;
+
+    /// Encodings of finite values.
+    const EXAMPLES: &[(f64, &[u8])] = &[
+        (0.0, &hex!("0900")),
+        (1.0, &hex!("0903800001")),
+        (-1.0, &hex!("0903C00001")),
+        (0.5, &hex!("090380FF01")),
+        (10.0, &hex!("0903800105")),
+        (-1.5, &hex!("0903C0FF03")),
+        (f64::MAX, &hex!("090A8103CB1FFFFFFFFFFFFF")),
+        (f64::MIN_POSITIVE, &hex!("090481FC0201")),
+        (5e-324, &hex!("090481FBCE01")),
+    ];
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let mut buf = [0u8; 16];
+
+        for &(value, der) in EXAMPLES {
+            assert_eq!(value.encode_to_slice(&mut buf)?, der);
+            assert_eq!(f64::from_der(der)?.to_bits(), value.to_bits());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn special_values() -> Result<()> {
+        let mut buf = [0u8; 3];
+
+        for (value, der) in [
+            (f64::INFINITY, hex!("090140")),
+            (f64::NEG_INFINITY, hex!("090141")),
+            (f64::NAN, hex!("090142")),
+            (-0.0, hex!("090143")),
+        ] {
+            assert_eq!(value.encode_to_slice(&mut buf)?, der);
+            assert_eq!(f64::from_der(&der)?.to_bits(), value.to_bits());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_non_canonical() {
+        for der in [
+            // Even mantissa
+            &hex!("0903800002")[..],
+            // Leading zero in the mantissa
+            &hex!("090480000001"),
+            // Non-minimal exponent
+            &hex!("090481000101"),
+            // Non-zero scaling factor
+            &hex!("0903840001"),
+            // Base 16
+            &hex!("0903A00001"),
+            // Decimal encoding
+            &hex!("0904033145"),
+            // Not representable as `f64`
+            &hex!("090481FBCD01"),
+            &hex!("090481040001"),
+        ] {
+            assert!(f64::from_der(der).is_err(), "{:02X?}", der);
+        }
+    }
+}
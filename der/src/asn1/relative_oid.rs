@@ -0,0 +1,280 @@
+//! ASN.1 `RELATIVE-OID` support.
+
+use crate::{
+    asn1::Any, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error,
+    ErrorKind, FixedTag, Header, Length, Result, Tag,
+};
+use core::{fmt, iter::FusedIterator};
+
+/// Bit which marks that more bytes of an arc follow.
+const CONTINUATION_FLAG: u8 = 0b10000000;
+
+/// Maximum number of bytes in the encoding of a `u32` arc.
+const MAX_ARC_LEN: usize = 5;
+
+/// ASN.1 `RELATIVE-OID` type.
+///
+/// Relative object identifiers are sequences of arcs, which identify an
+/// object relative to some base `OBJECT IDENTIFIER` known from context.
+/// Unlike [`ObjectIdentifier`][`crate::asn1::ObjectIdentifier`]s, the first
+/// two arcs aren't combined into a single one when encoding, and there are
+/// no restrictions on their values.
+///
+/// Arcs are limited to 32 bits. Relative OIDs are formatted as dotted
+/// strings of arcs, e.g. `8571.3.2`.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct RelativeOid<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> RelativeOid<'a> {
+    /// Create a new `RELATIVE-OID` from its BER/DER encoding.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.is_empty() || bytes[bytes.len() - 1] & CONTINUATION_FLAG != 0 {
+            return Err(Self::TAG.length_error());
+        }
+
+        let mut arc_len = 0;
+        let mut first_byte = 0;
+
+        for &byte in bytes {
+            if arc_len == 0 {
+                // Arcs must be minimally encoded
+                if byte == CONTINUATION_FLAG {
+                    return Err(Self::TAG.non_canonical_error());
+                }
+
+                first_byte = byte;
+            }
+
+            arc_len += 1;
+
+            // Arcs must fit in a `u32`
+            if arc_len > MAX_ARC_LEN || (arc_len == MAX_ARC_LEN && first_byte > 0x8F) {
+                return Err(Self::TAG.value_error());
+            }
+
+            if byte & CONTINUATION_FLAG == 0 {
+                arc_len = 0;
+            }
+        }
+
+        ByteSlice::new(bytes)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Encode the given arcs as a `RELATIVE-OID` into the provided buffer.
+    pub fn from_arcs_in(arcs: &[u32], buf: &'a mut [u8]) -> Result<Self> {
+        let mut len = 0;
+
+        for &arc in arcs {
+            let arc_len = arc_len(arc);
+            let end = len + arc_len;
+            let bytes = buf.get_mut(len..end).ok_or(ErrorKind::Overlength)?;
+
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                let shift = 7 * (arc_len - i - 1);
+                *byte = (arc >> shift) as u8 & !CONTINUATION_FLAG;
+
+                if shift != 0 {
+                    *byte |= CONTINUATION_FLAG;
+                }
+            }
+
+            len = end;
+        }
+
+        Self::new(&buf[..len])
+    }
+
+    /// Iterate over the arcs of this relative OID.
+    pub fn arcs(&self) -> RelativeOidArcs<'a> {
+        RelativeOidArcs {
+            bytes: self.as_bytes(),
+        }
+    }
+
+    /// Borrow the encoded arcs as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the encoded arcs.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the encoding empty?
+    ///
+    /// Always `false` for a valid `RELATIVE-OID`, which has at least one arc.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for RelativeOid<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for RelativeOid<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for RelativeOid<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> FixedTag for RelativeOid<'a> {
+    const TAG: Tag = Tag::RelativeOid;
+}
+
+impl OrdIsValueOrd for RelativeOid<'_> {}
+
+impl<'a> From<&RelativeOid<'a>> for RelativeOid<'a> {
+    fn from(value: &RelativeOid<'a>) -> RelativeOid<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for RelativeOid<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<RelativeOid<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<RelativeOid<'a>> for Any<'a> {
+    fn from(relative_oid: RelativeOid<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::RelativeOid, relative_oid.inner)
+    }
+}
+
+impl<'a> fmt::Display for RelativeOid<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arc) in self.arcs().enumerate() {
+            if i != 0 {
+                f.write_str(".")?;
+            }
+
+            write!(f, "{}", arc)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Debug for RelativeOid<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RelativeOid({})", self)
+    }
+}
+
+/// Iterator over the arcs of a [`RelativeOid`].
+#[derive(Clone, Debug)]
+pub struct RelativeOidArcs<'a> {
+    /// Remaining encoded arcs.
+    bytes: &'a [u8],
+}
+
+impl Iterator for RelativeOidArcs<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let mut arc = 0u32;
+
+        // Arcs were validated by `RelativeOid::new`
+        while let Some((&byte, rest)) = self.bytes.split_first() {
+            self.bytes = rest;
+            arc = (arc << 7) | u32::from(byte & !CONTINUATION_FLAG);
+
+            if byte & CONTINUATION_FLAG == 0 {
+                return Some(arc);
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for RelativeOidArcs<'_> {}
+
+/// Number of bytes needed to encode an arc.
+fn arc_len(arc: u32) -> usize {
+    match arc {
+        0..=0x7F => 1,
+        0x80..=0x3FFF => 2,
+        0x4000..=0x1F_FFFF => 3,
+        0x20_0000..=0xFFF_FFFF => 4,
+        _ => MAX_ARC_LEN,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RelativeOid;
+    use crate::{Decodable, Encodable, Result};
+    use hex_literal::hex;
+
+    /// `RELATIVE-OID` 8571.3.2 (example from X.690 § 8.20.5)
+    const EXAMPLE: &[u8] = &hex!("0D04 C27B0302");
+
+    #[test]
+    fn decode_arcs() -> Result<()> {
+        let relative_oid = RelativeOid::from_der(EXAMPLE)?;
+        assert!(relative_oid.arcs().eq([8571, 3, 2]));
+        Ok(())
+    }
+
+    #[test]
+    fn from_arcs_round_trip() -> Result<()> {
+        let mut buf = [0u8; 16];
+        let relative_oid = RelativeOid::from_arcs_in(&[8571, 3, 2], &mut buf)?;
+
+        let mut der = [0u8; 6];
+        assert_eq!(relative_oid.encode_to_slice(&mut der)?, EXAMPLE);
+
+        let mut buf = [0u8; 16];
+        let relative_oid = RelativeOid::from_arcs_in(&[0, u32::MAX], &mut buf)?;
+        assert_eq!(relative_oid.as_bytes(), hex!("00 8FFFFFFF7F"));
+        assert!(relative_oid.arcs().eq([0, u32::MAX]));
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display() -> Result<()> {
+        use alloc::string::ToString;
+        assert_eq!(RelativeOid::from_der(EXAMPLE)?.to_string(), "8571.3.2");
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for der in [
+            // Empty
+            &hex!("0D00")[..],
+            // Truncated arc
+            &hex!("0D02 0381"),
+            // Non-minimal arc
+            &hex!("0D02 8001"),
+            // Arc overflowing a `u32`
+            &hex!("0D05 9FFFFFFF7F"),
+            &hex!("0D06 818080808000"),
+        ] {
+            assert!(RelativeOid::from_der(der).is_err(), "{:02X?}", der);
+        }
+    }
+}
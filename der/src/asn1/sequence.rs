@@ -20,6 +20,20 @@ pub trait Sequence<'a>: Decodable<'a> {
     fn fields<F, T>(&self, f: F) -> Result<T>
     where
         F: FnOnce(&[&dyn Encodable]) -> Result<T>;
+
+    /// Compute the total encoded length of the fields of this `SEQUENCE`,
+    /// i.e. the length of its value.
+    ///
+    /// The default implementation sums the lengths of the trait objects
+    /// passed by [`Sequence::fields`]. The custom derive overrides it to
+    /// compute each field's length directly, avoiding dynamic dispatch.
+    fn fields_len(&self) -> Result<Length> {
+        self.fields(|fields| {
+            fields
+                .iter()
+                .fold(Ok(Length::ZERO), |len, field| len + field.encoded_len()?)
+        })
+    }
 }
 
 impl<'a, M> EncodeValue for M
@@ -27,11 +41,7 @@ where
     M: Sequence<'a>,
 {
     fn value_len(&self) -> Result<Length> {
-        self.fields(|fields| {
-            fields
-                .iter()
-                .fold(Ok(Length::ZERO), |len, field| len + field.encoded_len()?)
-        })
+        self.fields_len()
     }
 
     fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
@@ -60,6 +70,9 @@ pub struct SequenceRef<'a> {
 
     /// Offset location in the outer document where this `SEQUENCE` begins.
     offset: Length,
+
+    /// Whether the outer decoder accepts lenient `GeneralizedTime` values.
+    lenient_generalized_time: bool,
 }
 
 impl<'a> SequenceRef<'a> {
@@ -68,7 +81,8 @@ impl<'a> SequenceRef<'a> {
     where
         F: FnOnce(&mut Decoder<'a>) -> Result<T>,
     {
-        let mut nested_decoder = Decoder::new_with_offset(self.body, self.offset);
+        let mut nested_decoder =
+            Decoder::new_with_offset(self.body, self.offset, self.lenient_generalized_time);
         let result = f(&mut nested_decoder)?;
         nested_decoder.finish(result)
     }
@@ -77,8 +91,13 @@ impl<'a> SequenceRef<'a> {
 impl<'a> DecodeValue<'a> for SequenceRef<'a> {
     fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
         let offset = decoder.position();
+        let lenient_generalized_time = decoder.is_lenient_generalized_time();
         let body = ByteSlice::decode_value(decoder, header)?;
-        Ok(Self { body, offset })
+        Ok(Self {
+            body,
+            offset,
+            lenient_generalized_time,
+        })
     }
 }
 
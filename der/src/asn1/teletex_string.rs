@@ -0,0 +1,200 @@
+//! ASN.1 `TeletexString` support.
+
+use crate::{
+    asn1::Any, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error,
+    FixedTag, Header, Length, Result, Tag,
+};
+use core::{
+    fmt::{self, Write},
+    iter::FusedIterator,
+    str,
+};
+
+/// ASN.1 `TeletexString` type, also known as `T61String`.
+///
+/// The character set of [ITU-T T.61] is rarely implemented faithfully:
+/// in practice, the CAs which still issue certificates containing
+/// `TeletexString` values almost always fill them with ASCII or Latin-1
+/// (ISO 8859-1) text. Accordingly, this type accepts any contents when
+/// decoding, and offers two conversions:
+///
+/// - [`TeletexString::as_str`] is strict, succeeding only for ASCII
+///   contents, on which T.61 and all of its common interpretations agree.
+/// - [`TeletexString::chars_lossy`] interprets the contents as Latin-1,
+///   which never fails but may misinterpret characters which T.61 encodes
+///   differently. This is also what the [`Display`][`fmt::Display`] impl
+///   uses.
+///
+/// This type is intended to allow parsing legacy data: for new structures,
+/// use [`Utf8String`][`crate::asn1::Utf8String`].
+///
+/// [ITU-T T.61]: https://www.itu.int/rec/T-REC-T.61
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct TeletexString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> TeletexString<'a> {
+    /// Create a new `TeletexString` from its contents.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        ByteSlice::new(input.as_ref())
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Borrow the string as a `str`, if it only contains ASCII characters.
+    pub fn as_str(&self) -> Result<&'a str> {
+        let bytes = self.as_bytes();
+
+        if !bytes.is_ascii() {
+            return Err(Self::TAG.value_error());
+        }
+
+        str::from_utf8(bytes).map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Iterate over the characters of this string, interpreting it as
+    /// Latin-1 (ISO 8859-1).
+    pub fn chars_lossy(&self) -> TeletexChars<'a> {
+        TeletexChars {
+            bytes: self.as_bytes().iter(),
+        }
+    }
+
+    /// Borrow the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for TeletexString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for TeletexString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for TeletexString<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> FixedTag for TeletexString<'a> {
+    const TAG: Tag = Tag::TeletexString;
+}
+
+impl OrdIsValueOrd for TeletexString<'_> {}
+
+impl<'a> From<&TeletexString<'a>> for TeletexString<'a> {
+    fn from(value: &TeletexString<'a>) -> TeletexString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for TeletexString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<TeletexString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<TeletexString<'a>> for Any<'a> {
+    fn from(teletex_string: TeletexString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::TeletexString, teletex_string.inner)
+    }
+}
+
+impl<'a> From<TeletexString<'a>> for &'a [u8] {
+    fn from(teletex_string: TeletexString<'a>) -> &'a [u8] {
+        teletex_string.as_bytes()
+    }
+}
+
+impl<'a> fmt::Display for TeletexString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars_lossy().try_for_each(|c| f.write_char(c))
+    }
+}
+
+impl<'a> fmt::Debug for TeletexString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TeletexString(\"")?;
+        self.chars_lossy()
+            .flat_map(|c| c.escape_debug())
+            .try_for_each(|c| f.write_char(c))?;
+        f.write_str("\")")
+    }
+}
+
+/// Iterator over the characters of a [`TeletexString`], interpreted as
+/// Latin-1 (ISO 8859-1).
+#[derive(Clone, Debug)]
+pub struct TeletexChars<'a> {
+    /// Remaining bytes.
+    bytes: core::slice::Iter<'a, u8>,
+}
+
+impl Iterator for TeletexChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.bytes.next().map(|&byte| char::from(byte))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bytes.size_hint()
+    }
+}
+
+impl ExactSizeIterator for TeletexChars<'_> {}
+
+impl FusedIterator for TeletexChars<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::TeletexString;
+    use crate::{Decodable, Result};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_ascii() -> Result<()> {
+        let teletex_string = TeletexString::from_der(&hex!("14 07 45 78 61 6d 70 6c 65"))?;
+        assert_eq!(teletex_string.as_str()?, "Example");
+        assert!(teletex_string.chars_lossy().eq("Example".chars()));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_latin1() -> Result<()> {
+        // "Zürich", with "ü" encoded as Latin-1 0xFC
+        let teletex_string = TeletexString::from_der(&hex!("14 06 5a fc 72 69 63 68"))?;
+        assert!(teletex_string.as_str().is_err());
+        assert!(teletex_string.chars_lossy().eq("Z\u{FC}rich".chars()));
+        Ok(())
+    }
+}
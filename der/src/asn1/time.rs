@@ -0,0 +1,240 @@
+//! ASN.1 `TIME` support.
+
+use crate::{
+    asn1::Any, ord::OrdIsValueOrd, ByteSlice, Decodable, DecodeValue, Decoder, Encodable,
+    EncodeValue, Encoder, Error, ErrorKind, FixedTag, Header, Length, Result, StrSlice, Tag,
+};
+use core::{fmt, str};
+
+/// ASN.1 `TIME` type, as introduced in X.680 (2008).
+///
+/// `TIME` values are ISO 8601 strings, which can describe dates, times of
+/// day, date-times, durations, intervals and recurrences. This type checks
+/// that values only contain characters used by ISO 8601, but doesn't
+/// parse them further: the common cases can be parsed with
+/// [`Date`][`crate::asn1::Date`], [`TimeOfDay`][`crate::asn1::TimeOfDay`]
+/// and [`IsoDuration`][`crate::asn1::IsoDuration`].
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Time<'a> {
+    /// Inner value
+    inner: StrSlice<'a>,
+}
+
+impl<'a> Time<'a> {
+    /// Create a new ASN.1 `TIME`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        // Validate all characters are used by ISO 8601
+        if input.is_empty() || !input.iter().all(|&c| is_iso8601_char(c)) {
+            return Err(Self::TAG.value_error());
+        }
+
+        StrSlice::from_bytes(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Borrow the string as a `str`.
+    pub fn as_str(&self) -> &'a str {
+        self.inner.as_str()
+    }
+
+    /// Borrow the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<str> for Time<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for Time<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for Time<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for Time<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> FixedTag for Time<'a> {
+    const TAG: Tag = Tag::Time;
+}
+
+impl OrdIsValueOrd for Time<'_> {}
+
+impl<'a> From<&Time<'a>> for Time<'a> {
+    fn from(value: &Time<'a>) -> Time<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for Time<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Time<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<Time<'a>> for Any<'a> {
+    fn from(time: Time<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::Time, time.inner.into())
+    }
+}
+
+impl<'a> fmt::Display for Time<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> fmt::Debug for Time<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Time({:?})", self.as_str())
+    }
+}
+
+/// Is this character used in ISO 8601 representations?
+fn is_iso8601_char(c: u8) -> bool {
+    matches!(
+        c,
+        b'0'..=b'9'
+            | b'+'
+            | b'-'
+            | b':'
+            | b'.'
+            | b','
+            | b'/'
+            | b'D'
+            | b'H'
+            | b'M'
+            | b'P'
+            | b'R'
+            | b'S'
+            | b'T'
+            | b'W'
+            | b'Y'
+            | b'Z'
+    )
+}
+
+/// Universal tag of a subtype of `TIME`.
+///
+/// `DATE`, `TIME-OF-DAY` and `DURATION` have universal tag numbers above 30,
+/// so their identifiers use the multi-byte form: a leading `0x1F` octet
+/// followed by the tag number. [`Tag`] only represents single-octet
+/// identifiers, so these types are encoded and decoded with this helper
+/// rather than through [`FixedTag`], and errors in their values are reported
+/// with the `TIME` tag.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SubtypeTag(u8);
+
+impl SubtypeTag {
+    /// Universal tag of `DATE`.
+    pub(crate) const DATE: Self = Self(31);
+
+    /// Universal tag of `TIME-OF-DAY`.
+    pub(crate) const TIME_OF_DAY: Self = Self(32);
+
+    /// Universal tag of `DURATION`.
+    pub(crate) const DURATION: Self = Self(34);
+
+    /// Leading identifier octet of a universal tag number above 30.
+    const LEADING_OCTET: u8 = 0x1F;
+
+    /// Decode a value with this tag.
+    pub(crate) fn decode<'a, T: DecodeValue<'a>>(self, decoder: &mut Decoder<'a>) -> Result<T> {
+        let leading_octet = decoder.byte()?;
+
+        if leading_octet != Self::LEADING_OCTET {
+            let kind = match Tag::try_from(leading_octet) {
+                Ok(actual) => ErrorKind::TagUnexpected {
+                    expected: None,
+                    actual,
+                },
+                Err(err) => err.kind(),
+            };
+
+            return Err(decoder.error(kind));
+        }
+
+        if decoder.byte()? != self.0 {
+            return Err(decoder.error(ErrorKind::TagNumberInvalid));
+        }
+
+        let length = Length::decode(decoder)?;
+        T::decode_value(
+            decoder,
+            Header {
+                tag: Tag::Time,
+                length,
+            },
+        )
+    }
+
+    /// Compute the length of the encoding of a value with this tag.
+    pub(crate) fn encoded_len(self, value: &impl EncodeValue) -> Result<Length> {
+        let value_len = value.value_len()?;
+        (value_len.encoded_len()? + value_len)? + 2u8
+    }
+
+    /// Encode a value with this tag.
+    pub(crate) fn encode(self, value: &impl EncodeValue, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(&[Self::LEADING_OCTET, self.0])?;
+        value.value_len()?.encode(encoder)?;
+        value.encode_value(encoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Time;
+    use crate::{Decodable, Result};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() -> Result<()> {
+        // 2012-12-21T12:00Z/P1D
+        let time = Time::from_der(&hex!("0E 15 323031322D31322D32315431323A30305A2F503144"))?;
+        assert_eq!(time.as_str(), "2012-12-21T12:00Z/P1D");
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid_chars() {
+        assert!(Time::new("").is_err());
+        assert!(Time::new("2012-12-21 12:00").is_err());
+        assert!(Time::new("tomorrow").is_err());
+    }
+}
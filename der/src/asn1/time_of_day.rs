@@ -0,0 +1,164 @@
+//! ASN.1 `TIME-OF-DAY` support.
+
+use crate::{
+    asn1::{time::SubtypeTag, Time},
+    datetime,
+    ord::OrdIsValueOrd,
+    ByteSlice, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, Header,
+    Length, Result, Tag,
+};
+use core::fmt;
+
+/// ASN.1 `TIME-OF-DAY` type: a local time of day in the `HH:MM:SS` format.
+///
+/// `TIME-OF-DAY` is defined as a subtype of `TIME` with the universal tag
+/// 32, so its identifier octets are `1F 20`. As with
+/// [`Date`][`crate::asn1::Date`], this type doesn't impl
+/// [`FixedTag`][`crate::FixedTag`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct TimeOfDay {
+    /// Hour (0-23)
+    hour: u8,
+
+    /// Minutes (0-59)
+    minutes: u8,
+
+    /// Seconds (0-59)
+    seconds: u8,
+}
+
+impl TimeOfDay {
+    /// Length of an ASN.1 DER-encoded [`TimeOfDay`].
+    pub const LENGTH: Length = Length::new(8);
+
+    /// Create a new [`TimeOfDay`] from the given components.
+    pub fn new(hour: u8, minutes: u8, seconds: u8) -> Result<Self> {
+        if hour > 23 || minutes > 59 || seconds > 59 {
+            return Err(Tag::Time.value_error());
+        }
+
+        Ok(Self {
+            hour,
+            minutes,
+            seconds,
+        })
+    }
+
+    /// Get the hour.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Get the minutes.
+    pub fn minutes(&self) -> u8 {
+        self.minutes
+    }
+
+    /// Get the seconds.
+    pub fn seconds(&self) -> u8 {
+        self.seconds
+    }
+
+    /// Parse a [`TimeOfDay`] from its `HH:MM:SS` representation.
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        match *bytes {
+            [hour1, hour2, b':', min1, min2, b':', sec1, sec2] => Self::new(
+                datetime::decode_decimal(Tag::Time, hour1, hour2)?,
+                datetime::decode_decimal(Tag::Time, min1, min2)?,
+                datetime::decode_decimal(Tag::Time, sec1, sec2)?,
+            ),
+            _ => Err(Tag::Time.value_error()),
+        }
+    }
+}
+
+impl DecodeValue<'_> for TimeOfDay {
+    fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
+        Self::from_bytes(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for TimeOfDay {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Self::LENGTH)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        datetime::encode_decimal(encoder, Tag::Time, self.hour)?;
+        encoder.byte(b':')?;
+        datetime::encode_decimal(encoder, Tag::Time, self.minutes)?;
+        encoder.byte(b':')?;
+        datetime::encode_decimal(encoder, Tag::Time, self.seconds)
+    }
+}
+
+impl OrdIsValueOrd for TimeOfDay {}
+
+impl Decodable<'_> for TimeOfDay {
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
+        SubtypeTag::TIME_OF_DAY.decode(decoder)
+    }
+}
+
+impl Encodable for TimeOfDay {
+    fn encoded_len(&self) -> Result<Length> {
+        SubtypeTag::TIME_OF_DAY.encoded_len(self)
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        SubtypeTag::TIME_OF_DAY.encode(self, encoder)
+    }
+}
+
+impl TryFrom<Time<'_>> for TimeOfDay {
+    type Error = Error;
+
+    fn try_from(time: Time<'_>) -> Result<TimeOfDay> {
+        Self::from_bytes(time.as_bytes())
+    }
+}
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.hour, self.minutes, self.seconds
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimeOfDay;
+    use crate::{Decodable, Encodable, ErrorKind, Result};
+    use hex_literal::hex;
+
+    /// `TIME-OF-DAY` 12:30:05
+    const EXAMPLE: &[u8] = &hex!("1F20 08 31323A33303A3035");
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let time_of_day = TimeOfDay::from_der(EXAMPLE)?;
+        assert_eq!(time_of_day, TimeOfDay::new(12, 30, 5)?);
+
+        let mut buf = [0u8; 11];
+        assert_eq!(time_of_day.encode_to_slice(&mut buf)?, EXAMPLE);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid() {
+        assert!(TimeOfDay::new(24, 0, 0).is_err());
+        assert!(TimeOfDay::from_der(&hex!("1F20 06 313233303035")).is_err());
+        assert!(TimeOfDay::from_der(&hex!("1F20 08 31323A36303A3030")).is_err());
+    }
+
+    #[test]
+    fn reject_other_tags() {
+        assert!(TimeOfDay::from_der(&hex!("0E08 31323A33303A3035")).is_err());
+
+        let err = TimeOfDay::from_der(&hex!("1F1F 08 31323A33303A3035")).err();
+        assert_eq!(err.map(|e| e.kind()), Some(ErrorKind::TagNumberInvalid));
+    }
+}
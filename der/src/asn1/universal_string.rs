@@ -0,0 +1,218 @@
+//! ASN.1 `UniversalString` support.
+
+use crate::{
+    asn1::Any, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error,
+    FixedTag, Header, Length, Result, Tag,
+};
+use core::{
+    fmt::{self, Write},
+    iter::FusedIterator,
+};
+
+/// ASN.1 `UniversalString` type.
+///
+/// Contains characters encoded as UCS-4: four big endian octets per
+/// character, i.e. UTF-32BE.
+///
+/// Legacy encoders don't always produce valid UCS-4, so this type accepts
+/// any contents when decoding, and offers two conversions:
+///
+/// - [`UniversalString::chars`] is strict, returning an error if the
+///   contents aren't a whole number of valid Unicode characters.
+/// - [`UniversalString::chars_lossy`] never fails, substituting
+///   [`char::REPLACEMENT_CHARACTER`] for invalid characters. This is also
+///   what the [`Display`][`fmt::Display`] impl uses.
+///
+/// This type is intended to allow parsing legacy data: for new structures,
+/// use [`Utf8String`][`crate::asn1::Utf8String`].
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct UniversalString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> UniversalString<'a> {
+    /// Create a new `UniversalString` from its contents.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        ByteSlice::new(input.as_ref())
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Iterate over the characters of this string, returning an error if it
+    /// isn't valid UCS-4.
+    pub fn chars(&self) -> Result<UniversalChars<'a>> {
+        let chars = self.chars_lossy();
+
+        if self.as_bytes().len() % 4 != 0 {
+            return Err(Self::TAG.length_error());
+        }
+
+        if chars.clone().any(|c| c == char::REPLACEMENT_CHARACTER) {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(chars)
+    }
+
+    /// Iterate over the characters of this string, replacing invalid ones
+    /// (including an incomplete character at the end) with
+    /// [`char::REPLACEMENT_CHARACTER`].
+    pub fn chars_lossy(&self) -> UniversalChars<'a> {
+        UniversalChars {
+            bytes: self.as_bytes(),
+        }
+    }
+
+    /// Borrow the UCS-4 encoding of the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for UniversalString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for UniversalString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for UniversalString<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> FixedTag for UniversalString<'a> {
+    const TAG: Tag = Tag::UniversalString;
+}
+
+impl OrdIsValueOrd for UniversalString<'_> {}
+
+impl<'a> From<&UniversalString<'a>> for UniversalString<'a> {
+    fn from(value: &UniversalString<'a>) -> UniversalString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for UniversalString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<UniversalString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<UniversalString<'a>> for Any<'a> {
+    fn from(universal_string: UniversalString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::UniversalString, universal_string.inner)
+    }
+}
+
+impl<'a> From<UniversalString<'a>> for &'a [u8] {
+    fn from(universal_string: UniversalString<'a>) -> &'a [u8] {
+        universal_string.as_bytes()
+    }
+}
+
+impl<'a> fmt::Display for UniversalString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars_lossy().try_for_each(|c| f.write_char(c))
+    }
+}
+
+impl<'a> fmt::Debug for UniversalString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("UniversalString(\"")?;
+        self.chars_lossy()
+            .flat_map(|c| c.escape_debug())
+            .try_for_each(|c| f.write_char(c))?;
+        f.write_str("\")")
+    }
+}
+
+/// Iterator over the characters of a [`UniversalString`].
+#[derive(Clone, Debug)]
+pub struct UniversalChars<'a> {
+    /// Remaining UCS-4 encoded bytes.
+    bytes: &'a [u8],
+}
+
+impl Iterator for UniversalChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match *self.bytes {
+            [] => None,
+            [a, b, c, d, ref rest @ ..] => {
+                self.bytes = rest;
+                Some(
+                    char::from_u32(u32::from_be_bytes([a, b, c, d]))
+                        .unwrap_or(char::REPLACEMENT_CHARACTER),
+                )
+            }
+            _ => {
+                self.bytes = &[];
+                Some(char::REPLACEMENT_CHARACTER)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.bytes.len() + 3) / 4;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for UniversalChars<'_> {}
+
+impl FusedIterator for UniversalChars<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::UniversalString;
+    use crate::{Decodable, Result};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() -> Result<()> {
+        // "A€😀"
+        let universal_string =
+            UniversalString::from_der(&hex!("1C 0C 00000041 000020AC 0001F600"))?;
+        assert!(universal_string.chars()?.eq("A\u{20AC}\u{1F600}".chars()));
+        Ok(())
+    }
+
+    #[test]
+    fn lossy_conversion() -> Result<()> {
+        // "A", a surrogate, then an incomplete character
+        let universal_string = UniversalString::from_der(&hex!("1C 0A 00000041 0000D800 0000"))?;
+        assert!(universal_string.chars().is_err());
+        assert!(universal_string
+            .chars_lossy()
+            .eq("A\u{FFFD}\u{FFFD}".chars()));
+        Ok(())
+    }
+}
@@ -4,16 +4,16 @@ use crate::{
     asn1::Any,
     datetime::{self, DateTime},
     ord::OrdIsValueOrd,
-    ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, FixedTag, Header, Length, Result,
-    Tag,
+    ByteSlice, Decodable, DecodeValue, Decoder, EncodeValue, Encoder, Error, FixedTag, Header,
+    Length, Result, Tag,
 };
 use core::time::Duration;
 
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
-/// Maximum year that can be represented as a `UTCTime`.
-pub const MAX_YEAR: u16 = 2049;
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
 
 /// ASN.1 `UTCTime` type.
 ///
@@ -37,9 +37,17 @@ impl UtcTime {
     /// Length of an RFC 5280-flavored ASN.1 DER-encoded [`UtcTime`].
     pub const LENGTH: Length = Length::new(13);
 
+    /// Two-digit year pivot specified by RFC 5280: two-digit years greater
+    /// than or equal to it are interpreted as `19YY`, and smaller ones as
+    /// `20YY`.
+    pub const PIVOT: u8 = 50;
+
+    /// Maximum year that can be represented as a [`UtcTime`].
+    pub const MAX_YEAR: u16 = 2049;
+
     /// Create a [`UtcTime`] from a [`DateTime`].
     pub fn from_date_time(datetime: DateTime) -> Result<Self> {
-        if datetime.year() <= MAX_YEAR {
+        if datetime.year() <= Self::MAX_YEAR {
             Ok(Self(datetime))
         } else {
             Err(Self::TAG.value_error())
@@ -77,33 +85,26 @@ impl UtcTime {
     pub fn to_system_time(&self) -> SystemTime {
         self.0.to_system_time()
     }
+
+    /// Decode an ASN.1 `UTCTime`, interpreting two-digit years greater than
+    /// or equal to `pivot` as `19YY`, and smaller ones as `20YY`, rather
+    /// than using the RFC 5280 [`UtcTime::PIVOT`].
+    ///
+    /// For example, a pivot of `100` interprets all years as `20YY`. The time
+    /// is returned as a [`DateTime`] as it may be beyond
+    /// [`UtcTime::MAX_YEAR`].
+    pub fn decode_with_pivot(decoder: &mut Decoder<'_>, pivot: u8) -> Result<DateTime> {
+        let header = Header::decode(decoder)?;
+        header.tag.assert_eq(Self::TAG)?;
+        decode_date_time(ByteSlice::decode_value(decoder, header)?.as_bytes(), pivot)
+    }
 }
 
 impl DecodeValue<'_> for UtcTime {
     fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
-        match *ByteSlice::decode_value(decoder, header)?.as_bytes() {
-            // RFC 5280 requires mandatory seconds and Z-normalized time zone
-            [year1, year2, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
-                let year = datetime::decode_decimal(Self::TAG, year1, year2)?;
-                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
-                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
-                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
-                let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
-                let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
-
-                // RFC 5280 rules for interpreting the year
-                let year = if year >= 50 {
-                    year as u16 + 1900
-                } else {
-                    year as u16 + 2000
-                };
-
-                DateTime::new(year, month, day, hour, minute, second)
-                    .map_err(|_| Self::TAG.value_error())
-                    .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
-            }
-            _ => Err(Self::TAG.value_error()),
-        }
+        let bytes = ByteSlice::decode_value(decoder, header)?.as_bytes();
+        let datetime = decode_date_time(bytes, Self::PIVOT)?;
+        Self::from_unix_duration(datetime.unix_duration())
     }
 }
 
@@ -185,10 +186,55 @@ impl TryFrom<Any<'_>> for UtcTime {
     }
 }
 
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<UtcTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(utc_time: UtcTime) -> Result<OffsetDateTime> {
+        utc_time.to_date_time().try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<OffsetDateTime> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<UtcTime> {
+        DateTime::try_from(time)?.try_into()
+    }
+}
+
+/// Decode the contents of a `UTCTime`, using the given two-digit year pivot.
+fn decode_date_time(bytes: &[u8], pivot: u8) -> Result<DateTime> {
+    match *bytes {
+        // RFC 5280 requires mandatory seconds and Z-normalized time zone
+        [year1, year2, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
+            let tag = UtcTime::TAG;
+            let year = datetime::decode_decimal(tag, year1, year2)?;
+            let month = datetime::decode_decimal(tag, mon1, mon2)?;
+            let day = datetime::decode_decimal(tag, day1, day2)?;
+            let hour = datetime::decode_decimal(tag, hour1, hour2)?;
+            let minute = datetime::decode_decimal(tag, min1, min2)?;
+            let second = datetime::decode_decimal(tag, sec1, sec2)?;
+
+            let year = if year >= pivot {
+                year as u16 + 1900
+            } else {
+                year as u16 + 2000
+            };
+
+            DateTime::new(year, month, day, hour, minute, second).map_err(|_| tag.value_error())
+        }
+        _ => Err(UtcTime::TAG.value_error()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::UtcTime;
-    use crate::{Decodable, Encodable, Encoder};
+    use crate::{Decodable, Decoder, Encodable, Encoder, Result};
     use hex_literal::hex;
 
     #[test]
@@ -202,4 +248,33 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn decode_with_pivot() -> Result<()> {
+        // 1991-05-06 or 2091-05-06
+        let example_bytes = hex!("17 0d 39 31 30 35 30 36 32 33 34 35 34 30 5a");
+
+        let datetime =
+            UtcTime::decode_with_pivot(&mut Decoder::new(&example_bytes)?, UtcTime::PIVOT)?;
+        assert_eq!(datetime, UtcTime::from_der(&example_bytes)?.to_date_time());
+
+        let datetime = UtcTime::decode_with_pivot(&mut Decoder::new(&example_bytes)?, 100)?;
+        assert_eq!(datetime.year(), 2091);
+        assert!(UtcTime::from_date_time(datetime).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn offset_date_time() -> Result<()> {
+        use time::{OffsetDateTime, UtcOffset};
+
+        let utc_time = UtcTime::from_unix_duration(core::time::Duration::from_secs(673573540))?;
+        let time = OffsetDateTime::try_from(utc_time)?;
+        assert_eq!(time.unix_timestamp(), 673573540);
+
+        let offset = UtcOffset::from_hms(2, 0, 0)?;
+        assert_eq!(UtcTime::try_from(time.to_offset(offset))?, utc_time);
+        Ok(())
+    }
 }
@@ -0,0 +1,152 @@
+//! ASN.1 `VisibleString` support.
+
+use crate::{
+    asn1::Any, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error,
+    FixedTag, Header, Length, Result, StrSlice, Tag,
+};
+use core::{fmt, str};
+
+/// ASN.1 `VisibleString` type, also known as `ISO646String`.
+///
+/// Supports the printable characters of ASCII, i.e. space and the
+/// characters from `!` to `~`, excluding control characters.
+///
+/// For UTF-8, use [`Utf8String`][`crate::asn1::Utf8String`]. For the full
+/// ASCII character set, use [`Ia5String`][`crate::asn1::Ia5String`].
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct VisibleString<'a> {
+    /// Inner value
+    inner: StrSlice<'a>,
+}
+
+impl<'a> VisibleString<'a> {
+    /// Create a new `VisibleString`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        // Validate all characters are within VisibleString's allowed set
+        if input.iter().any(|&c| !(0x20..=0x7E).contains(&c)) {
+            return Err(Self::TAG.value_error());
+        }
+
+        StrSlice::from_bytes(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Borrow the string as a `str`.
+    pub fn as_str(&self) -> &'a str {
+        self.inner.as_str()
+    }
+
+    /// Borrow the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<str> for VisibleString<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for VisibleString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for VisibleString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for VisibleString<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> FixedTag for VisibleString<'a> {
+    const TAG: Tag = Tag::VisibleString;
+}
+
+impl OrdIsValueOrd for VisibleString<'_> {}
+
+impl<'a> From<&VisibleString<'a>> for VisibleString<'a> {
+    fn from(value: &VisibleString<'a>) -> VisibleString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for VisibleString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<VisibleString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<VisibleString<'a>> for Any<'a> {
+    fn from(visible_string: VisibleString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::VisibleString, visible_string.inner.into())
+    }
+}
+
+impl<'a> From<VisibleString<'a>> for &'a [u8] {
+    fn from(visible_string: VisibleString<'a>) -> &'a [u8] {
+        visible_string.as_bytes()
+    }
+}
+
+impl<'a> fmt::Display for VisibleString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> fmt::Debug for VisibleString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "VisibleString({:?})", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VisibleString;
+    use crate::{Decodable, Result};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() -> Result<()> {
+        let visible_string =
+            VisibleString::from_der(&hex!("1a 0b 48 65 6c 6c 6f 2c 20 7e 21 3f 7b"))?;
+        assert_eq!(visible_string.as_str(), "Hello, ~!?{");
+        Ok(())
+    }
+
+    #[test]
+    fn reject_control_chars() {
+        assert!(VisibleString::new("tab\t").is_err());
+        assert!(VisibleString::new(&[0x41, 0x7F]).is_err());
+    }
+}
@@ -0,0 +1,222 @@
+//! Canonical Encoding Rules (CER) support.
+
+use crate::{Decodable, Decoder, Encodable, ErrorKind, Header, Result, Tag};
+use alloc::vec::Vec;
+
+/// Maximum nesting depth of constructed values.
+const MAX_DEPTH: usize = 32;
+
+/// Maximum number of contents octets of a primitive string encoding, and of
+/// each fragment of a constructed string encoding.
+const MAX_FRAGMENT_LEN: usize = 1000;
+
+/// Flag which marks a tag as constructed.
+const CONSTRUCTED_FLAG: u8 = 0b100000;
+
+/// Length octet introducing an indefinite length.
+const INDEFINITE_LENGTH: u8 = 0x80;
+
+/// End-of-contents octets terminating an indefinite-length value.
+const END_OF_CONTENTS: [u8; 2] = [0, 0];
+
+/// Transcode an ASN.1 DER document into CER, as described in X.690 § 9:
+///
+/// - constructed values use the indefinite-length form
+/// - strings with more than 1000 contents octets use the constructed form,
+///   split into primitive fragments of 1000 contents octets
+///
+/// Everything else is encoded identically in both. Note that the elements of
+/// a `SET OF` are kept in the order of their DER encodings, which may differ
+/// from the order of their CER encodings in rare cases.
+///
+/// Strings are identified by their universal tag, so the type of an
+/// IMPLICIT-tagged value (e.g. `[0] IMPLICIT OCTET STRING`) can't be known
+/// without the schema. Primitive values with a non-universal tag and more
+/// than 1000 contents octets are therefore rejected with
+/// [`ErrorKind::Length`], as they may be strings which need fragmenting.
+pub(crate) fn from_der(der: &[u8]) -> Result<Vec<u8>> {
+    let mut decoder = Decoder::new(der)?;
+    let mut cer = Vec::with_capacity(der.len());
+    transcode(&mut decoder, &mut cer, 0)?;
+    decoder.finish(cer)
+}
+
+/// Transcode the next value from DER into CER, appending it to `out`.
+fn transcode(decoder: &mut Decoder<'_>, out: &mut Vec<u8>, depth: usize) -> Result<()> {
+    let header = Header::decode(decoder)?;
+    let position = decoder.position();
+    let value = decoder.bytes(header.length)?;
+
+    if header.tag.is_constructed() {
+        if depth == MAX_DEPTH {
+            return Err(ErrorKind::Overlength.at(position));
+        }
+
+        out.extend_from_slice(&[header.tag.octet(), INDEFINITE_LENGTH]);
+
+        let mut nested = Decoder::new(value)?;
+
+        while !nested.is_finished() {
+            transcode(&mut nested, out, depth + 1).map_err(|e| e.nested(position))?;
+        }
+
+        out.extend_from_slice(&END_OF_CONTENTS);
+    } else if !header.tag.is_universal() && value.len() > MAX_FRAGMENT_LEN {
+        return Err(ErrorKind::Length { tag: header.tag }.at(position));
+    } else if is_string(header.tag) && value.len() > MAX_FRAGMENT_LEN {
+        out.extend_from_slice(&[header.tag.octet() | CONSTRUCTED_FLAG, INDEFINITE_LENGTH]);
+
+        if header.tag == Tag::BitString {
+            encode_bit_string_fragments(value, out).map_err(|e| e.nested(position))?;
+        } else {
+            for fragment in value.chunks(MAX_FRAGMENT_LEN) {
+                encode_primitive(header.tag, fragment, out)?;
+            }
+        }
+
+        out.extend_from_slice(&END_OF_CONTENTS);
+    } else {
+        encode_primitive(header.tag, value, out)?;
+    }
+
+    Ok(())
+}
+
+/// Split the contents of a `BIT STRING` into fragments.
+///
+/// Each fragment begins with its own count of unused bits, which is zero
+/// for all but the last.
+fn encode_bit_string_fragments(value: &[u8], out: &mut Vec<u8>) -> Result<()> {
+    let (&unused_bits, bytes) = value.split_first().ok_or(ErrorKind::Length {
+        tag: Tag::BitString,
+    })?;
+
+    let mut fragment = Vec::with_capacity(MAX_FRAGMENT_LEN);
+    let mut chunks = bytes.chunks(MAX_FRAGMENT_LEN - 1).peekable();
+
+    while let Some(chunk) = chunks.next() {
+        fragment.clear();
+        fragment.push(if chunks.peek().is_some() {
+            0
+        } else {
+            unused_bits
+        });
+        fragment.extend_from_slice(chunk);
+        encode_primitive(Tag::BitString, &fragment, out)?;
+    }
+
+    Ok(())
+}
+
+/// Encode a primitive value with a definite length.
+fn encode_primitive(tag: Tag, value: &[u8], out: &mut Vec<u8>) -> Result<()> {
+    let mut buf = [0u8; 6];
+    let header = Header::new(tag, value.len())?;
+    out.extend_from_slice(header.encode_to_slice(&mut buf)?);
+    out.extend_from_slice(value);
+    Ok(())
+}
+
+/// Is this the tag of a string type, which CER splits into fragments?
+fn is_string(tag: Tag) -> bool {
+    matches!(
+        tag,
+        Tag::BitString
+            | Tag::OctetString
+            | Tag::Utf8String
+            | Tag::NumericString
+            | Tag::PrintableString
+            | Tag::TeletexString
+            | Tag::Ia5String
+            | Tag::GraphicString
+            | Tag::VisibleString
+            | Tag::GeneralString
+            | Tag::UniversalString
+            | Tag::BmpString
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_der;
+    use crate::{
+        asn1::{ContextSpecific, OctetString},
+        Encodable, ErrorKind, Result, Tag, TagMode, TagNumber,
+    };
+    use alloc::vec::Vec;
+    use hex_literal::hex;
+
+    #[test]
+    fn indefinite_length_constructed() -> Result<()> {
+        // SEQUENCE { INTEGER 42, SEQUENCE { NULL } }
+        let cer = from_der(&hex!("3007 02012A 30020500"))?;
+        assert_eq!(cer, hex!("3080 02012A 30800500 0000 0000"));
+        Ok(())
+    }
+
+    #[test]
+    fn short_strings_unchanged() -> Result<()> {
+        let der = OctetString::new(&[0x55; 1000])?.to_vec()?;
+        assert_eq!(from_der(&der)?, der);
+        Ok(())
+    }
+
+    #[test]
+    fn octet_string_fragments() -> Result<()> {
+        let der = OctetString::new(&[0x55; 2001])?.to_vec()?;
+        let cer = from_der(&der)?;
+
+        let mut expected = Vec::from(hex!("2480"));
+        for len in [1000, 1000, 1] {
+            expected.extend_from_slice(&OctetString::new(&[0x55; 1000][..len])?.to_vec()?);
+        }
+        expected.extend_from_slice(&[0, 0]);
+
+        assert_eq!(cer, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn bit_string_fragments() -> Result<()> {
+        // BIT STRING with 1000 bytes of data, the last 4 bits of which are unused
+        let mut der = Vec::from(hex!("038203E9 04"));
+        der.extend_from_slice(&[0xF0; 1000]);
+        let cer = from_der(&der)?;
+
+        let mut expected = Vec::from(hex!("2380 038203E8 00"));
+        expected.extend_from_slice(&[0xF0; 999]);
+        expected.extend_from_slice(&hex!("030204F0 0000"));
+
+        assert_eq!(cer, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_long_implicit_values() -> Result<()> {
+        /// Encode `[0] IMPLICIT OCTET STRING`, which can't be told apart from
+        /// other types without the schema.
+        fn implicit_octet_string(bytes: &[u8]) -> Result<Vec<u8>> {
+            ContextSpecific {
+                tag_number: TagNumber::N0,
+                tag_mode: TagMode::Implicit,
+                value: OctetString::new(bytes)?,
+            }
+            .to_vec()
+        }
+
+        let der = implicit_octet_string(&[0x55; 1000])?;
+        assert_eq!(from_der(&der)?, der);
+
+        let der = implicit_octet_string(&[0x55; 1001])?;
+        assert_eq!(
+            from_der(&der).err().map(|e| e.kind()),
+            Some(ErrorKind::Length {
+                tag: Tag::ContextSpecific {
+                    constructed: false,
+                    number: TagNumber::N0,
+                }
+            })
+        );
+        Ok(())
+    }
+}
@@ -12,7 +12,7 @@ use core::{fmt, str::FromStr, time::Duration};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "time")]
-use time::PrimitiveDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime};
 
 /// Minimum year allowed in [`DateTime`] values.
 const MIN_YEAR: u16 = 1970;
@@ -352,6 +352,30 @@ impl TryFrom<PrimitiveDateTime> for DateTime {
     }
 }
 
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<DateTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: DateTime) -> Result<OffsetDateTime> {
+        let secs =
+            i64::try_from(time.unix_duration().as_secs()).map_err(|_| ErrorKind::DateTime)?;
+        Ok(OffsetDateTime::from_unix_timestamp(secs)?)
+    }
+}
+
+/// Converts the time to UTC, truncating it to whole seconds.
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<OffsetDateTime> for DateTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<DateTime> {
+        let secs = u64::try_from(time.unix_timestamp()).map_err(|_| ErrorKind::DateTime)?;
+        DateTime::from_unix_duration(Duration::from_secs(secs))
+    }
+}
+
 /// Decode 2-digit decimal value
 pub(crate) fn decode_decimal(tag: Tag, hi: u8, lo: u8) -> Result<u8> {
     if (b'0'..=b'9').contains(&hi) && (b'0'..=b'9').contains(&lo) {
@@ -17,11 +17,27 @@ pub trait Decodable<'a>: Sized {
     fn decode(decoder: &mut Decoder<'a>) -> Result<Self>;
 
     /// Parse `Self` from the provided DER-encoded byte slice.
+    ///
+    /// Returns [`ErrorKind::TrailingData`][`crate::ErrorKind::TrailingData`]
+    /// if any bytes remain after the message. Use
+    /// [`Decodable::from_der_partial`] to parse messages which are followed
+    /// by other data.
     fn from_der(bytes: &'a [u8]) -> Result<Self> {
         let mut decoder = Decoder::new(bytes)?;
         let result = Self::decode(&mut decoder)?;
         decoder.finish(result)
     }
+
+    /// Parse `Self` from the start of the provided DER-encoded byte slice,
+    /// returning it along with any remaining bytes which follow it.
+    ///
+    /// This makes it possible to parse a series of concatenated DER messages
+    /// which aren't enclosed in an outer `SEQUENCE` (e.g. certificate bundles).
+    fn from_der_partial(bytes: &'a [u8]) -> Result<(Self, &'a [u8])> {
+        let mut decoder = Decoder::new(bytes)?;
+        let result = Self::decode(&mut decoder)?;
+        Ok((result, decoder.remaining()?))
+    }
 }
 
 impl<'a, T> Decodable<'a> for T
@@ -21,6 +21,10 @@ pub struct Decoder<'a> {
     ///
     /// Used for nested decoding.
     offset: Length,
+
+    /// Accept `GeneralizedTime` values which don't follow the RFC 5280
+    /// profile.
+    lenient_generalized_time: bool,
 }
 
 impl<'a> Decoder<'a> {
@@ -30,6 +34,7 @@ impl<'a> Decoder<'a> {
             bytes: Some(ByteSlice::new(bytes)?),
             position: Length::ZERO,
             offset: Length::ZERO,
+            lenient_generalized_time: false,
         })
     }
 
@@ -37,14 +42,37 @@ impl<'a> Decoder<'a> {
     /// an original ASN.1 DER document.
     ///
     /// This is used for calculating positions when decoding nested documents.
-    pub(crate) fn new_with_offset(bytes: ByteSlice<'a>, offset: Length) -> Self {
+    pub(crate) fn new_with_offset(
+        bytes: ByteSlice<'a>,
+        offset: Length,
+        lenient_generalized_time: bool,
+    ) -> Self {
         Self {
             bytes: Some(bytes),
             position: Length::ZERO,
             offset,
+            lenient_generalized_time,
         }
     }
 
+    /// Accept [`GeneralizedTime`] values which don't follow the RFC 5280
+    /// profile, as with [`GeneralizedTime::decode_lenient`].
+    ///
+    /// This applies to values decoded by this decoder, including those
+    /// within `SEQUENCE`s, so that it can be used when decoding structures
+    /// like certificates which were issued by non-conforming CAs. It's off
+    /// by default.
+    pub fn with_lenient_generalized_time(mut self, lenient: bool) -> Self {
+        self.lenient_generalized_time = lenient;
+        self
+    }
+
+    /// Are [`GeneralizedTime`] values which don't follow the RFC 5280 profile
+    /// accepted?
+    pub fn is_lenient_generalized_time(&self) -> bool {
+        self.lenient_generalized_time
+    }
+
     /// Decode a value which impls the [`Decodable`] trait.
     pub fn decode<T: Decodable<'a>>(&mut self) -> Result<T> {
         if self.is_failed() {
@@ -180,6 +208,11 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `BMPString`.
+    pub fn bmp_string(&mut self) -> Result<BmpString<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `CONTEXT-SPECIFIC` field with the
     /// provided [`TagNumber`].
     pub fn context_specific<T>(
@@ -197,11 +230,21 @@ impl<'a> Decoder<'a> {
         .map(|field| field.value))
     }
 
+    /// Attempt to decode an ASN.1 `GeneralString`.
+    pub fn general_string(&mut self) -> Result<GeneralString<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `GeneralizedTime`.
     pub fn generalized_time(&mut self) -> Result<GeneralizedTime> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `GraphicString`.
+    pub fn graphic_string(&mut self) -> Result<GraphicString<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `IA5String`.
     pub fn ia5_string(&mut self) -> Result<Ia5String<'a>> {
         self.decode()
@@ -234,6 +277,26 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `RELATIVE-OID`.
+    pub fn relative_oid(&mut self) -> Result<RelativeOid<'a>> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `TeletexString`.
+    pub fn teletex_string(&mut self) -> Result<TeletexString<'a>> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `TIME`.
+    pub fn time(&mut self) -> Result<Time<'a>> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `UniversalString`.
+    pub fn universal_string(&mut self) -> Result<UniversalString<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `UTCTime`.
     pub fn utc_time(&mut self) -> Result<UtcTime> {
         self.decode()
@@ -244,6 +307,11 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `VisibleString`.
+    pub fn visible_string(&mut self) -> Result<VisibleString<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `SEQUENCE`, creating a new nested
     /// [`Decoder`] and calling the provided argument with it.
     pub fn sequence<F, T>(&mut self, f: F) -> Result<T>
@@ -314,7 +382,7 @@ impl<'a> Decoder<'a> {
 
     /// Obtain the remaining bytes in this decoder from the current cursor
     /// position.
-    fn remaining(&self) -> Result<&'a [u8]> {
+    pub(crate) fn remaining(&self) -> Result<&'a [u8]> {
         let pos = usize::try_from(self.position)?;
 
         match self.bytes.and_then(|slice| slice.as_bytes().get(pos..)) {
@@ -377,6 +445,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_der_partial() {
+        let (x, remaining) = i8::from_der_partial(EXAMPLE_MSG).unwrap();
+        assert_eq!(42i8, x);
+        assert_eq!(&[0x00], remaining);
+
+        // Two concatenated INTEGERs: 42, 7
+        let (x, remaining) = i8::from_der_partial(&hex!("02012A020107")).unwrap();
+        assert_eq!(42i8, x);
+        let (y, remaining) = i8::from_der_partial(remaining).unwrap();
+        assert_eq!(7i8, y);
+        assert!(remaining.is_empty());
+    }
+
     #[test]
     fn trailing_data() {
         let mut decoder = Decoder::new(EXAMPLE_MSG).unwrap();
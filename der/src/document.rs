@@ -59,12 +59,8 @@ pub trait Document<'a>: AsRef<[u8]> + Sized + TryFrom<Vec<u8>, Error = Error> {
     where
         Self: pem::PemLabel,
     {
-        let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
-
-        if label != Self::TYPE_LABEL {
-            return Err(pem::Error::Label.into());
-        }
-
+        pem::check_type_label(s.as_bytes(), Self::TYPE_LABEL)?;
+        let (_, der_bytes) = pem::decode_vec(s.as_bytes())?;
         der_bytes.try_into()
     }
 
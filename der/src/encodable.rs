@@ -45,6 +45,23 @@ pub trait Encodable {
         actual_len.try_into()
     }
 
+    /// Serialize this message as a byte vector using the Canonical Encoding
+    /// Rules (CER) rather than DER.
+    ///
+    /// CER differs from DER in that constructed values use indefinite
+    /// lengths, and strings longer than 1000 octets are split into
+    /// fragments. It's required by some timestamping and archival formats.
+    ///
+    /// Strings are recognized by their universal tag: primitive values with
+    /// any other tag and more than 1000 octets (e.g. a long
+    /// `[0] IMPLICIT OCTET STRING`) can't be transcoded without the schema,
+    /// and return [`ErrorKind::Length`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_cer(&self) -> Result<Vec<u8>> {
+        crate::cer::from_der(&self.to_vec()?)
+    }
+
     /// Serialize this message as a byte vector.
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -163,6 +163,58 @@ impl<'a> Encoder<'a> {
         }
     }
 
+    /// Encode an ASN.1 `SEQUENCE` whose length isn't known in advance.
+    ///
+    /// Unlike [`Encoder::sequence`], the caller doesn't need to compute the
+    /// length of the contents up front. The contents are encoded by `f`
+    /// into the buffer after room for the largest possible header, then
+    /// moved into place once their length is known.
+    ///
+    /// The buffer needs up to 4 bytes of headroom beyond the final encoding
+    /// while the contents are being encoded.
+    pub fn sequence_streaming<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Encoder<'_>) -> Result<()>,
+    {
+        /// Tag byte plus the longest length encoding.
+        const MAX_HEADER_LEN: usize = 6;
+
+        if self.is_failed() {
+            self.error(ErrorKind::Failed)?;
+        }
+
+        let start = usize::try_from(self.position)?;
+        let body_start = start + MAX_HEADER_LEN;
+
+        let body = self
+            .bytes
+            .as_mut()
+            .and_then(|bytes| bytes.get_mut(body_start..))
+            .ok_or_else(|| ErrorKind::Overlength.at(self.position));
+
+        let body_len = body
+            .and_then(|body| {
+                let mut nested_encoder = Encoder::new(body);
+                f(&mut nested_encoder)?;
+                Ok(nested_encoder.finish()?.len())
+            })
+            .map_err(|e| {
+                self.bytes.take();
+                e.nested(self.position)
+            })?;
+
+        let length = Length::try_from(body_len)?;
+        Header::new(Tag::Sequence, length).and_then(|header| header.encode(self))?;
+
+        let header_end = usize::try_from(self.position)?;
+        if let Some(bytes) = self.bytes.as_mut() {
+            bytes.copy_within(body_start..(body_start + body_len), header_end);
+        }
+
+        self.reserve(length)?;
+        Ok(())
+    }
+
     /// Encode the provided value as an ASN.1 `UTCTime`
     pub fn utc_time(&mut self, value: impl TryInto<UtcTime>) -> Result<()> {
         value
@@ -249,7 +301,10 @@ impl<'a> Encoder<'a> {
 mod tests {
     use hex_literal::hex;
 
-    use crate::{asn1::BitString, Encodable, ErrorKind, Length, TagMode, TagNumber};
+    use crate::{
+        asn1::{BitString, OctetString},
+        Encodable, ErrorKind, Length, Result, TagMode, TagNumber,
+    };
 
     use super::Encoder;
 
@@ -262,6 +317,49 @@ mod tests {
         assert_eq!(err.position(), Some(Length::ZERO));
     }
 
+    #[test]
+    fn sequence_streaming() -> Result<()> {
+        // SEQUENCE { INTEGER 1, SEQUENCE { BOOLEAN TRUE } }
+        const EXPECTED_BYTES: &[u8] = &hex!("300802010130030101FF");
+
+        let mut buf = [0u8; 32];
+        let mut encoder = Encoder::new(&mut buf);
+        encoder.sequence_streaming(|encoder| {
+            encoder.encode(&1u8)?;
+            encoder.sequence_streaming(|encoder| encoder.encode(&true))
+        })?;
+
+        assert_eq!(EXPECTED_BYTES, encoder.finish()?);
+        Ok(())
+    }
+
+    #[test]
+    fn sequence_streaming_long_form() -> Result<()> {
+        let contents = [0xAAu8; 200];
+        let mut buf = [0u8; 256];
+        let mut encoder = Encoder::new(&mut buf);
+        encoder.encode(&false)?;
+        encoder.sequence_streaming(|encoder| encoder.encode(&OctetString::new(&contents[..])?))?;
+
+        let encoded = encoder.finish()?;
+        assert_eq!(&encoded[..9], &hex!("010100 3081CB 0481C8"));
+        assert_eq!(&encoded[9..], &contents[..]);
+        Ok(())
+    }
+
+    #[test]
+    fn sequence_streaming_overlength() {
+        // The final 8-byte encoding fits, but not with headroom for the
+        // largest possible header while encoding the contents
+        let mut buf = [0u8; 8];
+        let mut encoder = Encoder::new(&mut buf);
+        let result =
+            encoder.sequence_streaming(|encoder| encoder.encode(&OctetString::new(&[0u8; 4][..])?));
+
+        assert_eq!(result.map_err(|e| e.kind()), Err(ErrorKind::Overlength));
+        assert!(encoder.is_failed());
+    }
+
     #[test]
     fn context_specific_with_implicit_field() {
         // From RFC8410 Section 10.3:
@@ -0,0 +1,87 @@
+//! Impls for heap-allocated and reference-counted values: [`Box`], [`Rc`],
+//! and [`Arc`].
+//!
+//! These make it possible to use such values as fields of structs which
+//! derive [`Sequence`] or [`Choice`][`crate::Choice`], e.g. when building
+//! recursive structures.
+//!
+//! `Rc<T>` and `Arc<T>` can be used with any `T` which impls
+//! [`DecodeValue`], [`EncodeValue`], and [`FixedTag`].
+//!
+//! `Box<T>` is limited to `T` which impls [`Sequence`]: because `Box` is a
+//! `#[fundamental]` type, impls of [`EncodeValue`] and [`FixedTag`] for
+//! `Box<T>` would overlap with the blanket impls for [`Sequence`] types.
+
+use crate::{
+    DecodeValue, Decoder, Encodable, EncodeValue, Encoder, FixedTag, Header, Length, Result,
+    Sequence, Tag, ValueOrd,
+};
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+use core::cmp::Ordering;
+
+impl<'a, T> DecodeValue<'a> for Box<T>
+where
+    T: DecodeValue<'a>,
+{
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        T::decode_value(decoder, header).map(Box::new)
+    }
+}
+
+impl<'a, T> Sequence<'a> for Box<T>
+where
+    T: DecodeValue<'a> + Sequence<'a>,
+{
+    fn fields<F, U>(&self, f: F) -> Result<U>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<U>,
+    {
+        T::fields(self, f)
+    }
+}
+
+macro_rules! impl_shared_pointer {
+    ($($ptr:ident),+) => {
+        $(
+            impl<'a, T> DecodeValue<'a> for $ptr<T>
+            where
+                T: DecodeValue<'a>,
+            {
+                fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+                    T::decode_value(decoder, header).map($ptr::new)
+                }
+            }
+
+            impl<T> EncodeValue for $ptr<T>
+            where
+                T: EncodeValue,
+            {
+                fn value_len(&self) -> Result<Length> {
+                    T::value_len(self)
+                }
+
+                fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+                    T::encode_value(self, encoder)
+                }
+            }
+
+            impl<T> FixedTag for $ptr<T>
+            where
+                T: FixedTag,
+            {
+                const TAG: Tag = T::TAG;
+            }
+
+            impl<T> ValueOrd for $ptr<T>
+            where
+                T: ValueOrd,
+            {
+                fn value_cmp(&self, other: &Self) -> Result<Ordering> {
+                    T::value_cmp(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impl_shared_pointer!(Rc, Arc);
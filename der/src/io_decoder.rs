@@ -0,0 +1,410 @@
+//! Streaming DER decoder over [`std::io::Read`].
+
+use crate::{Decodable, Error, ErrorKind, Header, Length, Result, Tag};
+use std::{
+    io::{self, BufRead, BufReader, Read},
+    vec::Vec,
+};
+
+/// Maximum length of a header: a one-byte tag followed by a length of up to
+/// five bytes.
+const MAX_HEADER_LEN: usize = 6;
+
+/// DER decoder which reads its input incrementally from a [`Read`]er.
+///
+/// Unlike [`Decoder`][`crate::Decoder`], which requires the whole document to
+/// be in memory, [`IoDecoder`] only buffers one TLV-encoded value at a time.
+/// Large constructed values (e.g. the list of revoked certificates in a CRL)
+/// can be entered with [`IoDecoder::sequence`] and their elements decoded
+/// one by one, so memory usage is bounded by the size of the largest
+/// element rather than the size of the document.
+///
+/// The reader is wrapped in a [`BufReader`], so there's no need to buffer it
+/// beforehand.
+#[derive(Debug)]
+pub struct IoDecoder<R> {
+    /// Reader being decoded.
+    reader: BufReader<R>,
+
+    /// Encoding of the value decoded last, or of a peeked header.
+    buffer: Vec<u8>,
+
+    /// Header at the start of `buffer`, if it has been peeked.
+    peeked: Option<Header>,
+
+    /// Number of bytes read from `reader`.
+    position: Length,
+
+    /// End positions of the constructed values being decoded, innermost last.
+    ends: Vec<Length>,
+
+    /// Has an error been encountered?
+    failed: bool,
+}
+
+impl<R: Read> IoDecoder<R> {
+    /// Create a new decoder which reads from the given reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            buffer: Vec::new(),
+            peeked: None,
+            position: Length::ZERO,
+            ends: Vec::new(),
+            failed: false,
+        }
+    }
+
+    /// Decode the next value, which may borrow from this decoder's buffer.
+    pub fn decode<'a, T: Decodable<'a>>(&'a mut self) -> Result<T> {
+        let position = self.start_position();
+        let bytes = self.tlv_bytes()?;
+        T::from_der(bytes).map_err(|e| e.nested(position))
+    }
+
+    /// Read the next value in full, returning its encoding (header included)
+    /// so it can be decoded later.
+    pub fn tlv_bytes(&mut self) -> Result<&[u8]> {
+        self.guard(Self::read_tlv)?;
+        Ok(&self.buffer)
+    }
+
+    /// Peek at the header of the next value without consuming it.
+    pub fn peek_header(&mut self) -> Result<Header> {
+        self.guard(Self::read_header)
+    }
+
+    /// Peek at the tag of the next value without consuming it.
+    pub fn peek_tag(&mut self) -> Result<Tag> {
+        Ok(self.peek_header()?.tag)
+    }
+
+    /// Decode an ASN.1 `SEQUENCE`, calling the provided closure to decode
+    /// its elements one by one, which must consume the entire `SEQUENCE`.
+    pub fn sequence<F, T>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        let header = self.peek_header()?;
+
+        if header.tag != Tag::Sequence {
+            self.failed = true;
+            return Err(header
+                .tag
+                .unexpected_error(Some(Tag::Sequence))
+                .nested(self.start_position()));
+        }
+
+        let end = (self.position + header.length)?;
+        self.peeked = None;
+        self.ends.push(end);
+
+        let result = f(self);
+        self.ends.pop();
+
+        if self.failed || result.is_err() {
+            self.failed = true;
+            return result.and(Err(ErrorKind::Failed.at(self.position)));
+        }
+
+        if self.position != end {
+            self.failed = true;
+            return Err(self.error(ErrorKind::TrailingData {
+                decoded: self.start_position(),
+                remaining: (end - self.start_position())?,
+            }));
+        }
+
+        result
+    }
+
+    /// Have all of the values in the `SEQUENCE` being decoded, or in the
+    /// document if none is, been decoded?
+    ///
+    /// Returns `false` if a fatal error has occurred.
+    pub fn is_finished(&mut self) -> bool {
+        if self.failed || self.peeked.is_some() {
+            return false;
+        }
+
+        match self.ends.last() {
+            Some(&end) => self.position == end,
+            None => self.reader.fill_buf().map_or(false, <[u8]>::is_empty),
+        }
+    }
+
+    /// Finish decoding, returning the given value if the end of the input has
+    /// been reached, or an error otherwise.
+    ///
+    /// If there is trailing data, the rest of the input is read to determine
+    /// its length, which is reported as [`ErrorKind::TrailingData`]. Trailing
+    /// data longer than [`Length::MAX`] is reported as
+    /// [`ErrorKind::Overlength`] instead.
+    pub fn finish<T>(mut self, value: T) -> Result<T> {
+        if self.failed {
+            return Err(ErrorKind::Failed.at(self.position));
+        }
+
+        if self.is_finished() {
+            return Ok(value);
+        }
+
+        let position = self.start_position();
+        let peeked_len = (self.position - position)?;
+
+        // Read at most one byte more than `Length::MAX`
+        let max_len = u64::from(u32::from(Length::MAX)) + 1;
+        let unread_len = io::copy(&mut (&mut self.reader).take(max_len), &mut io::sink())
+            .map_err(|e| self.io_error(e))?;
+
+        let remaining = u32::try_from(unread_len)
+            .ok()
+            .and_then(|len| (peeked_len + len).ok())
+            .ok_or_else(|| ErrorKind::Overlength.at(position))?;
+
+        Err(ErrorKind::TrailingData {
+            decoded: position,
+            remaining,
+        }
+        .at(position))
+    }
+
+    /// Get the number of bytes decoded so far.
+    pub fn position(&self) -> Length {
+        self.start_position()
+    }
+
+    /// Run the given operation, ensuring the decoder is in a good state
+    /// beforehand and marking it as failed if the operation fails.
+    fn guard<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if self.failed {
+            return Err(ErrorKind::Failed.at(self.position));
+        }
+
+        let result = f(self);
+        self.failed = result.is_err();
+        result
+    }
+
+    /// Read the next header into the buffer, unless it has already been read.
+    fn read_header(&mut self) -> Result<Header> {
+        if let Some(header) = self.peeked {
+            return Ok(header);
+        }
+
+        let start = self.position;
+
+        if let Some(&end) = self.ends.last() {
+            if (start + Length::from(2u8))? > end {
+                return Err(self.error(ErrorKind::Incomplete {
+                    expected_len: (start + Length::from(2u8))?,
+                    actual_len: end,
+                }));
+            }
+        }
+
+        self.buffer.clear();
+        self.read_bytes(2)?;
+
+        let length_octets = match self.buffer[1] {
+            byte if byte > 0x80 => usize::from(byte & 0x7F),
+            _ => 0,
+        };
+
+        if 2 + length_octets > MAX_HEADER_LEN {
+            return Err(self.error(ErrorKind::Overlength));
+        }
+
+        self.read_bytes(length_octets)?;
+        let header = Header::from_der(&self.buffer).map_err(|e| e.nested(start))?;
+        let end = (self.position + header.length)?;
+
+        if let Some(&parent_end) = self.ends.last() {
+            if end > parent_end {
+                return Err(ErrorKind::Length { tag: header.tag }.at(start));
+            }
+        }
+
+        self.peeked = Some(header);
+        Ok(header)
+    }
+
+    /// Read the next value in full into the buffer.
+    fn read_tlv(&mut self) -> Result<()> {
+        let header = self.read_header()?;
+        self.read_bytes(header.length.try_into()?)?;
+        self.peeked = None;
+        Ok(())
+    }
+
+    /// Read `len` bytes, appending them to the buffer.
+    fn read_bytes(&mut self, len: usize) -> Result<()> {
+        // Grow the buffer as data is read, rather than trusting `len`
+        let read_len = (&mut self.reader)
+            .take(len as u64)
+            .read_to_end(&mut self.buffer)
+            .map_err(|e| self.io_error(e))?;
+
+        self.position = (self.position + Length::try_from(read_len)?)?;
+
+        if read_len == len {
+            Ok(())
+        } else {
+            let expected_len = (self.position + Length::try_from(len - read_len)?)?;
+            Err(self.error(ErrorKind::Incomplete {
+                expected_len,
+                actual_len: self.position,
+            }))
+        }
+    }
+
+    /// Position of the next value, including its header if it was peeked.
+    fn start_position(&self) -> Length {
+        match self.peeked {
+            Some(_) => {
+                let header_len = Length::try_from(self.buffer.len()).unwrap_or(Length::ZERO);
+                (self.position - header_len).unwrap_or(Length::ZERO)
+            }
+            None => self.position,
+        }
+    }
+
+    /// Return an error with the given [`ErrorKind`] at the current position.
+    fn error(&self, kind: ErrorKind) -> Error {
+        kind.at(self.position)
+    }
+
+    /// Convert an I/O error, annotating it with the current position.
+    fn io_error(&self, err: io::Error) -> Error {
+        self.error(Error::from(err).kind())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IoDecoder;
+    use crate::{asn1::OctetString, ErrorKind, Length, Result, Tag};
+    use hex_literal::hex;
+
+    // SEQUENCE { INTEGER 1, INTEGER 2, OCTET STRING "abc" }
+    const EXAMPLE_MSG: &[u8] = &hex!("300B 020101 020102 0403616263");
+
+    #[test]
+    fn decode_sequence() -> Result<()> {
+        let mut decoder = IoDecoder::new(EXAMPLE_MSG);
+
+        let (a, b, c) = decoder.sequence(|decoder| {
+            let a = decoder.decode::<u8>()?;
+            let b = decoder.decode::<u8>()?;
+            assert_eq!(decoder.peek_tag()?, Tag::OctetString);
+            let c = decoder.decode::<OctetString<'_>>()?.as_bytes().to_vec();
+            assert!(decoder.is_finished());
+            Ok((a, b, c))
+        })?;
+
+        assert_eq!((a, b, c.as_slice()), (1, 2, &b"abc"[..]));
+        assert_eq!(decoder.position(), Length::from(13u8));
+        decoder.finish(())
+    }
+
+    #[test]
+    fn decode_elements() -> Result<()> {
+        let mut decoder = IoDecoder::new(EXAMPLE_MSG);
+        let mut count = 0;
+
+        decoder.sequence(|decoder| {
+            while !decoder.is_finished() {
+                decoder.tlv_bytes()?;
+                count += 1;
+            }
+
+            Ok(())
+        })?;
+
+        assert_eq!(count, 3);
+        decoder.finish(())
+    }
+
+    #[test]
+    fn reject_incomplete() {
+        let mut decoder = IoDecoder::new(&EXAMPLE_MSG[..10]);
+        let err = decoder.tlv_bytes().err();
+        assert!(matches!(
+            err.map(|e| e.kind()),
+            Some(ErrorKind::Incomplete { .. })
+        ));
+        assert_eq!(
+            decoder.tlv_bytes().err().map(|e| e.kind()),
+            Some(ErrorKind::Failed)
+        );
+    }
+
+    #[test]
+    fn reject_overlong_child() {
+        // Nested SEQUENCE claims to be longer than its parent
+        let mut decoder = IoDecoder::new(&hex!("3004 3003 0500")[..]);
+        let err = decoder
+            .sequence(|decoder| decoder.tlv_bytes().map(|_| ()))
+            .err();
+        assert_eq!(
+            err.map(|e| e.kind()),
+            Some(ErrorKind::Length { tag: Tag::Sequence })
+        );
+    }
+
+    #[test]
+    fn reject_reading_past_sequence() {
+        let mut decoder = IoDecoder::new(&hex!("3003 020101 020102")[..]);
+        let err = decoder
+            .sequence(|decoder| {
+                decoder.decode::<u8>()?;
+                decoder.decode::<u8>()
+            })
+            .err();
+        assert!(matches!(
+            err.map(|e| e.kind()),
+            Some(ErrorKind::Incomplete { .. })
+        ));
+    }
+
+    #[test]
+    fn reject_unconsumed_elements() {
+        let mut decoder = IoDecoder::new(EXAMPLE_MSG);
+        let err = decoder.sequence(|decoder| decoder.decode::<u8>()).err();
+        assert!(matches!(
+            err.map(|e| e.kind()),
+            Some(ErrorKind::TrailingData { .. })
+        ));
+    }
+
+    #[test]
+    fn reject_trailing_data() -> Result<()> {
+        let mut decoder = IoDecoder::new(&hex!("020101 000000")[..]);
+        assert_eq!(decoder.decode::<u8>()?, 1);
+        let err = decoder.finish(()).err();
+        assert_eq!(
+            err.map(|e| e.kind()),
+            Some(ErrorKind::TrailingData {
+                decoded: Length::from(3u8),
+                remaining: Length::from(3u8),
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reject_trailing_data_after_peek() -> Result<()> {
+        let mut decoder = IoDecoder::new(&hex!("020101 020102 00")[..]);
+        assert_eq!(decoder.decode::<u8>()?, 1);
+        assert_eq!(decoder.peek_tag()?, Tag::Integer);
+        let err = decoder.finish(()).err();
+        assert_eq!(
+            err.map(|e| e.kind()),
+            Some(ErrorKind::TrailingData {
+                decoded: Length::from(3u8),
+                remaining: Length::from(4u8),
+            })
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+//! Streaming DER encoder into [`std::io::Write`].
+
+use crate::{Encodable, Error, ErrorKind, Header, Length, Result, Tag};
+use std::{
+    io::{self, BufWriter, Write},
+    vec::Vec,
+};
+
+/// DER encoder which writes its output incrementally to a [`Write`]r.
+///
+/// Counterpart to [`IoDecoder`][`crate::IoDecoder`]: each value is encoded
+/// into an internal buffer and written out before the next one, so large
+/// constructed values (e.g. the list of revoked certificates in a CRL) can
+/// be generated element by element with [`IoEncoder::sequence`] while
+/// memory usage stays bounded by the size of the largest element.
+///
+/// As DER lengths precede the values they describe, the length of each
+/// `SEQUENCE` must be known before its elements are written: it can be
+/// computed beforehand as the sum of the [`Encodable::encoded_len`] of the
+/// elements.
+///
+/// The writer is wrapped in a [`BufWriter`], which is flushed by
+/// [`IoEncoder::finish`].
+#[derive(Debug)]
+pub struct IoEncoder<W: Write> {
+    /// Writer being encoded into.
+    writer: BufWriter<W>,
+
+    /// Encoding of the value being written.
+    buffer: Vec<u8>,
+
+    /// Number of bytes written to `writer`.
+    position: Length,
+
+    /// End positions of the constructed values being encoded, innermost last.
+    ends: Vec<Length>,
+
+    /// Has an error been encountered?
+    failed: bool,
+}
+
+impl<W: Write> IoEncoder<W> {
+    /// Create a new encoder which writes to the given writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: BufWriter::new(writer),
+            buffer: Vec::new(),
+            position: Length::ZERO,
+            ends: Vec::new(),
+            failed: false,
+        }
+    }
+
+    /// Encode a value which impls the [`Encodable`] trait.
+    pub fn encode<T: Encodable + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.guard(|encoder| {
+            encoder.buffer.clear();
+            value.encode_to_vec(&mut encoder.buffer)?;
+            encoder.write_buffer()
+        })
+    }
+
+    /// Encode an ASN.1 `SEQUENCE` whose contents are `length` bytes long,
+    /// calling the provided closure to encode its elements one by one.
+    ///
+    /// Returns an error if the elements encoded by `f` don't add up to
+    /// exactly `length` bytes.
+    pub fn sequence<F>(&mut self, length: Length, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Self) -> Result<()>,
+    {
+        self.encode(&Header::new(Tag::Sequence, length)?)?;
+
+        let end = (self.position + length)?;
+        self.ends.push(end);
+
+        let result = f(self);
+        self.ends.pop();
+
+        if self.failed || result.is_err() {
+            self.failed = true;
+            return result.and(Err(ErrorKind::Failed.at(self.position)));
+        }
+
+        if self.position != end {
+            self.failed = true;
+            return Err(self.error(ErrorKind::Incomplete {
+                expected_len: end,
+                actual_len: self.position,
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Get the number of bytes encoded so far.
+    pub fn position(&self) -> Length {
+        self.position
+    }
+
+    /// Finish encoding, flushing and returning the writer.
+    pub fn finish(self) -> Result<W> {
+        if self.failed {
+            return Err(ErrorKind::Failed.at(self.position));
+        }
+
+        let position = self.position;
+        self.writer
+            .into_inner()
+            .map_err(|e| Error::from(e.into_error()).kind().at(position))
+    }
+
+    /// Run the given operation, ensuring the encoder is in a good state
+    /// beforehand and marking it as failed if the operation fails.
+    fn guard(&mut self, f: impl FnOnce(&mut Self) -> Result<()>) -> Result<()> {
+        if self.failed {
+            return Err(ErrorKind::Failed.at(self.position));
+        }
+
+        let result = f(self);
+        self.failed = result.is_err();
+        result
+    }
+
+    /// Write the buffer out, checking it fits within the enclosing
+    /// `SEQUENCE`.
+    fn write_buffer(&mut self) -> Result<()> {
+        let end = (self.position + Length::try_from(self.buffer.len())?)?;
+
+        if self
+            .ends
+            .last()
+            .map_or(false, |&parent_end| end > parent_end)
+        {
+            return Err(self.error(ErrorKind::Overlength));
+        }
+
+        self.writer
+            .write_all(&self.buffer)
+            .map_err(|e| self.io_error(e))?;
+
+        self.position = end;
+        Ok(())
+    }
+
+    /// Return an error with the given [`ErrorKind`] at the current position.
+    fn error(&self, kind: ErrorKind) -> Error {
+        kind.at(self.position)
+    }
+
+    /// Convert an I/O error, annotating it with the current position.
+    fn io_error(&self, err: io::Error) -> Error {
+        self.error(Error::from(err).kind())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IoEncoder;
+    use crate::{asn1::OctetString, Encodable, ErrorKind, Length, Result};
+    use hex_literal::hex;
+    use std::vec::Vec;
+
+    // SEQUENCE { INTEGER 1, INTEGER 2, OCTET STRING "abc" }
+    const EXAMPLE_MSG: &[u8] = &hex!("300B 020101 020102 0403616263");
+
+    #[test]
+    fn encode_sequence() -> Result<()> {
+        let octet_string = OctetString::new(b"abc")?;
+        let length = ((1u8.encoded_len()? + 2u8.encoded_len()?)? + octet_string.encoded_len()?)?;
+
+        let mut encoder = IoEncoder::new(Vec::new());
+        encoder.sequence(length, |encoder| {
+            encoder.encode(&1u8)?;
+            encoder.encode(&2u8)?;
+            encoder.encode(&octet_string)
+        })?;
+
+        assert_eq!(encoder.position(), Length::from(13u8));
+        assert_eq!(encoder.finish()?, EXAMPLE_MSG);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_overlong_sequence() {
+        let mut encoder = IoEncoder::new(Vec::new());
+        let err = encoder
+            .sequence(Length::from(3u8), |encoder| {
+                encoder.encode(&1u8)?;
+                encoder.encode(&2u8)
+            })
+            .err();
+        assert_eq!(err.map(|e| e.kind()), Some(ErrorKind::Overlength));
+        assert_eq!(
+            encoder.encode(&1u8).err().map(|e| e.kind()),
+            Some(ErrorKind::Failed)
+        );
+    }
+
+    #[test]
+    fn reject_incomplete_sequence() {
+        let mut encoder = IoEncoder::new(Vec::new());
+        let err = encoder
+            .sequence(Length::from(6u8), |encoder| encoder.encode(&1u8))
+            .err();
+        assert!(matches!(
+            err.map(|e| e.kind()),
+            Some(ErrorKind::Incomplete { .. })
+        ));
+    }
+}
@@ -25,28 +25,49 @@
 //! - [`bool`]: ASN.1 `BOOLEAN`.
 //! - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`]: ASN.1 `INTEGER`.
 //! - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`]: ASN.1 `INTEGER`.
+//! - [`f64`]: ASN.1 `REAL`.
 //! - [`str`], [`String`][`alloc::string::String`]: ASN.1 `UTF8String`.
 //!   `String` requires `alloc` feature. See also [`Utf8String`].
 //!   Requires `alloc` feature. See also [`SetOf`].
 //! - [`Option`]: ASN.1 `OPTIONAL`.
+//! - [`Duration`][`core::time::Duration`]: ASN.1 `INTEGER` number of seconds.
 //! - [`SystemTime`][`std::time::SystemTime`]: ASN.1 `GeneralizedTime`. Requires `std` feature.
 //! - [`Vec`][`alloc::vec::Vec`]: ASN.1 `SEQUENCE OF`. Requires `alloc` feature.
 //! - `[T; N]`: ASN.1 `SEQUENCE OF`. See also [`SequenceOf`].
+//! - [`Rc`][`alloc::rc::Rc`], [`Arc`][`alloc::sync::Arc`]: same as the inner
+//!   type. Requires `alloc` feature.
+//! - [`Box`][`alloc::boxed::Box`]: same as the inner type, which must be a
+//!   [`Sequence`]. Requires `alloc` feature.
 //!
 //! The following ASN.1 types provided by this crate also impl these traits:
-//! - [`Any`]: ASN.1 `ANY`
-//! - [`BitString`]: ASN.1 `BIT STRING`
+//! - [`Any`], [`AnyOwned`]: ASN.1 `ANY`
+//! - [`BitString`], [`BitStringOwned`]: ASN.1 `BIT STRING`
+//! - [`BmpString`]: ASN.1 `BMPString`
+//! - [`Date`]: ASN.1 `DATE`
+//! - [`GeneralString`]: ASN.1 `GeneralString`
 //! - [`GeneralizedTime`]: ASN.1 `GeneralizedTime`
+//! - [`GraphicString`]: ASN.1 `GraphicString`
 //! - [`Ia5String`]: ASN.1 `IA5String`
+//! - [`IsoDuration`]: ASN.1 `DURATION`
 //! - [`Null`]: ASN.1 `NULL`
 //! - [`ObjectIdentifier`]: ASN.1 `OBJECT IDENTIFIER`
-//! - [`OctetString`]: ASN.1 `OCTET STRING`
+//! - [`OctetString`], [`OctetStringOwned`]: ASN.1 `OCTET STRING`
 //! - [`PrintableString`]: ASN.1 `PrintableString` (ASCII subset)
+//! - [`RelativeOid`]: ASN.1 `RELATIVE-OID`
 //! - [`SequenceOf`]: ASN.1 `SEQUENCE OF`
 //! - [`SetOf`], [`SetOfVec`]: ASN.1 `SET OF`
+//! - [`TeletexString`]: ASN.1 `TeletexString` (legacy)
+//! - [`Time`]: ASN.1 `TIME`
+//! - [`TimeOfDay`]: ASN.1 `TIME-OF-DAY`
 //! - [`UIntBytes`]: ASN.1 unsigned `INTEGER` with raw access to encoded bytes
+//! - [`UniversalString`]: ASN.1 `UniversalString` (legacy)
 //! - [`UtcTime`]: ASN.1 `UTCTime`
 //! - [`Utf8String`]: ASN.1 `UTF8String`
+//! - [`VisibleString`]: ASN.1 `VisibleString`
+//!
+//! [`AnyOwned`], [`BitStringOwned`] and [`OctetStringOwned`] own their
+//! contents rather than borrowing them from the input, so they can outlive it
+//! (e.g. to be sent to another thread). They require the `alloc` feature.
 //!
 //! Context specific fields can be modeled using these generic types:
 //! - [`ContextSpecific`]: decoder/encoder for owned context-specific fields
@@ -184,7 +205,7 @@
 //!
 //! let algorithm_identifier = AlgorithmIdentifier {
 //!     // OID for `id-ecPublicKey`, if you're curious
-//!     algorithm: "1.2.840.10045.2.1".parse().unwrap(),
+//!     algorithm: der::oid!("1.2.840.10045.2.1"),
 //!
 //!     // `Any<'a>` impls `TryFrom<&'a [u8]>`, which parses the provided
 //!     // slice as an ASN.1 DER-encoded message.
@@ -241,11 +262,11 @@
 //! }
 //!
 //! // Example parameters value: OID for the NIST P-256 elliptic curve.
-//! let parameters_oid = "1.2.840.10045.3.1.7".parse::<ObjectIdentifier>().unwrap();
+//! let parameters_oid = der::oid!("1.2.840.10045.3.1.7");
 //!
 //! let algorithm_identifier = AlgorithmIdentifier {
 //!     // OID for `id-ecPublicKey`, if you're curious
-//!     algorithm: "1.2.840.10045.2.1".parse().unwrap(),
+//!     algorithm: der::oid!("1.2.840.10045.2.1"),
 //!
 //!     // `Any<'a>` impls `From<&'a ObjectIdentifier>`, allowing OID constants to
 //!     // be directly converted to an `Any` type for this use case.
@@ -309,21 +330,35 @@
 //! [A Warm Welcome to ASN.1 and DER]: https://letsencrypt.org/docs/a-warm-welcome-to-asn1-and-der/
 //!
 //! [`Any`]: asn1::Any
+//! [`AnyOwned`]: asn1::AnyOwned
 //! [`ContextSpecific`]: asn1::ContextSpecific
 //! [`ContextSpecificRef`]: asn1::ContextSpecificRef
 //! [`BitString`]: asn1::BitString
+//! [`BitStringOwned`]: asn1::BitStringOwned
+//! [`BmpString`]: asn1::BmpString
+//! [`Date`]: asn1::Date
+//! [`GeneralString`]: asn1::GeneralString
 //! [`GeneralizedTime`]: asn1::GeneralizedTime
+//! [`GraphicString`]: asn1::GraphicString
 //! [`Ia5String`]: asn1::Ia5String
+//! [`IsoDuration`]: asn1::IsoDuration
 //! [`Null`]: asn1::Null
 //! [`ObjectIdentifier`]: asn1::ObjectIdentifier
 //! [`OctetString`]: asn1::OctetString
+//! [`OctetStringOwned`]: asn1::OctetStringOwned
 //! [`PrintableString`]: asn1::PrintableString
+//! [`RelativeOid`]: asn1::RelativeOid
 //! [`SequenceOf`]: asn1::SequenceOf
 //! [`SetOf`]: asn1::SetOf
 //! [`SetOfVec`]: asn1::SetOfVec
+//! [`TeletexString`]: asn1::TeletexString
+//! [`Time`]: asn1::Time
+//! [`TimeOfDay`]: asn1::TimeOfDay
 //! [`UIntBytes`]: asn1::UIntBytes
+//! [`UniversalString`]: asn1::UniversalString
 //! [`UtcTime`]: asn1::UtcTime
 //! [`Utf8String`]: asn1::Utf8String
+//! [`VisibleString`]: asn1::VisibleString
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(test, macro_use)]
@@ -347,9 +382,18 @@ mod ord;
 mod str_slice;
 mod tag;
 mod value;
+mod walker;
 
+#[cfg(feature = "alloc")]
+mod cer;
 #[cfg(feature = "alloc")]
 mod document;
+#[cfg(feature = "alloc")]
+mod heap;
+#[cfg(feature = "std")]
+mod io_decoder;
+#[cfg(feature = "std")]
+mod io_encoder;
 
 pub use crate::{
     asn1::{Any, Choice, Sequence},
@@ -364,11 +408,16 @@ pub use crate::{
     ord::{DerOrd, ValueOrd},
     tag::{Class, FixedTag, Tag, TagMode, TagNumber, Tagged},
     value::{DecodeValue, EncodeValue},
+    walker::{Tlv, Walker},
 };
 
 #[cfg(feature = "alloc")]
 pub use document::Document;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use {io_decoder::IoDecoder, io_encoder::IoEncoder};
+
 #[cfg(feature = "bigint")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bigint")))]
 pub use crypto_bigint as bigint;
@@ -377,6 +426,10 @@ pub use crypto_bigint as bigint;
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use der_derive::{Choice, Enumerated, Newtype, Sequence, ValueOrd};
 
+#[cfg(feature = "oid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+pub use const_oid::oid;
+
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
 pub use pem_rfc7468 as pem;
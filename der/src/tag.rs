@@ -67,12 +67,21 @@ pub enum Tag {
     /// `OBJECT IDENTIFIER` tag: `6`.
     ObjectIdentifier,
 
+    /// `REAL` tag: `9`.
+    Real,
+
     /// `ENUMERATED` tag: `10`.
     Enumerated,
 
     /// `UTF8String` tag: `12`.
     Utf8String,
 
+    /// `RELATIVE-OID` tag: `13`.
+    RelativeOid,
+
+    /// `TIME` tag: `14`.
+    Time,
+
     /// `SEQUENCE` tag: `16`.
     Sequence,
 
@@ -85,6 +94,9 @@ pub enum Tag {
     /// `PrintableString` tag: `19`.
     PrintableString,
 
+    /// `TeletexString` (a.k.a. `T61String`) tag: `20`.
+    TeletexString,
+
     /// `IA5String` tag: `22`.
     Ia5String,
 
@@ -94,9 +106,18 @@ pub enum Tag {
     /// `GeneralizedTime` tag: `24`.
     GeneralizedTime,
 
-    /// `VisibleString` tag: `26`.
+    /// `GraphicString` tag: `25`.
+    GraphicString,
+
+    /// `VisibleString` (a.k.a. `ISO646String`) tag: `26`.
     VisibleString,
 
+    /// `GeneralString` tag: `27`.
+    GeneralString,
+
+    /// `UniversalString` tag: `28`.
+    UniversalString,
+
     /// `BMPString` tag: `30`.
     BmpString,
 
@@ -189,17 +210,24 @@ impl Tag {
             Tag::OctetString => 0x04,
             Tag::Null => 0x05,
             Tag::ObjectIdentifier => 0x06,
+            Tag::Real => 0x09,
             Tag::Enumerated => 0x0A,
             Tag::Utf8String => 0x0C,
+            Tag::RelativeOid => 0x0D,
+            Tag::Time => 0x0E,
             Tag::Sequence => 0x10 | CONSTRUCTED_FLAG,
             Tag::Set => 0x11 | CONSTRUCTED_FLAG,
             Tag::NumericString => 0x12,
             Tag::PrintableString => 0x13,
+            Tag::TeletexString => 0x14,
             Tag::Ia5String => 0x16,
             Tag::UtcTime => 0x17,
             Tag::GeneralizedTime => 0x18,
+            Tag::GraphicString => 0x19,
             Tag::VisibleString => 0x1A,
-            Tag::BmpString => 0x1D,
+            Tag::GeneralString => 0x1B,
+            Tag::UniversalString => 0x1C,
+            Tag::BmpString => 0x1E,
             Tag::Application {
                 constructed,
                 number,
@@ -257,15 +285,22 @@ impl TryFrom<u8> for Tag {
             0x04 => Ok(Tag::OctetString),
             0x05 => Ok(Tag::Null),
             0x06 => Ok(Tag::ObjectIdentifier),
+            0x09 => Ok(Tag::Real),
             0x0A => Ok(Tag::Enumerated),
             0x0C => Ok(Tag::Utf8String),
+            0x0D => Ok(Tag::RelativeOid),
+            0x0E => Ok(Tag::Time),
             0x12 => Ok(Tag::NumericString),
             0x13 => Ok(Tag::PrintableString),
+            0x14 => Ok(Tag::TeletexString),
             0x16 => Ok(Tag::Ia5String),
             0x17 => Ok(Tag::UtcTime),
             0x18 => Ok(Tag::GeneralizedTime),
+            0x19 => Ok(Tag::GraphicString),
             0x1A => Ok(Tag::VisibleString),
-            0x1d => Ok(Tag::BmpString),
+            0x1B => Ok(Tag::GeneralString),
+            0x1C => Ok(Tag::UniversalString),
+            0x1E => Ok(Tag::BmpString),
             0x30 => Ok(Tag::Sequence), // constructed
             0x31 => Ok(Tag::Set),      // constructed
             0x40..=0x7E => Ok(Tag::Application {
@@ -330,15 +365,22 @@ impl fmt::Display for Tag {
             Tag::OctetString => f.write_str("OCTET STRING"),
             Tag::Null => f.write_str("NULL"),
             Tag::ObjectIdentifier => f.write_str("OBJECT IDENTIFIER"),
+            Tag::Real => f.write_str("REAL"),
             Tag::Enumerated => f.write_str("ENUMERATED"),
             Tag::Utf8String => f.write_str("UTF8String"),
+            Tag::RelativeOid => f.write_str("RELATIVE-OID"),
+            Tag::Time => f.write_str("TIME"),
             Tag::Set => f.write_str("SET"),
             Tag::NumericString => f.write_str("NumericString"),
             Tag::PrintableString => f.write_str("PrintableString"),
+            Tag::TeletexString => f.write_str("TeletexString"),
             Tag::Ia5String => f.write_str("IA5String"),
             Tag::UtcTime => f.write_str("UTCTime"),
             Tag::GeneralizedTime => f.write_str("GeneralizedTime"),
+            Tag::GraphicString => f.write_str("GraphicString"),
             Tag::VisibleString => f.write_str("VisibleString"),
+            Tag::GeneralString => f.write_str("GeneralString"),
+            Tag::UniversalString => f.write_str("UniversalString"),
             Tag::BmpString => f.write_str("BMPString"),
             Tag::Sequence => f.write_str("SEQUENCE"),
             Tag::Application {
@@ -388,14 +430,22 @@ mod tests {
         assert_eq!(Tag::OctetString.class(), Class::Universal);
         assert_eq!(Tag::Null.class(), Class::Universal);
         assert_eq!(Tag::ObjectIdentifier.class(), Class::Universal);
+        assert_eq!(Tag::Real.class(), Class::Universal);
         assert_eq!(Tag::Enumerated.class(), Class::Universal);
         assert_eq!(Tag::Utf8String.class(), Class::Universal);
+        assert_eq!(Tag::RelativeOid.class(), Class::Universal);
+        assert_eq!(Tag::Time.class(), Class::Universal);
         assert_eq!(Tag::Set.class(), Class::Universal);
         assert_eq!(Tag::NumericString.class(), Class::Universal);
         assert_eq!(Tag::PrintableString.class(), Class::Universal);
+        assert_eq!(Tag::TeletexString.class(), Class::Universal);
         assert_eq!(Tag::Ia5String.class(), Class::Universal);
         assert_eq!(Tag::UtcTime.class(), Class::Universal);
         assert_eq!(Tag::GeneralizedTime.class(), Class::Universal);
+        assert_eq!(Tag::GraphicString.class(), Class::Universal);
+        assert_eq!(Tag::VisibleString.class(), Class::Universal);
+        assert_eq!(Tag::GeneralString.class(), Class::Universal);
+        assert_eq!(Tag::UniversalString.class(), Class::Universal);
         assert_eq!(Tag::Sequence.class(), Class::Universal);
 
         for num in 0..=30 {
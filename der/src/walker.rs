@@ -0,0 +1,188 @@
+//! Non-recursive traversal of ASN.1 DER documents.
+
+use crate::{ByteSlice, Decodable, Decoder, ErrorKind, Header, Length, Result};
+
+/// Iterator over every TLV-encoded value in an ASN.1 DER document,
+/// including values nested inside constructed types, in document order.
+///
+/// Unlike [`Decoder`], which recurses into constructed values, [`Walker`]
+/// tracks the enclosing values on an explicit stack holding at most
+/// `MAX_DEPTH` entries. Its stack usage is therefore fixed regardless of the
+/// input, making it suitable for checking the structure of untrusted
+/// documents before decoding them.
+///
+/// Only the tags and lengths of values are checked: each value must fit
+/// within the one enclosing it, and documents nested more than `MAX_DEPTH`
+/// constructed values deep are rejected with [`ErrorKind::Overlength`].
+/// Iteration stops after the first error.
+#[derive(Clone, Debug)]
+pub struct Walker<'a, const MAX_DEPTH: usize> {
+    /// Document being traversed.
+    bytes: &'a [u8],
+
+    /// Position of the next TLV within `bytes`.
+    position: usize,
+
+    /// End positions of the enclosing constructed values, innermost last.
+    stack: [usize; MAX_DEPTH],
+
+    /// Number of entries in `stack` which are in use.
+    depth: usize,
+
+    /// Has an error been encountered?
+    failed: bool,
+}
+
+/// TLV-encoded value yielded by a [`Walker`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Tlv<'a> {
+    /// Number of constructed values enclosing this one.
+    pub depth: usize,
+
+    /// Header (tag and length) of this value.
+    pub header: Header,
+
+    /// Position of this value's header within the document.
+    pub position: Length,
+
+    /// Contents of this value.
+    ///
+    /// For constructed values this contains the encodings of the nested
+    /// values, which are yielded individually afterwards.
+    pub value: &'a [u8],
+}
+
+impl<'a, const MAX_DEPTH: usize> Walker<'a, MAX_DEPTH> {
+    /// Create a new walker over the given ASN.1 DER document.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        ByteSlice::new(bytes)?;
+
+        Ok(Self {
+            bytes,
+            position: 0,
+            stack: [0; MAX_DEPTH],
+            depth: 0,
+            failed: false,
+        })
+    }
+
+    /// Walk the rest of the document, returning an error if it is
+    /// malformed or nested more deeply than `MAX_DEPTH`.
+    pub fn finish(self) -> Result<()> {
+        for tlv in self {
+            tlv?;
+        }
+
+        Ok(())
+    }
+
+    /// Decode the next TLV, if any.
+    fn next_tlv(&mut self) -> Result<Option<Tlv<'a>>> {
+        while self.depth > 0 && self.stack[self.depth - 1] == self.position {
+            self.depth -= 1;
+        }
+
+        let end = match self.depth {
+            0 => self.bytes.len(),
+            depth => self.stack[depth - 1],
+        };
+
+        if self.position == end {
+            return Ok(None);
+        }
+
+        let position = Length::try_from(self.position)?;
+        let mut decoder = Decoder::new(&self.bytes[self.position..end])?;
+        let (header, value) = Header::decode(&mut decoder)
+            .and_then(|header| Ok((header, decoder.bytes(header.length)?)))
+            .map_err(|e| e.nested(position))?;
+
+        let tlv = Tlv {
+            depth: self.depth,
+            header,
+            position,
+            value,
+        };
+
+        let value_end = self.position + usize::try_from(decoder.position())?;
+
+        if header.tag.is_constructed() {
+            if self.depth == MAX_DEPTH {
+                return Err(ErrorKind::Overlength.at(position));
+            }
+
+            self.stack[self.depth] = value_end;
+            self.depth += 1;
+            self.position = value_end - value.len();
+        } else {
+            self.position = value_end;
+        }
+
+        Ok(Some(tlv))
+    }
+}
+
+impl<'a, const MAX_DEPTH: usize> Iterator for Walker<'a, MAX_DEPTH> {
+    type Item = Result<Tlv<'a>>;
+
+    fn next(&mut self) -> Option<Result<Tlv<'a>>> {
+        if self.failed {
+            return None;
+        }
+
+        let result = self.next_tlv().transpose();
+        self.failed = matches!(result, Some(Err(_)));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Walker;
+    use crate::{ErrorKind, Length, Result, Tag};
+    use hex_literal::hex;
+
+    // SEQUENCE { INTEGER 42, SEQUENCE { NULL }, OCTET STRING "" }
+    const EXAMPLE_MSG: &[u8] = &hex!("3009 02012A 30020500 0400");
+
+    #[test]
+    fn walk_nested() -> Result<()> {
+        let mut walker = Walker::<'_, 2>::new(EXAMPLE_MSG)?;
+        let expected = [
+            (0, Tag::Sequence, 0u8, 9usize),
+            (1, Tag::Integer, 2, 1),
+            (1, Tag::Sequence, 5, 2),
+            (2, Tag::Null, 7, 0),
+            (1, Tag::OctetString, 9, 0),
+        ];
+
+        for (depth, tag, position, len) in expected {
+            let tlv = walker.next().ok_or(ErrorKind::Failed)??;
+            assert_eq!(tlv.depth, depth);
+            assert_eq!(tlv.header.tag, tag);
+            assert_eq!(tlv.position, Length::from(position));
+            assert_eq!(tlv.value.len(), len);
+        }
+
+        assert!(walker.next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn reject_too_deep() -> Result<()> {
+        let err = Walker::<'_, 1>::new(EXAMPLE_MSG)?.finish().err();
+        assert_eq!(err.map(|e| e.kind()), Some(ErrorKind::Overlength));
+        assert_eq!(err.and_then(|e| e.position()), Some(Length::from(5u8)));
+        Ok(())
+    }
+
+    #[test]
+    fn reject_overlong_child() -> Result<()> {
+        // Nested SEQUENCE claims to be longer than its parent
+        let mut walker = Walker::<'_, 4>::new(&hex!("3004 3003 0500"))?;
+        assert!(walker.next().ok_or(ErrorKind::Failed)?.is_ok());
+        assert!(walker.next().ok_or(ErrorKind::Failed)?.is_err());
+        assert!(walker.next().is_none());
+        Ok(())
+    }
+}
@@ -203,7 +203,7 @@ mod enumerated {
 mod sequence {
     use der::{
         asn1::{Any, ObjectIdentifier, SetOf},
-        Decodable, Encodable, Sequence, ValueOrd,
+        Decodable, Encodable, Length, Sequence, ValueOrd,
     };
     use hex_literal::hex;
 
@@ -299,6 +299,31 @@ mod sequence {
         pub extn_value: &'a [u8],
     }
 
+    // Sequence from an ASN.1 module declared with `DEFINITIONS IMPLICIT TAGS`.
+    //
+    // ```text
+    //    ImplicitModuleExample ::= SEQUENCE {
+    //         version     [0] INTEGER,
+    //         flag        [1] EXPLICIT BOOLEAN OPTIONAL,
+    //         value       [2] OCTET STRING
+    //         }
+    // ```
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    #[asn1(tag_mode = "IMPLICIT")]
+    pub struct ImplicitModuleExample<'a> {
+        /// version     [0] INTEGER,
+        #[asn1(context_specific = "0")]
+        pub version: u8,
+
+        /// flag        [1] EXPLICIT BOOLEAN OPTIONAL,
+        #[asn1(context_specific = "1", optional = "true", tag_mode = "EXPLICIT")]
+        pub flag: Option<bool>,
+
+        /// value       [2] OCTET STRING
+        #[asn1(context_specific = "2", type = "OCTET STRING")]
+        pub value: &'a [u8],
+    }
+
     /// X.509 `AlgorithmIdentifier`
     #[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
     pub struct AlgorithmIdentifier<'a> {
@@ -378,6 +403,38 @@ mod sequence {
         pub typed_context_specific_optional: Option<&'a [u8]>,
     }
 
+    const IMPLICIT_MODULE_EXAMPLE_DER: &[u8] = &hex!("30 0c 80 01 05 a1 03 01 01 ff 82 02 68 69");
+
+    #[test]
+    fn implicit_module_test() {
+        let example = ImplicitModuleExample::from_der(IMPLICIT_MODULE_EXAMPLE_DER).unwrap();
+        assert_eq!(5, example.version);
+        assert_eq!(Some(true), example.flag);
+        assert_eq!(b"hi", example.value);
+
+        let mut buf = [0u8; 128];
+        let encoded = example.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(encoded, IMPLICIT_MODULE_EXAMPLE_DER);
+    }
+
+    #[test]
+    fn fields_len_test() {
+        let example = ImplicitModuleExample::from_der(IMPLICIT_MODULE_EXAMPLE_DER).unwrap();
+        let dyn_len = example
+            .fields(|fields| {
+                fields
+                    .iter()
+                    .fold(Ok(Length::ZERO), |len, field| len + field.encoded_len()?)
+            })
+            .unwrap();
+
+        assert_eq!(example.fields_len().unwrap(), dyn_len);
+        assert_eq!(
+            example.encoded_len().unwrap(),
+            Length::try_from(IMPLICIT_MODULE_EXAMPLE_DER.len()).unwrap()
+        );
+    }
+
     #[test]
     fn idp_test() {
         let idp = IssuingDistributionPointExample::from_der(&hex!("30038101FF")).unwrap();
@@ -492,3 +549,80 @@ mod newtype {
         assert_eq!(en, lt);
     }
 }
+
+/// Custom derive test cases for `Box`, `Rc`, and `Arc` fields.
+mod heap {
+    use der::{Choice, Decodable, Encodable, Sequence};
+    use hex_literal::hex;
+    use std::{rc::Rc, sync::Arc};
+
+    /// Recursive `SEQUENCE` which requires boxing.
+    #[derive(Sequence, Debug, Eq, PartialEq)]
+    pub struct Node {
+        pub value: u8,
+        pub next: Option<Box<Node>>,
+    }
+
+    #[derive(Choice, Debug, Eq, PartialEq)]
+    pub enum SharedChoice {
+        Node(Arc<Node>),
+
+        #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+        Flag(Rc<bool>),
+    }
+
+    #[derive(Sequence, Debug, Eq, PartialEq)]
+    pub struct SharedSequence {
+        pub node: Rc<Node>,
+        pub choice: SharedChoice,
+    }
+
+    const NODE_DER: &[u8] = &hex!("30 08 02 01 01 30 03 02 01 02");
+
+    fn example_node() -> Node {
+        Node {
+            value: 1,
+            next: Some(Box::new(Node {
+                value: 2,
+                next: None,
+            })),
+        }
+    }
+
+    #[test]
+    fn box_test() {
+        let node = Node::from_der(NODE_DER).unwrap();
+        assert_eq!(node, example_node());
+        assert_eq!(node.to_vec().unwrap(), NODE_DER);
+
+        let boxed = Box::<Node>::from_der(NODE_DER).unwrap();
+        assert_eq!(boxed.to_vec().unwrap(), NODE_DER);
+    }
+
+    #[test]
+    fn rc_arc_test() {
+        let node = Rc::new(example_node());
+        let der_encoded = node.to_vec().unwrap();
+        assert_eq!(der_encoded, NODE_DER);
+        assert_eq!(Rc::<Node>::from_der(NODE_DER).unwrap(), node);
+        assert_eq!(Arc::<Node>::from_der(NODE_DER).unwrap().as_ref(), &*node);
+
+        let flag = SharedSequence {
+            node: node.clone(),
+            choice: SharedChoice::Flag(Rc::new(true)),
+        };
+        let der_encoded = flag.to_vec().unwrap();
+        assert_eq!(
+            der_encoded,
+            hex!("30 0d 30 08 02 01 01 30 03 02 01 02 80 01 ff")
+        );
+        assert_eq!(SharedSequence::from_der(&der_encoded).unwrap(), flag);
+
+        let shared = SharedSequence {
+            node,
+            choice: SharedChoice::Node(Arc::new(example_node())),
+        };
+        let der_encoded = shared.to_vec().unwrap();
+        assert_eq!(SharedSequence::from_der(&der_encoded).unwrap(), shared);
+    }
+}
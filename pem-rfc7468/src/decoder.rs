@@ -11,7 +11,7 @@
 //! [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648
 
 use crate::{
-    grammar, Base64Decoder, Error, Result, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
+    grammar, Base64Decoder, Error, Result, Span, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
     PRE_ENCAPSULATION_BOUNDARY,
 };
 use core::str;
@@ -61,6 +61,14 @@ pub fn decode_label(pem: &[u8]) -> Result<&str> {
     Ok(Encapsulation::try_from(pem)?.label())
 }
 
+/// Check that the type label of a PEM document is `expected`.
+///
+/// Returns [`Error::UnexpectedTypeLabel`] with the location of the actual
+/// label if it differs, which can be used with [`Span::slice`] to obtain it.
+pub fn check_type_label(pem: &[u8], expected: &'static str) -> Result<()> {
+    Encapsulation::try_from(pem)?.check_label(expected)
+}
+
 /// Buffered PEM decoder.
 ///
 /// Stateful buffered decoder type which decodes an input PEM document according
@@ -70,6 +78,9 @@ pub struct Decoder<'i> {
     /// PEM type label.
     type_label: &'i str,
 
+    /// Location of the PEM type label within the input.
+    type_label_span: Span,
+
     /// Buffered Base64 decoder.
     base64: Base64Decoder<'i>,
 }
@@ -86,8 +97,31 @@ impl<'i> Decoder<'i> {
     pub fn new_wrapped(pem: &'i [u8], line_width: usize) -> Result<Self> {
         let encapsulation = Encapsulation::try_from(pem)?;
         let type_label = encapsulation.label();
+        let type_label_span = encapsulation.label_span;
         let base64 = Base64Decoder::new_wrapped(encapsulation.encapsulated_text, line_width)?;
-        Ok(Self { type_label, base64 })
+        Ok(Self {
+            type_label,
+            type_label_span,
+            base64,
+        })
+    }
+
+    /// Create a new PEM [`Decoder`] which wraps at the width of the first line
+    /// of the encapsulated text.
+    ///
+    /// This accepts documents produced by encoders which wrap at a width
+    /// other than the one expected by the document's format, including
+    /// documents whose Base64 body is on a single line. As with
+    /// [`Decoder::new_wrapped`], all lines but the last must have the same
+    /// width.
+    pub fn new_detect_wrap(pem: &'i [u8]) -> Result<Self> {
+        let encapsulated_text = Encapsulation::try_from(pem)?.encapsulated_text;
+        let line_width = encapsulated_text
+            .iter()
+            .position(|&b| b == b'\r' || b == b'\n')
+            .unwrap_or(encapsulated_text.len());
+
+        Self::new_wrapped(pem, line_width)
     }
 
     /// Get the PEM type label for the input document.
@@ -95,6 +129,13 @@ impl<'i> Decoder<'i> {
         self.type_label
     }
 
+    /// Check that the PEM type label for the input document is `expected`.
+    ///
+    /// See [`check_type_label`].
+    pub fn check_type_label(&self, expected: &'static str) -> Result<()> {
+        check_label(self.type_label, self.type_label_span, expected)
+    }
+
     /// Decode data into the provided output buffer.
     ///
     /// There must be at least as much remaining Base64 input to be decoded
@@ -174,6 +215,9 @@ struct Encapsulation<'a> {
     /// > implementations require the labels to match; others do not.
     label: &'a str,
 
+    /// Location of the label within the input.
+    label_span: Span,
+
     /// Encapsulated text portion contained between the boundaries.
     ///
     /// This data should be encoded as Base64, however this type performs no
@@ -184,16 +228,24 @@ struct Encapsulation<'a> {
 impl<'a> Encapsulation<'a> {
     /// Parse the type label and encapsulated text from between the
     /// pre/post-encapsulation boundaries.
-    pub fn parse(data: &'a [u8]) -> Result<Self> {
+    pub fn parse(input: &'a [u8]) -> Result<Self> {
         // Strip the "preamble": optional text occurring before the pre-encapsulation boundary
-        let data = grammar::strip_preamble(data)?;
+        let data = grammar::strip_preamble(input)?;
 
         // Parse pre-encapsulation boundary (including label)
         let data = data
             .strip_prefix(PRE_ENCAPSULATION_BOUNDARY)
             .ok_or(Error::PreEncapsulationBoundary)?;
 
-        let (label, body) = grammar::split_label(data).ok_or(Error::Label)?;
+        let label_offset = input.len() - data.len();
+        let (label, body) = grammar::split_label(data).map_err(|n| match data.get(n) {
+            Some(&byte) => Error::InvalidCharacter {
+                byte,
+                position: Span::new(input, label_offset + n, 1),
+            },
+            None => Error::PreEncapsulationBoundary,
+        })?;
+        let label_span = Span::new(input, label_offset, label.len());
 
         let mut body = match grammar::strip_trailing_eol(body).unwrap_or(body) {
             [head @ .., b'-', b'-', b'-', b'-', b'-'] => head,
@@ -218,6 +270,7 @@ impl<'a> Encapsulation<'a> {
 
         Ok(Self {
             label,
+            label_span,
             encapsulated_text,
         })
     }
@@ -226,6 +279,23 @@ impl<'a> Encapsulation<'a> {
     pub fn label(self) -> &'a str {
         self.label
     }
+
+    /// Check that the label is `expected`.
+    fn check_label(self, expected: &'static str) -> Result<()> {
+        check_label(self.label, self.label_span, expected)
+    }
+}
+
+/// Check that `label`, located at `span` in the input, is `expected`.
+fn check_label(label: &str, span: Span, expected: &'static str) -> Result<()> {
+    if label == expected {
+        Ok(())
+    } else {
+        Err(Error::UnexpectedTypeLabel {
+            expected,
+            found: span,
+        })
+    }
 }
 
 impl<'a> TryFrom<&'a [u8]> for Encapsulation<'a> {
@@ -0,0 +1,128 @@
+//! Label-agnostic owned PEM documents.
+
+use crate::{decode_vec, encode_string, encoded_len, grammar, Error, LineEnding, Result};
+use alloc::{string::String, vec::Vec};
+use core::str;
+
+/// PEM document with an arbitrary type label.
+///
+/// Unlike the typed documents provided by other crates in this workspace
+/// (which ensure the label matches a particular [`PemLabel`][crate::PemLabel]),
+/// this type accepts any label permitted by RFC 7468, which makes it possible
+/// to pass through document types which aren't otherwise modeled.
+///
+/// The line ending used by the original document is retained so that
+/// documents which conform to RFC 7468's "Strict" grammar (i.e. which were
+/// produced by a conforming generator) re-encode identically.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct PemDocument {
+    /// PEM type label.
+    label: String,
+
+    /// Decoded document bytes.
+    bytes: Vec<u8>,
+
+    /// Line ending used when encoding this document.
+    line_ending: LineEnding,
+}
+
+impl PemDocument {
+    /// Create a new PEM document with the given label and contents.
+    ///
+    /// Uses the default [`LineEnding`] for the target platform when encoded.
+    pub fn new(label: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Result<Self> {
+        let label = label.into();
+        grammar::validate_label(label.as_bytes())?;
+
+        Ok(Self {
+            label,
+            bytes: bytes.into(),
+            line_ending: LineEnding::default(),
+        })
+    }
+
+    /// Decode a PEM document with any type label.
+    pub fn decode(pem: &[u8]) -> Result<Self> {
+        let (label, bytes) = decode_vec(pem)?;
+        let line_ending = detect_line_ending(pem)?;
+
+        Ok(Self {
+            label: label.into(),
+            bytes,
+            line_ending,
+        })
+    }
+
+    /// Get the PEM type label for this document.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Borrow the decoded document bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Convert into the decoded document bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Get the [`LineEnding`] used when encoding this document.
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    /// Set the [`LineEnding`] used when encoding this document.
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    /// Get the length of this document when PEM encoded.
+    pub fn encoded_len(&self) -> usize {
+        encoded_len(&self.label, self.line_ending, &self.bytes)
+    }
+
+    /// Encode this document as PEM, returning the result as a [`String`].
+    pub fn to_pem(&self) -> Result<String> {
+        encode_string(&self.label, self.line_ending, &self.bytes)
+    }
+}
+
+impl AsRef<[u8]> for PemDocument {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl str::FromStr for PemDocument {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::decode(s.as_bytes())
+    }
+}
+
+impl TryFrom<&[u8]> for PemDocument {
+    type Error = Error;
+
+    fn try_from(pem: &[u8]) -> Result<Self> {
+        Self::decode(pem)
+    }
+}
+
+/// Detect the line ending which terminates the pre-encapsulation boundary.
+fn detect_line_ending(pem: &[u8]) -> Result<LineEnding> {
+    let boundary = grammar::strip_preamble(pem)?;
+    let eol = boundary
+        .iter()
+        .position(|&byte| byte == grammar::CHAR_CR || byte == grammar::CHAR_LF)
+        .ok_or(Error::PreEncapsulationBoundary)?;
+
+    match &boundary[eol..] {
+        [grammar::CHAR_CR, grammar::CHAR_LF, ..] => Ok(LineEnding::CRLF),
+        [grammar::CHAR_CR, ..] => Ok(LineEnding::CR),
+        _ => Ok(LineEnding::LF),
+    }
+}
@@ -1,5 +1,6 @@
 //! Error types
 
+use crate::grammar;
 use core::fmt;
 
 /// Result type with the `pem-rfc7468` crate's [`Error`] type.
@@ -21,6 +22,15 @@ pub enum Error {
     /// Header detected in the encapsulated text.
     HeaderDisallowed,
 
+    /// Invalid character in the input.
+    InvalidCharacter {
+        /// Offending byte.
+        byte: u8,
+
+        /// Location of the offending byte within the input.
+        position: Span,
+    },
+
     /// Invalid label.
     Label,
 
@@ -35,6 +45,15 @@ pub enum Error {
 
     /// Errors in the post-encapsulation boundary.
     PostEncapsulationBoundary,
+
+    /// Unexpected PEM type label.
+    UnexpectedTypeLabel {
+        /// Type label which was expected.
+        expected: &'static str,
+
+        /// Location of the type label found in the input.
+        found: Span,
+    },
 }
 
 impl fmt::Display for Error {
@@ -44,6 +63,11 @@ impl fmt::Display for Error {
             Error::CharacterEncoding => f.write_str("PEM character encoding error"),
             Error::EncapsulatedText => f.write_str("PEM error in encapsulated text"),
             Error::HeaderDisallowed => f.write_str("PEM headers disallowed by RFC7468"),
+            Error::InvalidCharacter { byte, position } => write!(
+                f,
+                "PEM invalid character 0x{:02x} on line {}",
+                byte, position.line
+            ),
             Error::Label => f.write_str("PEM type label invalid"),
             Error::Length => f.write_str("PEM length invalid"),
             Error::Preamble => f.write_str("PEM preamble contains invalid data (NUL byte)"),
@@ -53,6 +77,11 @@ impl fmt::Display for Error {
             Error::PostEncapsulationBoundary => {
                 f.write_str("PEM error in post-encapsulation boundary")
             }
+            Error::UnexpectedTypeLabel { expected, found } => write!(
+                f,
+                "PEM type label on line {} invalid: expected \"{}\"",
+                found.line, expected
+            ),
         }
     }
 }
@@ -60,6 +89,45 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+/// Location of a range of bytes within a PEM document, used to report the
+/// cause of errors without borrowing the input.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Span {
+    /// Offset of the first byte from the beginning of the input.
+    pub offset: usize,
+
+    /// Length in bytes.
+    pub len: usize,
+
+    /// Line number of the first byte, starting from 1.
+    pub line: usize,
+}
+
+impl Span {
+    /// Create a new [`Span`] for the given range of `input`.
+    pub(crate) fn new(input: &[u8], offset: usize, len: usize) -> Self {
+        let preceding = input.get(..offset).unwrap_or(input);
+        let mut line = 1;
+
+        for (i, &byte) in preceding.iter().enumerate() {
+            // Lines are divided with CRLF, CR, or LF
+            if byte == grammar::CHAR_LF
+                || (byte == grammar::CHAR_CR && input.get(i + 1) != Some(&grammar::CHAR_LF))
+            {
+                line += 1;
+            }
+        }
+
+        Self { offset, len, line }
+    }
+
+    /// Get the bytes this span refers to within `input`, i.e. the document
+    /// it was reported for.
+    pub fn slice<'a>(&self, input: &'a [u8]) -> Option<&'a [u8]> {
+        input.get(self.offset..self.offset.checked_add(self.len)?)
+    }
+}
+
 impl From<base64ct::Error> for Error {
     fn from(err: base64ct::Error) -> Error {
         Error::Base64(err)
@@ -86,10 +154,12 @@ impl From<Error> for std::io::Error {
             Error::Base64(err) => return err.into(), // Use existing conversion
             Error::CharacterEncoding
             | Error::EncapsulatedText
+            | Error::InvalidCharacter { .. }
             | Error::Label
             | Error::Preamble
             | Error::PreEncapsulationBoundary
-            | Error::PostEncapsulationBoundary => std::io::ErrorKind::InvalidData,
+            | Error::PostEncapsulationBoundary
+            | Error::UnexpectedTypeLabel { .. } => std::io::ErrorKind::InvalidData,
             Error::Length => std::io::ErrorKind::UnexpectedEof,
             _ => std::io::ErrorKind::Other,
         };
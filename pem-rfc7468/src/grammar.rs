@@ -116,6 +116,9 @@ pub(crate) fn strip_trailing_eol(bytes: &[u8]) -> Option<&[u8]> {
 /// boundary. Returns the label as a `&str`, and slice beginning with the
 /// encapsulated text with leading `-----` and newline removed.
 ///
+/// On failure, returns the offset of the first byte which is invalid in the
+/// label or the rest of the boundary.
+///
 /// This implementation follows the rules put forth in Section 2, which are
 /// stricter than those found in the ABNF grammar:
 ///
@@ -130,7 +133,7 @@ pub(crate) fn strip_trailing_eol(bytes: &[u8]) -> Option<&[u8]> {
 ///   (NOTE: this is an overly strict initial implementation and should be relaxed)
 /// - Whitespace MUST NOT contain more than one consecutive WSP character
 // TODO(tarcieri): evaluate whether this is too strict; support '-'
-pub(crate) fn split_label(bytes: &[u8]) -> Option<(&str, &[u8])> {
+pub(crate) fn split_label(bytes: &[u8]) -> core::result::Result<(&str, &[u8]), usize> {
     let mut n = 0;
 
     // TODO(tarcieri): handle hyphens in labels as well as spaces
@@ -146,23 +149,25 @@ pub(crate) fn split_label(bytes: &[u8]) -> Option<(&str, &[u8])> {
         } else if n != 0 && is_wsp(char) {
             // Repeated whitespace disallowed
             if last_was_wsp {
-                return None;
+                return Err(n);
             }
 
             last_was_wsp = true;
         } else {
-            return None;
+            return Err(n);
         }
 
         n += 1;
     }
 
     let (raw_label, rest) = bytes.split_at(n);
-    let label = str::from_utf8(raw_label).ok()?;
+    let label = str::from_utf8(raw_label).map_err(|_| 0usize)?;
 
     match rest {
-        [b'-', b'-', b'-', b'-', b'-', body @ ..] => Some((label, strip_leading_eol(body)?)),
-        _ => None,
+        [b'-', b'-', b'-', b'-', b'-', body @ ..] => {
+            Ok((label, strip_leading_eol(body).ok_or(n + 5)?))
+        }
+        _ => Err(n + rest.iter().take_while(|&&byte| byte == b'-').count()),
     }
 }
 
@@ -215,7 +220,7 @@ mod tests {
     /// Reject labels containing repeated spaces
     #[test]
     fn split_label_with_repeat_wsp_is_err() {
-        assert!(split_label(b"PRIVATE  KEY-----\nBODY").is_none());
+        assert!(split_label(b"PRIVATE  KEY-----\nBODY").is_err());
     }
 
     /// Basic validation of a label
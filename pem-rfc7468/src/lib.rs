@@ -49,19 +49,21 @@ extern crate alloc;
 extern crate std;
 
 mod decoder;
+#[cfg(feature = "alloc")]
+mod document;
 mod encoder;
 mod error;
 mod grammar;
 
 pub use crate::{
-    decoder::{decode, decode_label, Decoder},
+    decoder::{check_type_label, decode, decode_label, Decoder},
     encoder::{encode, encoded_len, Encoder},
-    error::{Error, Result},
+    error::{Error, Result, Span},
 };
 pub use base64ct::LineEnding;
 
 #[cfg(feature = "alloc")]
-pub use crate::{decoder::decode_vec, encoder::encode_string};
+pub use crate::{decoder::decode_vec, document::PemDocument, encoder::encode_string};
 
 /// The pre-encapsulation boundary appears before the encapsulated text.
 ///
@@ -0,0 +1,42 @@
+//! Label-agnostic PEM document tests
+
+#![cfg(feature = "alloc")]
+
+use pem_rfc7468::{LineEnding, PemDocument};
+
+#[test]
+fn pkcs8_roundtrip() {
+    let pem = include_str!("examples/pkcs8.pem");
+    let doc = PemDocument::decode(pem.as_bytes()).unwrap();
+    assert_eq!(doc.label(), "PRIVATE KEY");
+    assert_eq!(doc.as_bytes(), include_bytes!("examples/pkcs8.der"));
+    assert_eq!(doc.line_ending(), LineEnding::LF);
+    assert_eq!(doc.encoded_len(), pem.len());
+    assert_eq!(doc.to_pem().unwrap(), pem);
+}
+
+#[test]
+fn unknown_label_roundtrip() {
+    let pem = "-----BEGIN EXAMPLE DOCUMENT-----\r\nAAECAwQFBgcICQ==\r\n-----END EXAMPLE DOCUMENT-----\r\n";
+    let doc: PemDocument = pem.parse().unwrap();
+    assert_eq!(doc.label(), "EXAMPLE DOCUMENT");
+    assert_eq!(doc.as_bytes(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(doc.line_ending(), LineEnding::CRLF);
+    assert_eq!(doc.to_pem().unwrap(), pem);
+}
+
+#[test]
+fn new_with_line_ending() {
+    let mut doc =
+        PemDocument::new("PRIVATE KEY", &include_bytes!("examples/pkcs8.der")[..]).unwrap();
+    doc.set_line_ending(LineEnding::LF);
+    assert_eq!(doc.to_pem().unwrap(), include_str!("examples/pkcs8.pem"));
+}
+
+#[test]
+fn reject_invalid_label() {
+    assert_eq!(
+        PemDocument::new("-INVALID-", vec![]),
+        Err(pem_rfc7468::Error::Label)
+    );
+}
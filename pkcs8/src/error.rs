@@ -59,6 +59,13 @@ impl From<der::ErrorKind> for Error {
     }
 }
 
+#[cfg(feature = "pem")]
+impl From<der::pem::Error> for Error {
+    fn from(err: der::pem::Error) -> Error {
+        Error::Asn1(err.into())
+    }
+}
+
 #[cfg(feature = "pkcs5")]
 impl From<pkcs5::Error> for Error {
     fn from(err: pkcs5::Error) -> Error {
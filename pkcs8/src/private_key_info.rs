@@ -1,6 +1,6 @@
 //! PKCS#8 `PrivateKeyInfo`.
 
-use crate::{AlgorithmIdentifier, Error, Result, Version};
+use crate::{AlgorithmIdentifier, Error, ObjectIdentifier, Result, Version};
 use core::fmt;
 use der::{
     asn1::{Any, BitString, ContextSpecific, OctetString},
@@ -29,6 +29,15 @@ use subtle::{Choice, ConstantTimeEq};
 /// Context-specific tag number for the public key.
 const PUBLIC_KEY_TAG: TagNumber = TagNumber::new(1);
 
+/// `rsaEncryption` algorithm OID (RFC 8017).
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+/// `id-ecPublicKey` algorithm OID (RFC 5480).
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// `id-Ed25519` algorithm OID (RFC 8410).
+const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
 /// PKCS#8 `PrivateKeyInfo`.
 ///
 /// ASN.1 structure containing an [`AlgorithmIdentifier`], private key
@@ -126,6 +135,58 @@ impl<'a> PrivateKeyInfo<'a> {
         }
     }
 
+    /// Get the `RSAPrivateKey` payload of an RSA private key.
+    ///
+    /// Checks that the algorithm is `rsaEncryption` with absent or `NULL`
+    /// parameters and returns the ASN.1 DER-encoded `RSAPrivateKey`, which
+    /// can be parsed with the `pkcs1` crate.
+    pub fn rsa(&self) -> Result<&'a [u8]> {
+        self.algorithm.assert_algorithm_oid(RSA_ENCRYPTION_OID)?;
+
+        match self.algorithm.parameters {
+            Some(params) if !params.is_null() => Err(Error::ParametersMalformed),
+            _ => Ok(self.private_key),
+        }
+    }
+
+    /// Get the named curve and `ECPrivateKey` payload of an elliptic curve
+    /// private key.
+    ///
+    /// Checks that the algorithm is `id-ecPublicKey` with a curve OID as its
+    /// parameters and returns that OID along with the ASN.1 DER-encoded
+    /// `ECPrivateKey`, which can be parsed with the `sec1` crate.
+    pub fn ec(&self) -> Result<(ObjectIdentifier, &'a [u8])> {
+        self.algorithm.assert_algorithm_oid(EC_PUBLIC_KEY_OID)?;
+
+        let curve = self
+            .algorithm
+            .parameters_oid()
+            .map_err(|_| Error::ParametersMalformed)?;
+
+        Ok((curve, self.private_key))
+    }
+
+    /// Get the seed of an Ed25519 private key.
+    ///
+    /// Checks that the algorithm is `id-Ed25519` with absent parameters as
+    /// required by [RFC 8410 Section 3] and decodes the inner
+    /// `CurvePrivateKey` OCTET STRING.
+    ///
+    /// [RFC 8410 Section 3]: https://datatracker.ietf.org/doc/html/rfc8410#section-3
+    pub fn ed25519(&self) -> Result<&'a [u8; 32]> {
+        self.algorithm.assert_algorithm_oid(ED25519_OID)?;
+
+        if self.algorithm.parameters.is_some() {
+            return Err(Error::ParametersMalformed);
+        }
+
+        OctetString::from_der(self.private_key)
+            .map_err(|_| Error::KeyMalformed)?
+            .as_bytes()
+            .try_into()
+            .map_err(|_| Error::KeyMalformed)
+    }
+
     /// Encrypt this private key using a symmetric encryption key derived
     /// from the provided password.
     ///
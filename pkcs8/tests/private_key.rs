@@ -1,5 +1,6 @@
 //! PKCS#8 private key tests
 
+use der::Decodable;
 use hex_literal::hex;
 use pkcs8::{PrivateKeyInfo, Version};
 
@@ -241,3 +242,38 @@ fn read_pem_file() {
         PrivateKeyDocument::read_pkcs8_pem_file("tests/examples/p256-priv.pem").unwrap();
     assert_eq!(pkcs8_doc.as_ref(), EC_P256_DER_EXAMPLE);
 }
+
+#[test]
+fn reject_trailing_data() {
+    let mut bytes = ED25519_DER_V1_EXAMPLE.to_vec();
+    bytes.push(0);
+
+    let err = PrivateKeyInfo::from_der(&bytes).unwrap_err();
+    assert!(matches!(err.kind(), der::ErrorKind::TrailingData { .. }));
+
+    #[cfg(feature = "pem")]
+    assert_eq!(PrivateKeyDocument::from_der(&bytes).unwrap_err(), err);
+}
+
+#[test]
+fn algorithm_specific_payloads() {
+    let pk = PrivateKeyInfo::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(pk.rsa().unwrap(), pk.private_key);
+    assert!(pk.ec().is_err());
+    assert!(pk.ed25519().is_err());
+
+    let pk = PrivateKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let (curve, ec_private_key) = pk.ec().unwrap();
+    assert_eq!(curve, "1.2.840.10045.3.1.7".parse().unwrap());
+    assert_eq!(ec_private_key, pk.private_key);
+    assert!(pk.rsa().is_err());
+
+    for der in [ED25519_DER_V1_EXAMPLE, ED25519_DER_V2_EXAMPLE] {
+        let pk = PrivateKeyInfo::try_from(der).unwrap();
+        assert_eq!(&pk.ed25519().unwrap()[..], &pk.private_key[2..]);
+    }
+
+    // X25519 keys share the inner encoding but not the algorithm
+    let pk = PrivateKeyInfo::try_from(X25519_DER_EXAMPLE).unwrap();
+    assert!(pk.ed25519().is_err());
+}
@@ -1,5 +1,6 @@
 //! Public key (`SubjectPublicKeyInfo`) tests
 
+use der::Decodable;
 use hex_literal::hex;
 use pkcs8::SubjectPublicKeyInfo;
 
@@ -181,3 +182,15 @@ fn read_pem_file() {
 
     assert_eq!(pkcs8_doc.as_ref(), EC_P256_DER_EXAMPLE);
 }
+
+#[test]
+fn reject_trailing_data() {
+    let mut bytes = ED25519_DER_EXAMPLE.to_vec();
+    bytes.push(0);
+
+    let err = SubjectPublicKeyInfo::from_der(&bytes).unwrap_err();
+    assert!(matches!(err.kind(), der::ErrorKind::TrailingData { .. }));
+
+    #[cfg(feature = "pem")]
+    assert_eq!(PublicKeyDocument::from_der(&bytes).unwrap_err(), err);
+}
@@ -1,8 +1,27 @@
+use crate::{point, Error, Result};
 use der::{
     asn1::{Any, ObjectIdentifier},
-    DecodeValue, Decoder, EncodeValue, Encoder, FixedTag, Header, Length, Tag,
+    DecodeValue, Decoder, EncodeValue, Encoder, ErrorKind, FixedTag, Header, Length, Tag,
 };
 
+/// Named curves with known field element sizes (in bytes).
+const NAMED_CURVES: &[(ObjectIdentifier, usize)] = &[
+    // secp224r1
+    (ObjectIdentifier::new_unwrap("1.3.132.0.33"), 28),
+    // secp256r1 (a.k.a. NIST P-256, prime256v1)
+    (ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7"), 32),
+    // secp384r1 (a.k.a. NIST P-384)
+    (ObjectIdentifier::new_unwrap("1.3.132.0.34"), 48),
+    // secp521r1 (a.k.a. NIST P-521)
+    (ObjectIdentifier::new_unwrap("1.3.132.0.35"), 66),
+    // brainpoolP256r1
+    (ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.7"), 32),
+    // brainpoolP384r1
+    (ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.11"), 48),
+    // brainpoolP512r1
+    (ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.13"), 64),
+];
+
 /// Elliptic curve parameters as described in
 /// [RFC5480 Section 2.1.1](https://datatracker.ietf.org/doc/html/rfc5480#section-2.1.1):
 ///
@@ -54,6 +73,55 @@ impl EcParameters {
             Self::NamedCurve(oid) => Some(oid),
         }
     }
+
+    /// Get the size in bytes of a serialized field element of the named
+    /// curve, if it's a known curve.
+    ///
+    /// Known curves are the NIST curves secp224r1, secp256r1, secp384r1, and
+    /// secp521r1, as well as brainpoolP256r1, brainpoolP384r1, and
+    /// brainpoolP512r1 from [RFC5639].
+    ///
+    /// [RFC5639]: https://datatracker.ietf.org/doc/html/rfc5639
+    pub fn field_size(self) -> Option<usize> {
+        let oid = self.named_curve()?;
+
+        NAMED_CURVES
+            .iter()
+            .find(|(curve, _)| *curve == oid)
+            .map(|&(_, size)| size)
+    }
+
+    /// Check that `public_key` is a compressed or uncompressed SEC1 encoded
+    /// point of the correct size for the named curve.
+    ///
+    /// This only validates the encoding: it doesn't check that the point is
+    /// on the curve.
+    ///
+    /// Returns [`ErrorKind::OidUnknown`] if the curve is unknown, or
+    /// [`Error::PointEncoding`] if the point is malformed, compact, or the
+    /// identity.
+    pub fn validate_public_key(self, public_key: &[u8]) -> Result<()> {
+        let field_size = match self.field_size() {
+            Some(size) => size,
+            None => {
+                let Self::NamedCurve(oid) = self;
+                return Err(Error::Asn1(ErrorKind::OidUnknown { oid }.into()));
+            }
+        };
+
+        let tag = public_key
+            .first()
+            .cloned()
+            .ok_or(Error::PointEncoding)
+            .and_then(point::Tag::from_u8)?;
+
+        if tag.is_identity() || tag.is_compact() || public_key.len() != tag.message_len(field_size)
+        {
+            return Err(Error::PointEncoding);
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> From<&'a EcParameters> for Any<'a> {
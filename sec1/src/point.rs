@@ -13,7 +13,7 @@ use core::{
     str,
 };
 use generic_array::{
-    typenum::{U1, U28, U32, U48, U66},
+    typenum::{U1, U28, U32, U48, U64, U66},
     ArrayLength, GenericArray,
 };
 
@@ -58,7 +58,7 @@ macro_rules! impl_modulus_size {
     }
 }
 
-impl_modulus_size!(U28, U32, U48, U66);
+impl_modulus_size!(U28, U32, U48, U64, U66);
 
 /// SEC1 encoded curve point.
 ///
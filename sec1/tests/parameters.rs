@@ -0,0 +1,79 @@
+//! Elliptic curve parameters tests.
+
+use der::{asn1::ObjectIdentifier, ErrorKind};
+use sec1::{EcParameters, Error};
+
+/// OID for the brainpoolP512r1 elliptic curve.
+const BRAINPOOL_P512_R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.13");
+
+/// OID for the NIST P-521 elliptic curve (a.k.a. `secp521r1`).
+const SECP521_R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.35");
+
+#[test]
+fn field_size() {
+    let oids = [
+        ("1.3.132.0.33", 28),
+        ("1.2.840.10045.3.1.7", 32),
+        ("1.3.132.0.34", 48),
+        ("1.3.132.0.35", 66),
+        ("1.3.36.3.3.2.8.1.1.7", 32),
+        ("1.3.36.3.3.2.8.1.1.11", 48),
+        ("1.3.36.3.3.2.8.1.1.13", 64),
+    ];
+
+    for (oid, size) in oids {
+        let params = EcParameters::from(oid.parse::<ObjectIdentifier>().unwrap());
+        assert_eq!(Some(size), params.field_size());
+    }
+
+    let unknown = EcParameters::from(ObjectIdentifier::new_unwrap("1.3.132.0.10"));
+    assert_eq!(None, unknown.field_size());
+}
+
+#[test]
+fn validate_secp521r1_public_key() {
+    let params = EcParameters::from(SECP521_R1);
+
+    let mut uncompressed = [0u8; 133];
+    uncompressed[0] = 0x04;
+    assert_eq!(Ok(()), params.validate_public_key(&uncompressed));
+
+    let mut compressed = [0u8; 67];
+    compressed[0] = 0x03;
+    assert_eq!(Ok(()), params.validate_public_key(&compressed));
+
+    assert_eq!(
+        Err(Error::PointEncoding),
+        params.validate_public_key(&uncompressed[..129])
+    );
+}
+
+#[test]
+fn validate_brainpool_p512r1_public_key() {
+    let params = EcParameters::from(BRAINPOOL_P512_R1);
+
+    let mut uncompressed = [0u8; 129];
+    uncompressed[0] = 0x04;
+    assert_eq!(Ok(()), params.validate_public_key(&uncompressed));
+
+    let mut compressed = [0u8; 65];
+    compressed[0] = 0x02;
+    assert_eq!(Ok(()), params.validate_public_key(&compressed));
+
+    // Identity and compact points aren't valid public keys
+    assert_eq!(Err(Error::PointEncoding), params.validate_public_key(&[0]));
+    compressed[0] = 0x05;
+    assert_eq!(
+        Err(Error::PointEncoding),
+        params.validate_public_key(&compressed)
+    );
+    assert_eq!(Err(Error::PointEncoding), params.validate_public_key(&[]));
+}
+
+#[test]
+fn validate_unknown_curve_public_key() {
+    let oid = ObjectIdentifier::new_unwrap("1.3.132.0.10");
+    let params = EcParameters::from(oid);
+    let err = params.validate_public_key(&[0x02; 33]).unwrap_err();
+    assert_eq!(Error::Asn1(ErrorKind::OidUnknown { oid }.into()), err);
+}
@@ -54,5 +54,18 @@ impl From<der::Error> for Error {
     }
 }
 
+impl From<der::ErrorKind> for Error {
+    fn from(kind: der::ErrorKind) -> Error {
+        der::Error::from(kind).into()
+    }
+}
+
+#[cfg(feature = "pem")]
+impl From<der::pem::Error> for Error {
+    fn from(err: der::pem::Error) -> Error {
+        der::Error::from(err).into()
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
@@ -101,3 +101,12 @@ fn encode_ed25519_pem() {
 
     assert_eq!(ED25519_PEM_EXAMPLE, pk_encoded);
 }
+
+#[test]
+fn error_from_der_error_kind() {
+    let oid = spki::ObjectIdentifier::new_unwrap("1.3.101.112");
+    assert_eq!(
+        spki::Error::from(spki::der::ErrorKind::OidUnknown { oid }),
+        spki::Error::OidUnknown { oid }
+    );
+}
@@ -0,0 +1,64 @@
+//! Private key parsing benchmarks.
+//!
+//! Also reports the number of heap allocations made when decoding each key.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ssh_key::PrivateKey;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// RSA (3072-bit) OpenSSH-formatted private key
+const OSSH_RSA_3072_EXAMPLE: &str = include_str!("../tests/examples/id_rsa_3072");
+
+/// RSA (4096-bit) OpenSSH-formatted private key
+const OSSH_RSA_4096_EXAMPLE: &str = include_str!("../tests/examples/id_rsa_4096");
+
+/// Ed25519 OpenSSH-formatted private key
+const OSSH_ED25519_EXAMPLE: &str = include_str!("../tests/examples/id_ed25519");
+
+/// Allocator which counts the allocations it makes.
+struct CountingAllocator;
+
+/// Number of allocations made so far.
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn private_key(c: &mut Criterion) {
+    for (name, key) in [
+        ("Ed25519", OSSH_ED25519_EXAMPLE),
+        ("RSA 3072", OSSH_RSA_3072_EXAMPLE),
+        ("RSA 4096", OSSH_RSA_4096_EXAMPLE),
+    ] {
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let private_key = PrivateKey::from_openssh(key).unwrap();
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+        drop(private_key);
+
+        println!(
+            "PrivateKey::from_openssh ({}): {} allocations",
+            name, allocations
+        );
+
+        c.bench_function(&format!("PrivateKey::from_openssh ({})", name), |b| {
+            b.iter(|| PrivateKey::from_openssh(black_box(key)).unwrap())
+        });
+    }
+}
+
+criterion_group!(benches, private_key);
+criterion_main!(benches);
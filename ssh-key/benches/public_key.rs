@@ -0,0 +1,53 @@
+//! Public key parsing benchmarks.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ssh_key::{public::PublicKeyRef, AuthorizedKeys, PublicKey};
+
+/// Ed25519 OpenSSH-formatted public key
+const OSSH_ED25519_EXAMPLE: &str = include_str!("../tests/examples/id_ed25519.pub");
+
+/// ECDSA/P-256 OpenSSH-formatted public key
+const OSSH_ECDSA_P256_EXAMPLE: &str = include_str!("../tests/examples/id_ecdsa_p256.pub");
+
+/// Example `authorized_keys` file
+const AUTHORIZED_KEYS_EXAMPLE: &str = include_str!("../tests/examples/authorized_keys");
+
+fn public_key(c: &mut Criterion) {
+    for (name, key) in [
+        ("Ed25519", OSSH_ED25519_EXAMPLE),
+        ("ECDSA/P-256", OSSH_ECDSA_P256_EXAMPLE),
+    ] {
+        c.bench_function(&format!("PublicKey::from_openssh ({})", name), |b| {
+            b.iter(|| PublicKey::from_openssh(black_box(key)).unwrap())
+        });
+        c.bench_function(&format!("PublicKeyRef::from_openssh ({})", name), |b| {
+            b.iter(|| PublicKeyRef::from_openssh(black_box(key)).unwrap())
+        });
+    }
+}
+
+fn authorized_keys(c: &mut Criterion) {
+    c.bench_function("AuthorizedKeys::next", |b| {
+        b.iter(|| {
+            AuthorizedKeys::new(black_box(AUTHORIZED_KEYS_EXAMPLE))
+                .map(Result::unwrap)
+                .fold(0, |count, _| count + 1)
+        })
+    });
+    c.bench_function("AuthorizedKeys::next_ref", |b| {
+        b.iter(|| {
+            let mut authorized_keys = AuthorizedKeys::new(black_box(AUTHORIZED_KEYS_EXAMPLE));
+            let mut count = 0;
+
+            while let Some(entry) = authorized_keys.next_ref() {
+                entry.unwrap();
+                count += 1;
+            }
+
+            count
+        })
+    });
+}
+
+criterion_group!(benches, public_key, authorized_keys);
+criterion_main!(benches);
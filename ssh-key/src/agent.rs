@@ -0,0 +1,511 @@
+//! SSH agent protocol support.
+//!
+//! Support for encoding and decoding the messages exchanged between an SSH
+//! agent and its clients, as described in [draft-miller-ssh-agent].
+//!
+//! Every message is framed with a `uint32` length prefix, followed by a
+//! message type byte and the message contents. The [`Request::decode`] and
+//! [`Response::decode`] functions expect the complete framed message, which
+//! can be read from a stream by first reading the 4-byte length prefix.
+//!
+//! [draft-miller-ssh-agent]: https://datatracker.ietf.org/doc/html/draft-miller-ssh-agent
+
+use crate::{
+    base64::{Decode, DecoderExt, Encode, EncoderExt},
+    private::KeypairData,
+    public::KeyData,
+    Algorithm, Error, HashAlg, PublicKey, Result,
+};
+use alloc::{string::String, vec::Vec};
+
+/// `SSH_AGENT_FAILURE`
+const SSH_AGENT_FAILURE: u8 = 5;
+
+/// `SSH_AGENT_SUCCESS`
+const SSH_AGENT_SUCCESS: u8 = 6;
+
+/// `SSH_AGENTC_REQUEST_IDENTITIES`
+const SSH_AGENTC_REQUEST_IDENTITIES: u8 = 11;
+
+/// `SSH_AGENT_IDENTITIES_ANSWER`
+const SSH_AGENT_IDENTITIES_ANSWER: u8 = 12;
+
+/// `SSH_AGENTC_SIGN_REQUEST`
+const SSH_AGENTC_SIGN_REQUEST: u8 = 13;
+
+/// `SSH_AGENT_SIGN_RESPONSE`
+const SSH_AGENT_SIGN_RESPONSE: u8 = 14;
+
+/// `SSH_AGENTC_ADD_IDENTITY`
+const SSH_AGENTC_ADD_IDENTITY: u8 = 17;
+
+/// `SSH_AGENTC_REMOVE_IDENTITY`
+const SSH_AGENTC_REMOVE_IDENTITY: u8 = 18;
+
+/// `SSH_AGENTC_REMOVE_ALL_IDENTITIES`
+const SSH_AGENTC_REMOVE_ALL_IDENTITIES: u8 = 19;
+
+/// `SSH_AGENTC_ADD_ID_CONSTRAINED`
+const SSH_AGENTC_ADD_ID_CONSTRAINED: u8 = 25;
+
+/// `SSH_AGENTC_EXTENSION`
+const SSH_AGENTC_EXTENSION: u8 = 27;
+
+/// `SSH_AGENT_EXTENSION_FAILURE`
+const SSH_AGENT_EXTENSION_FAILURE: u8 = 28;
+
+/// `SSH_AGENT_CONSTRAIN_LIFETIME`
+const SSH_AGENT_CONSTRAIN_LIFETIME: u8 = 1;
+
+/// `SSH_AGENT_CONSTRAIN_CONFIRM`
+const SSH_AGENT_CONSTRAIN_CONFIRM: u8 = 2;
+
+/// Message sent by a client to an SSH agent.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Request {
+    /// `SSH_AGENTC_REQUEST_IDENTITIES`: list the keys held by the agent.
+    RequestIdentities,
+
+    /// `SSH_AGENTC_SIGN_REQUEST`: sign data using one of the agent's keys.
+    SignRequest(SignRequest),
+
+    /// `SSH_AGENTC_ADD_IDENTITY` or `SSH_AGENTC_ADD_ID_CONSTRAINED`: add a
+    /// private key to the agent.
+    AddIdentity(AddIdentity),
+
+    /// `SSH_AGENTC_REMOVE_IDENTITY`: remove the key with the given public key
+    /// from the agent.
+    RemoveIdentity(KeyData),
+
+    /// `SSH_AGENTC_REMOVE_ALL_IDENTITIES`: remove all keys from the agent.
+    RemoveAllIdentities,
+
+    /// `SSH_AGENTC_EXTENSION` with the `session-bind@openssh.com` extension:
+    /// bind the agent connection to an SSH session.
+    SessionBind(SessionBind),
+}
+
+impl Request {
+    /// Decode a framed request message, including its length prefix.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        decode_message(bytes, |msg_type, decoder| match msg_type {
+            SSH_AGENTC_REQUEST_IDENTITIES => Ok(Self::RequestIdentities),
+            SSH_AGENTC_SIGN_REQUEST => SignRequest::decode(decoder).map(Self::SignRequest),
+            SSH_AGENTC_ADD_IDENTITY => {
+                let key_data = KeypairData::decode(decoder)?;
+                let comment = decoder.decode_string()?;
+
+                Ok(Self::AddIdentity(AddIdentity {
+                    key_data,
+                    comment,
+                    constraints: Vec::new(),
+                }))
+            }
+            SSH_AGENTC_ADD_ID_CONSTRAINED => {
+                let key_data = KeypairData::decode(decoder)?;
+                let comment = decoder.decode_string()?;
+
+                // Constraints extend to the end of the message
+                let mut constraints = Vec::new();
+                while !decoder.is_empty() {
+                    constraints.push(Constraint::decode(decoder)?);
+                }
+
+                Ok(Self::AddIdentity(AddIdentity {
+                    key_data,
+                    comment,
+                    constraints,
+                }))
+            }
+            SSH_AGENTC_REMOVE_IDENTITY => decode_key_blob(decoder).map(Self::RemoveIdentity),
+            SSH_AGENTC_REMOVE_ALL_IDENTITIES => Ok(Self::RemoveAllIdentities),
+            SSH_AGENTC_EXTENSION => {
+                let mut buf = [0u8; SessionBind::EXTENSION_NAME.len()];
+
+                // Other extensions are unsupported
+                match decoder.decode_str(&mut buf) {
+                    Ok(SessionBind::EXTENSION_NAME) => {
+                        SessionBind::decode(decoder).map(Self::SessionBind)
+                    }
+                    _ => Err(Error::FormatEncoding),
+                }
+            }
+            _ => Err(Error::FormatEncoding),
+        })
+    }
+
+    /// Encode this request as a framed message, including its length prefix.
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut msg = Vec::new();
+
+        match self {
+            Self::RequestIdentities => msg.encode_u8(SSH_AGENTC_REQUEST_IDENTITIES)?,
+            Self::SignRequest(request) => {
+                msg.encode_u8(SSH_AGENTC_SIGN_REQUEST)?;
+                request.encode(&mut msg)?;
+            }
+            Self::AddIdentity(request) => {
+                if request.constraints.is_empty() {
+                    msg.encode_u8(SSH_AGENTC_ADD_IDENTITY)?;
+                } else {
+                    msg.encode_u8(SSH_AGENTC_ADD_ID_CONSTRAINED)?;
+                }
+
+                request.key_data.encode(&mut msg)?;
+                msg.encode_str(&request.comment)?;
+
+                for constraint in &request.constraints {
+                    constraint.encode(&mut msg)?;
+                }
+            }
+            Self::RemoveIdentity(key_data) => {
+                msg.encode_u8(SSH_AGENTC_REMOVE_IDENTITY)?;
+                encode_key_blob(&mut msg, key_data)?;
+            }
+            Self::RemoveAllIdentities => msg.encode_u8(SSH_AGENTC_REMOVE_ALL_IDENTITIES)?,
+            Self::SessionBind(request) => {
+                msg.encode_u8(SSH_AGENTC_EXTENSION)?;
+                msg.encode_str(SessionBind::EXTENSION_NAME)?;
+                request.encode(&mut msg)?;
+            }
+        }
+
+        frame_message(msg)
+    }
+}
+
+/// Message sent by an SSH agent in reply to a [`Request`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Response {
+    /// `SSH_AGENT_FAILURE`: the request failed or is unsupported.
+    Failure,
+
+    /// `SSH_AGENT_SUCCESS`: the request succeeded.
+    Success,
+
+    /// `SSH_AGENT_IDENTITIES_ANSWER`: the public keys held by the agent,
+    /// along with their comments.
+    IdentitiesAnswer(Vec<PublicKey>),
+
+    /// `SSH_AGENT_SIGN_RESPONSE`: the encoded signature, consisting of the
+    /// signature algorithm name followed by the algorithm-specific signature.
+    SignResponse(Vec<u8>),
+
+    /// `SSH_AGENT_EXTENSION_FAILURE`: an extension request failed.
+    ExtensionFailure,
+}
+
+impl Response {
+    /// Decode a framed response message, including its length prefix.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        decode_message(bytes, |msg_type, decoder| match msg_type {
+            SSH_AGENT_FAILURE => Ok(Self::Failure),
+            SSH_AGENT_SUCCESS => Ok(Self::Success),
+            SSH_AGENT_IDENTITIES_ANSWER => {
+                let nkeys = decoder.decode_usize()?;
+                let mut identities = Vec::new();
+
+                for _ in 0..nkeys {
+                    let key_data = decode_key_blob(decoder)?;
+                    let comment = decoder.decode_string()?;
+                    identities.push(PublicKey { key_data, comment });
+                }
+
+                Ok(Self::IdentitiesAnswer(identities))
+            }
+            SSH_AGENT_SIGN_RESPONSE => decoder.decode_byte_vec().map(Self::SignResponse),
+            SSH_AGENT_EXTENSION_FAILURE => Ok(Self::ExtensionFailure),
+            _ => Err(Error::FormatEncoding),
+        })
+    }
+
+    /// Encode this response as a framed message, including its length prefix.
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut msg = Vec::new();
+
+        match self {
+            Self::Failure => msg.encode_u8(SSH_AGENT_FAILURE)?,
+            Self::Success => msg.encode_u8(SSH_AGENT_SUCCESS)?,
+            Self::IdentitiesAnswer(identities) => {
+                msg.encode_u8(SSH_AGENT_IDENTITIES_ANSWER)?;
+                msg.encode_usize(identities.len())?;
+
+                for identity in identities {
+                    encode_key_blob(&mut msg, &identity.key_data)?;
+                    msg.encode_str(&identity.comment)?;
+                }
+            }
+            Self::SignResponse(signature) => {
+                msg.encode_u8(SSH_AGENT_SIGN_RESPONSE)?;
+                msg.encode_byte_slice(signature)?;
+            }
+            Self::ExtensionFailure => msg.encode_u8(SSH_AGENT_EXTENSION_FAILURE)?,
+        }
+
+        frame_message(msg)
+    }
+}
+
+/// `SSH_AGENTC_SIGN_REQUEST` message contents.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignRequest {
+    /// Public key of the agent's key to sign with.
+    pub key_data: KeyData,
+
+    /// Data to be signed.
+    pub data: Vec<u8>,
+
+    /// Signature flags (e.g. [`SignRequest::RSA_SHA2_256`]).
+    pub flags: u32,
+}
+
+impl SignRequest {
+    /// `SSH_AGENT_RSA_SHA2_256`: sign using `rsa-sha2-256` rather than `ssh-rsa`.
+    pub const RSA_SHA2_256: u32 = 2;
+
+    /// `SSH_AGENT_RSA_SHA2_512`: sign using `rsa-sha2-512` rather than `ssh-rsa`.
+    pub const RSA_SHA2_512: u32 = 4;
+
+    /// Create a request to sign `data` with the given signature algorithm.
+    ///
+    /// For RSA keys, the `rsa-sha2-*` algorithms set the corresponding flag,
+    /// while [`Algorithm::Rsa`] requests a SHA-1 `ssh-rsa` signature, which
+    /// many servers no longer accept.
+    ///
+    /// Returns [`Error::Algorithm`] if the signature algorithm can't be used
+    /// with the key.
+    pub fn new(key_data: KeyData, data: Vec<u8>, algorithm: Algorithm) -> Result<Self> {
+        if algorithm.key_algorithm() != key_data.algorithm() {
+            return Err(Error::Algorithm);
+        }
+
+        let flags = match algorithm.rsa_hash_alg() {
+            Some(HashAlg::Sha256) => Self::RSA_SHA2_256,
+            Some(HashAlg::Sha512) => Self::RSA_SHA2_512,
+            None => 0,
+        };
+
+        Ok(Self {
+            key_data,
+            data,
+            flags,
+        })
+    }
+
+    /// Get the signature algorithm requested for this key and flags.
+    ///
+    /// If both RSA flags are set, `rsa-sha2-256` takes precedence (as in
+    /// OpenSSH's `ssh-agent`). The flags are ignored for non-RSA keys.
+    pub fn signature_algorithm(&self) -> Algorithm {
+        match self.key_data.algorithm() {
+            Algorithm::Rsa if self.flags & Self::RSA_SHA2_256 != 0 => {
+                Algorithm::RsaSha2(HashAlg::Sha256)
+            }
+            Algorithm::Rsa if self.flags & Self::RSA_SHA2_512 != 0 => {
+                Algorithm::RsaSha2(HashAlg::Sha512)
+            }
+            alg => alg,
+        }
+    }
+}
+
+impl Decode for SignRequest {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let key_data = decode_key_blob(decoder)?;
+        let data = decoder.decode_byte_vec()?;
+        let flags = decoder.decode_u32()?;
+
+        Ok(Self {
+            key_data,
+            data,
+            flags,
+        })
+    }
+}
+
+impl Encode for SignRequest {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + self.key_data.encoded_len()? + 4 + self.data.len() + 4)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        encode_key_blob(encoder, &self.key_data)?;
+        encoder.encode_byte_slice(&self.data)?;
+        encoder.encode_u32(self.flags)
+    }
+}
+
+/// `SSH_AGENTC_ADD_IDENTITY` and `SSH_AGENTC_ADD_ID_CONSTRAINED` message
+/// contents.
+///
+/// The key is sent using the same serialization as the private section of
+/// an OpenSSH private key.
+#[derive(Clone, Debug)]
+pub struct AddIdentity {
+    /// Private key to add.
+    pub key_data: KeypairData,
+
+    /// Comment on the key.
+    pub comment: String,
+
+    /// Constraints on the use of the key. If non-empty, the request is sent
+    /// as `SSH_AGENTC_ADD_ID_CONSTRAINED`.
+    pub constraints: Vec<Constraint>,
+}
+
+/// Key constraint used with `SSH_AGENTC_ADD_ID_CONSTRAINED`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Constraint {
+    /// `SSH_AGENT_CONSTRAIN_LIFETIME`: remove the key after the given number
+    /// of seconds.
+    Lifetime(u32),
+
+    /// `SSH_AGENT_CONSTRAIN_CONFIRM`: confirm each use of the key with the
+    /// user.
+    Confirm,
+}
+
+impl Decode for Constraint {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        match decoder.decode_u8()? {
+            SSH_AGENT_CONSTRAIN_LIFETIME => decoder.decode_u32().map(Self::Lifetime),
+            SSH_AGENT_CONSTRAIN_CONFIRM => Ok(Self::Confirm),
+            // Extension constraints are not length-prefixed, so they can't be skipped
+            _ => Err(Error::FormatEncoding),
+        }
+    }
+}
+
+impl Encode for Constraint {
+    fn encoded_len(&self) -> Result<usize> {
+        match self {
+            Self::Lifetime(_) => Ok(5),
+            Self::Confirm => Ok(1),
+        }
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        match self {
+            Self::Lifetime(seconds) => {
+                encoder.encode_u8(SSH_AGENT_CONSTRAIN_LIFETIME)?;
+                encoder.encode_u32(*seconds)
+            }
+            Self::Confirm => encoder.encode_u8(SSH_AGENT_CONSTRAIN_CONFIRM),
+        }
+    }
+}
+
+/// `session-bind@openssh.com` extension message contents, as described in
+/// OpenSSH's [PROTOCOL.agent].
+///
+/// Sent by the SSH client after key exchange to bind the agent connection to
+/// the session, allowing the agent to enforce per-destination restrictions
+/// on the use of its keys. The agent should verify `signature` over
+/// `session_id` using `host_key` before accepting the binding.
+///
+/// [PROTOCOL.agent]: https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.agent
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionBind {
+    /// Public host key of the server.
+    pub host_key: KeyData,
+
+    /// Session identifier (exchange hash) from the SSH key exchange.
+    pub session_id: Vec<u8>,
+
+    /// Signature by the host key over the session identifier, as sent in the
+    /// server's `SSH_MSG_KEXDH_REPLY`.
+    pub signature: Vec<u8>,
+
+    /// Is the agent connection being forwarded (as opposed to used for
+    /// authentication)?
+    pub is_forwarding: bool,
+}
+
+impl SessionBind {
+    /// Name of this extension.
+    pub const EXTENSION_NAME: &'static str = "session-bind@openssh.com";
+}
+
+impl Decode for SessionBind {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let host_key = decode_key_blob(decoder)?;
+        let session_id = decoder.decode_byte_vec()?;
+        let signature = decoder.decode_byte_vec()?;
+        let is_forwarding = match decoder.decode_u8()? {
+            0 => false,
+            1 => true,
+            _ => return Err(Error::FormatEncoding),
+        };
+
+        Ok(Self {
+            host_key,
+            session_id,
+            signature,
+            is_forwarding,
+        })
+    }
+}
+
+impl Encode for SessionBind {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + self.host_key.encoded_len()?
+            + 4
+            + self.session_id.len()
+            + 4
+            + self.signature.len()
+            + 1)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        encode_key_blob(encoder, &self.host_key)?;
+        encoder.encode_byte_slice(&self.session_id)?;
+        encoder.encode_byte_slice(&self.signature)?;
+        encoder.encode_u8(self.is_forwarding as u8)
+    }
+}
+
+/// Decode a framed message, using the provided function to decode the message
+/// contents according to the message type.
+fn decode_message<T>(mut bytes: &[u8], f: impl FnOnce(u8, &mut &[u8]) -> Result<T>) -> Result<T> {
+    if bytes.decode_usize()? != bytes.len() {
+        return Err(Error::Length);
+    }
+
+    let msg_type = bytes.decode_u8()?;
+    let msg = f(msg_type, &mut bytes)?;
+
+    if bytes.is_empty() {
+        Ok(msg)
+    } else {
+        Err(Error::Length)
+    }
+}
+
+/// Prefix an encoded message with its length.
+fn frame_message(msg: Vec<u8>) -> Result<Vec<u8>> {
+    let mut framed = Vec::with_capacity(4 + msg.len());
+    framed.encode_byte_slice(&msg)?;
+    Ok(framed)
+}
+
+/// Decode a public key blob, i.e. a `string` containing an encoded public key.
+fn decode_key_blob(decoder: &mut impl DecoderExt) -> Result<KeyData> {
+    let blob = decoder.decode_byte_vec()?;
+    let mut reader = blob.as_slice();
+    let key_data = KeyData::decode(&mut reader)?;
+
+    if reader.is_empty() {
+        Ok(key_data)
+    } else {
+        Err(Error::Length)
+    }
+}
+
+/// Encode a public key blob, i.e. a `string` containing an encoded public key.
+fn encode_key_blob(encoder: &mut impl EncoderExt, key_data: &KeyData) -> Result<()> {
+    encoder.encode_usize(key_data.encoded_len()?)?;
+    key_data.encode(encoder)
+}
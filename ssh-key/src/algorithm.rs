@@ -6,6 +6,9 @@ use crate::{
 };
 use core::{fmt, str};
 
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
+
 /// ECDSA with SHA-256 + NIST P-256
 const ECDSA_SHA2_P256: &str = "ecdsa-sha2-nistp256";
 
@@ -24,6 +27,48 @@ const SSH_ED25519: &str = "ssh-ed25519";
 /// RSA
 const SSH_RSA: &str = "ssh-rsa";
 
+/// RSA with SHA-256 as described in RFC8332 § 3
+const RSA_SHA2_256: &str = "rsa-sha2-256";
+
+/// RSA with SHA-512 as described in RFC8332 § 3
+const RSA_SHA2_512: &str = "rsa-sha2-512";
+
+/// FIDO/U2F key with ECDSA/NIST P-256 + SHA-256
+const SK_ECDSA_SHA2_P256: &str = "sk-ecdsa-sha2-nistp256@openssh.com";
+
+/// FIDO/U2F key with Ed25519
+const SK_SSH_ED25519: &str = "sk-ssh-ed25519@openssh.com";
+
+/// OpenSSH certificate with ECDSA/NIST P-256
+const CERT_ECDSA_SHA2_P256: &str = "ecdsa-sha2-nistp256-cert-v01@openssh.com";
+
+/// OpenSSH certificate with ECDSA/NIST P-384
+const CERT_ECDSA_SHA2_P384: &str = "ecdsa-sha2-nistp384-cert-v01@openssh.com";
+
+/// OpenSSH certificate with ECDSA/NIST P-521
+const CERT_ECDSA_SHA2_P521: &str = "ecdsa-sha2-nistp521-cert-v01@openssh.com";
+
+/// OpenSSH certificate with DSA
+const CERT_DSA: &str = "ssh-dss-cert-v01@openssh.com";
+
+/// OpenSSH certificate with Ed25519
+const CERT_ED25519: &str = "ssh-ed25519-cert-v01@openssh.com";
+
+/// OpenSSH certificate with RSA
+const CERT_RSA: &str = "ssh-rsa-cert-v01@openssh.com";
+
+/// OpenSSH certificate with RSA, signed using SHA-256
+const CERT_RSA_SHA2_256: &str = "rsa-sha2-256-cert-v01@openssh.com";
+
+/// OpenSSH certificate with RSA, signed using SHA-512
+const CERT_RSA_SHA2_512: &str = "rsa-sha2-512-cert-v01@openssh.com";
+
+/// OpenSSH certificate with FIDO/U2F ECDSA/NIST P-256 key
+const CERT_SK_ECDSA_SHA2_P256: &str = "sk-ecdsa-sha2-nistp256-cert-v01@openssh.com";
+
+/// OpenSSH certificate with FIDO/U2F Ed25519 key
+const CERT_SK_SSH_ED25519: &str = "sk-ssh-ed25519-cert-v01@openssh.com";
+
 /// SSH key algorithms.
 ///
 /// This type provides a registry of supported digital signature algorithms
@@ -42,11 +87,23 @@ pub enum Algorithm {
 
     /// RSA
     Rsa,
+
+    /// RSA signatures using the given hash function as described in RFC8332.
+    ///
+    /// These identifiers only name signature algorithms: the keys
+    /// themselves are always encoded as [`Algorithm::Rsa`].
+    RsaSha2(HashAlg),
+
+    /// FIDO/U2F key with ECDSA/NIST P-256 + SHA-256
+    SkEcdsaSha2NistP256,
+
+    /// FIDO/U2F key with Ed25519
+    SkEd25519,
 }
 
 impl Algorithm {
     /// Maximum size of algorithms known to this crate in bytes.
-    const MAX_SIZE: usize = 20;
+    const MAX_SIZE: usize = 34;
 
     /// Decode algorithm from the given string identifier.
     ///
@@ -57,6 +114,13 @@ impl Algorithm {
     /// - `ssh-dss`
     /// - `ssh-ed25519`
     /// - `ssh-rsa`
+    /// - `rsa-sha2-256` (signature algorithm)
+    /// - `rsa-sha2-512` (signature algorithm)
+    /// - `sk-ecdsa-sha2-nistp256@openssh.com` (FIDO/U2F key)
+    /// - `sk-ssh-ed25519@openssh.com` (FIDO/U2F key)
+    ///
+    /// See [`Algorithm::new_certificate`] for the OpenSSH certificate forms
+    /// of these identifiers.
     pub fn new(id: &str) -> Result<Self> {
         match id {
             ECDSA_SHA2_P256 => Ok(Algorithm::Ecdsa(EcdsaCurve::NistP256)),
@@ -65,6 +129,28 @@ impl Algorithm {
             SSH_DSA => Ok(Algorithm::Dsa),
             SSH_ED25519 => Ok(Algorithm::Ed25519),
             SSH_RSA => Ok(Algorithm::Rsa),
+            RSA_SHA2_256 => Ok(Algorithm::RsaSha2(HashAlg::Sha256)),
+            RSA_SHA2_512 => Ok(Algorithm::RsaSha2(HashAlg::Sha512)),
+            SK_ECDSA_SHA2_P256 => Ok(Algorithm::SkEcdsaSha2NistP256),
+            SK_SSH_ED25519 => Ok(Algorithm::SkEd25519),
+            _ => Err(Error::Algorithm),
+        }
+    }
+
+    /// Decode algorithm from the given OpenSSH certificate type identifier,
+    /// e.g. `ssh-ed25519-cert-v01@openssh.com`.
+    pub fn new_certificate(id: &str) -> Result<Self> {
+        match id {
+            CERT_ECDSA_SHA2_P256 => Ok(Algorithm::Ecdsa(EcdsaCurve::NistP256)),
+            CERT_ECDSA_SHA2_P384 => Ok(Algorithm::Ecdsa(EcdsaCurve::NistP384)),
+            CERT_ECDSA_SHA2_P521 => Ok(Algorithm::Ecdsa(EcdsaCurve::NistP521)),
+            CERT_DSA => Ok(Algorithm::Dsa),
+            CERT_ED25519 => Ok(Algorithm::Ed25519),
+            CERT_RSA => Ok(Algorithm::Rsa),
+            CERT_RSA_SHA2_256 => Ok(Algorithm::RsaSha2(HashAlg::Sha256)),
+            CERT_RSA_SHA2_512 => Ok(Algorithm::RsaSha2(HashAlg::Sha512)),
+            CERT_SK_ECDSA_SHA2_P256 => Ok(Algorithm::SkEcdsaSha2NistP256),
+            CERT_SK_SSH_ED25519 => Ok(Algorithm::SkEd25519),
             _ => Err(Error::Algorithm),
         }
     }
@@ -78,6 +164,50 @@ impl Algorithm {
             Algorithm::Ecdsa(EcdsaCurve::NistP521) => ECDSA_SHA2_P521,
             Algorithm::Ed25519 => SSH_ED25519,
             Algorithm::Rsa => SSH_RSA,
+            Algorithm::RsaSha2(HashAlg::Sha256) => RSA_SHA2_256,
+            Algorithm::RsaSha2(HashAlg::Sha512) => RSA_SHA2_512,
+            Algorithm::SkEcdsaSha2NistP256 => SK_ECDSA_SHA2_P256,
+            Algorithm::SkEd25519 => SK_SSH_ED25519,
+        }
+    }
+
+    /// Get the OpenSSH certificate type identifier which corresponds to this
+    /// algorithm, e.g. `ssh-ed25519-cert-v01@openssh.com`.
+    pub fn as_certificate_str(self) -> &'static str {
+        match self {
+            Algorithm::Dsa => CERT_DSA,
+            Algorithm::Ecdsa(EcdsaCurve::NistP256) => CERT_ECDSA_SHA2_P256,
+            Algorithm::Ecdsa(EcdsaCurve::NistP384) => CERT_ECDSA_SHA2_P384,
+            Algorithm::Ecdsa(EcdsaCurve::NistP521) => CERT_ECDSA_SHA2_P521,
+            Algorithm::Ed25519 => CERT_ED25519,
+            Algorithm::Rsa => CERT_RSA,
+            Algorithm::RsaSha2(HashAlg::Sha256) => CERT_RSA_SHA2_256,
+            Algorithm::RsaSha2(HashAlg::Sha512) => CERT_RSA_SHA2_512,
+            Algorithm::SkEcdsaSha2NistP256 => CERT_SK_ECDSA_SHA2_P256,
+            Algorithm::SkEd25519 => CERT_SK_SSH_ED25519,
+        }
+    }
+
+    /// Get the key algorithm used with this (signature) algorithm.
+    ///
+    /// This is the same algorithm except in the case of the RFC8332
+    /// `rsa-sha2-*` signature algorithms, which use [`Algorithm::Rsa`] keys.
+    pub fn key_algorithm(self) -> Algorithm {
+        match self {
+            Algorithm::RsaSha2(_) => Algorithm::Rsa,
+            alg => alg,
+        }
+    }
+
+    /// Get the hash function used by the RFC8332 `rsa-sha2-*` signature
+    /// algorithms.
+    ///
+    /// Returns [`None`] for all other algorithms, including `ssh-rsa`
+    /// (i.e. [`Algorithm::Rsa`]) which signs using SHA-1.
+    pub fn rsa_hash_alg(self) -> Option<HashAlg> {
+        match self {
+            Algorithm::RsaSha2(hash_alg) => Some(hash_alg),
+            _ => None,
         }
     }
 
@@ -97,8 +227,15 @@ impl Algorithm {
     }
 
     /// Is the algorithm RSA?
+    ///
+    /// This includes the RFC8332 `rsa-sha2-*` signature algorithms.
     pub fn is_rsa(self) -> bool {
-        self == Algorithm::Rsa
+        matches!(self, Algorithm::Rsa | Algorithm::RsaSha2(_))
+    }
+
+    /// Is the algorithm a FIDO/U2F security key algorithm?
+    pub fn is_sk(self) -> bool {
+        matches!(self, Algorithm::SkEcdsaSha2NistP256 | Algorithm::SkEd25519)
     }
 }
 
@@ -143,7 +280,11 @@ pub enum CipherAlg {
 
 impl CipherAlg {
     /// Maximum size of cipher algorithms known to this crate in bytes.
-    const MAX_SIZE: usize = 4;
+    ///
+    /// This is large enough to hold the names of the ciphers OpenSSH uses to
+    /// encrypt private keys (e.g. `chacha20-poly1305@openssh.com`), so
+    /// encrypted keys can be told apart from malformed ones.
+    pub(crate) const MAX_SIZE: usize = 32;
 
     /// Decode cipher algorithm from the given `ciphername`.
     ///
@@ -165,9 +306,26 @@ impl CipherAlg {
 }
 
 impl Decode for CipherAlg {
+    /// Decode a `ciphername`.
+    ///
+    /// Returns [`Error::Encrypted`] for any cipher other than `none`.
     fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
         let mut buf = [0u8; Self::MAX_SIZE];
-        Self::new(decoder.decode_str(&mut buf)?)
+
+        match decoder.decode_str(&mut buf)? {
+            "none" => Ok(CipherAlg::None),
+            _ => Err(Error::Encrypted),
+        }
+    }
+}
+
+impl Encode for CipherAlg {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + self.as_str().len())
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        encoder.encode_str(self.as_str())
     }
 }
 
@@ -259,25 +417,101 @@ impl str::FromStr for EcdsaCurve {
     }
 }
 
+/// Hash functions used with RSA signatures as described in RFC8332, and
+/// with `SSHSIG` signatures.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum HashAlg {
+    /// SHA-256
+    Sha256,
+
+    /// SHA-512
+    Sha512,
+}
+
+impl HashAlg {
+    /// Maximum size of a hash function identifier known to this crate in
+    /// bytes.
+    const MAX_SIZE: usize = 6;
+
+    /// Decode hash function from the given string identifier.
+    ///
+    /// # Supported hash functions
+    ///
+    /// - `sha256`
+    /// - `sha512`
+    pub fn new(id: &str) -> Result<Self> {
+        match id {
+            "sha256" => Ok(HashAlg::Sha256),
+            "sha512" => Ok(HashAlg::Sha512),
+            _ => Err(Error::Algorithm),
+        }
+    }
+
+    /// Get the string identifier for this hash function.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HashAlg::Sha256 => "sha256",
+            HashAlg::Sha512 => "sha512",
+        }
+    }
+}
+
+impl Decode for HashAlg {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let mut buf = [0u8; Self::MAX_SIZE];
+        Self::new(decoder.decode_str(&mut buf)?)
+    }
+}
+
+impl Encode for HashAlg {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + self.as_str().len())
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        encoder.encode_str(self.as_str())
+    }
+}
+
+impl fmt::Display for HashAlg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl str::FromStr for HashAlg {
+    type Err = Error;
+
+    fn from_str(id: &str) -> Result<Self> {
+        HashAlg::new(id)
+    }
+}
+
 /// Key Derivation Function (KDF) algorithms.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum KdfAlg {
     /// None.
     None,
+
+    /// bcrypt-pbkdf.
+    Bcrypt,
 }
 
 impl KdfAlg {
     /// Maximum size of KDF algorithms known to this crate in bytes.
-    const MAX_SIZE: usize = 4;
+    const MAX_SIZE: usize = 6;
 
     /// Decode KDF algorithm from the given `kdfname`.
     ///
     /// # Supported kdfnames
     /// - `none`
+    /// - `bcrypt`
     pub fn new(kdfname: &str) -> Result<Self> {
         match kdfname {
             "none" => Ok(KdfAlg::None),
+            "bcrypt" => Ok(KdfAlg::Bcrypt),
             _ => Err(Error::Algorithm),
         }
     }
@@ -286,6 +520,7 @@ impl KdfAlg {
     pub fn as_str(self) -> &'static str {
         match self {
             KdfAlg::None => "none",
+            KdfAlg::Bcrypt => "bcrypt",
         }
     }
 }
@@ -297,6 +532,16 @@ impl Decode for KdfAlg {
     }
 }
 
+impl Encode for KdfAlg {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + self.as_str().len())
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        encoder.encode_str(self.as_str())
+    }
+}
+
 impl fmt::Display for KdfAlg {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self.as_str())
@@ -312,26 +557,326 @@ impl str::FromStr for KdfAlg {
 }
 
 /// Key Derivation Function (KDF) options.
-// TODO(tarcieri): stub!
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
-pub struct KdfOptions {}
+pub enum KdfOptions {
+    /// No KDF options (used with [`KdfAlg::None`]).
+    None,
+
+    /// bcrypt-pbkdf parameters (used with [`KdfAlg::Bcrypt`]).
+    Bcrypt(BcryptParams),
+}
 
 impl KdfOptions {
-    /// Create new KDF options.
-    pub(crate) fn new(kdfoptions: &str) -> Result<Self> {
-        // TODO(tarcieri): support for KDF options
+    /// Get the [`KdfAlg`] these options are used with.
+    pub fn kdf_alg(&self) -> KdfAlg {
+        match self {
+            Self::None => KdfAlg::None,
+            Self::Bcrypt(_) => KdfAlg::Bcrypt,
+        }
+    }
+
+    /// Get the bcrypt-pbkdf parameters if these are bcrypt KDF options.
+    pub fn bcrypt(&self) -> Option<&BcryptParams> {
+        match self {
+            Self::Bcrypt(params) => Some(params),
+            _ => None,
+        }
+    }
+
+    /// Is this [`KdfOptions::None`]?
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+
+    /// Is this [`KdfOptions::Bcrypt`]?
+    pub fn is_bcrypt(&self) -> bool {
+        matches!(self, Self::Bcrypt(_))
+    }
+
+    /// Length of the inner `kdfoptions` string.
+    fn inner_len(&self) -> Result<usize> {
+        match self {
+            Self::None => Ok(0),
+            Self::Bcrypt(params) => params.encoded_len(),
+        }
+    }
+}
+
+impl Default for KdfOptions {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl Decode for KdfOptions {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let mut buf = [0u8; BcryptParams::MAX_ENCODED_LEN];
+        let mut kdfoptions = decoder.decode_byte_slice(&mut buf)?;
+
+        if kdfoptions.is_empty() {
+            return Ok(Self::None);
+        }
+
+        let params = BcryptParams::decode(&mut kdfoptions)?;
+
         if kdfoptions.is_empty() {
-            Ok(Self {})
+            Ok(Self::Bcrypt(params))
         } else {
-            Err(Error::Algorithm)
+            Err(Error::Length)
         }
     }
 }
 
-impl Decode for KdfOptions {
+impl Encode for KdfOptions {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + self.inner_len()?)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        encoder.encode_usize(self.inner_len()?)?;
+
+        match self {
+            Self::None => Ok(()),
+            Self::Bcrypt(params) => params.encode(encoder),
+        }
+    }
+}
+
+impl From<BcryptParams> for KdfOptions {
+    fn from(params: BcryptParams) -> KdfOptions {
+        KdfOptions::Bcrypt(params)
+    }
+}
+
+/// bcrypt-pbkdf parameters as used by OpenSSH private keys.
+///
+/// Encoded as the following within the `kdfoptions` string:
+///
+/// ```text
+/// string salt
+/// uint32 rounds
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BcryptParams {
+    /// Salt bytes.
+    salt: [u8; Self::MAX_SALT_LEN],
+
+    /// Length of the salt.
+    salt_len: usize,
+
+    /// Number of rounds.
+    rounds: u32,
+}
+
+impl BcryptParams {
+    /// Default salt length in bytes, matching OpenSSH.
+    pub const DEFAULT_SALT_LEN: usize = 16;
+
+    /// Default number of rounds, matching OpenSSH.
+    pub const DEFAULT_ROUNDS: u32 = 16;
+
+    /// Maximum salt length in bytes supported by this crate.
+    pub const MAX_SALT_LEN: usize = 64;
+
+    /// Maximum size of the encoded parameters in bytes.
+    const MAX_ENCODED_LEN: usize = 4 + Self::MAX_SALT_LEN + 4;
+
+    /// Create new bcrypt-pbkdf parameters from the given salt and number of
+    /// rounds.
+    ///
+    /// The salt must be non-empty and at most [`BcryptParams::MAX_SALT_LEN`]
+    /// bytes, and `rounds` must be non-zero.
+    pub fn new(salt: &[u8], rounds: u32) -> Result<Self> {
+        if salt.is_empty() || salt.len() > Self::MAX_SALT_LEN || rounds == 0 {
+            return Err(Error::Length);
+        }
+
+        let mut buf = [0u8; Self::MAX_SALT_LEN];
+        buf[..salt.len()].copy_from_slice(salt);
+
+        Ok(Self {
+            salt: buf,
+            salt_len: salt.len(),
+            rounds,
+        })
+    }
+
+    /// Generate bcrypt-pbkdf parameters using a random salt of `salt_len`
+    /// bytes and the given number of rounds.
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    pub fn generate(
+        mut rng: impl CryptoRng + RngCore,
+        salt_len: usize,
+        rounds: u32,
+    ) -> Result<Self> {
+        let mut salt = [0u8; Self::MAX_SALT_LEN];
+        let salt = salt.get_mut(..salt_len).ok_or(Error::Length)?;
+        rng.fill_bytes(salt);
+        Self::new(salt, rounds)
+    }
+
+    /// Borrow the salt.
+    pub fn salt(&self) -> &[u8] {
+        &self.salt[..self.salt_len]
+    }
+
+    /// Get the number of rounds.
+    pub fn rounds(&self) -> u32 {
+        self.rounds
+    }
+}
+
+impl Decode for BcryptParams {
     fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
-        let mut buf = [0u8; 0];
-        Self::new(decoder.decode_str(&mut buf)?)
+        let mut salt = [0u8; Self::MAX_SALT_LEN];
+        let salt = decoder.decode_byte_slice(&mut salt)?;
+        let rounds = decoder.decode_u32()?;
+        Self::new(salt, rounds)
+    }
+}
+
+impl Encode for BcryptParams {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + self.salt_len + 4)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        encoder.encode_byte_slice(self.salt())?;
+        encoder.encode_u32(self.rounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Algorithm, BcryptParams, EcdsaCurve, HashAlg, KdfAlg, KdfOptions};
+    use crate::{base64::Decode, Error, Result};
+    use hex_literal::hex;
+
+    /// Every algorithm known to this crate.
+    const ALGORITHMS: &[Algorithm] = &[
+        Algorithm::Dsa,
+        Algorithm::Ecdsa(EcdsaCurve::NistP256),
+        Algorithm::Ecdsa(EcdsaCurve::NistP384),
+        Algorithm::Ecdsa(EcdsaCurve::NistP521),
+        Algorithm::Ed25519,
+        Algorithm::Rsa,
+        Algorithm::RsaSha2(HashAlg::Sha256),
+        Algorithm::RsaSha2(HashAlg::Sha512),
+        Algorithm::SkEcdsaSha2NistP256,
+        Algorithm::SkEd25519,
+    ];
+
+    /// bcrypt `kdfoptions` with a 16-byte salt and 16 rounds.
+    const BCRYPT_OPTIONS: &[u8] =
+        &hex!("00000018 00000010 9f5c1bd0a5a9e1501d2a3e7c4b86f0d2 00000010");
+
+    #[test]
+    fn algorithm_string_round_trip() -> Result<()> {
+        for &alg in ALGORITHMS {
+            assert_eq!(alg.as_str().parse::<Algorithm>()?, alg);
+            assert_eq!(Algorithm::new_certificate(alg.as_certificate_str())?, alg);
+            assert!(alg.as_certificate_str().ends_with("-cert-v01@openssh.com"));
+            assert!(alg.as_str().len() <= Algorithm::MAX_SIZE);
+        }
+
+        assert_eq!(
+            "rsa-sha2-512".parse::<Algorithm>()?,
+            Algorithm::RsaSha2(HashAlg::Sha512)
+        );
+        assert_eq!(
+            Algorithm::new_certificate("sk-ssh-ed25519-cert-v01@openssh.com")?,
+            Algorithm::SkEd25519
+        );
+        assert_eq!(
+            "ssh-ed25519-cert-v01@openssh.com".parse::<Algorithm>(),
+            Err(Error::Algorithm)
+        );
+        assert_eq!(
+            Algorithm::new_certificate("ssh-ed25519"),
+            Err(Error::Algorithm)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rsa_signature_algorithms() {
+        for &alg in ALGORITHMS {
+            assert_eq!(alg.is_rsa(), alg.key_algorithm() == Algorithm::Rsa);
+        }
+
+        let rsa_sha2_256 = Algorithm::RsaSha2(HashAlg::Sha256);
+        assert_eq!(rsa_sha2_256.key_algorithm(), Algorithm::Rsa);
+        assert_eq!(rsa_sha2_256.rsa_hash_alg(), Some(HashAlg::Sha256));
+        assert_eq!(Algorithm::Rsa.rsa_hash_alg(), None);
+        assert_eq!(Algorithm::Ed25519.key_algorithm(), Algorithm::Ed25519);
+    }
+
+    #[test]
+    fn decode_bcrypt_options() -> Result<()> {
+        let mut input = BCRYPT_OPTIONS;
+        let options = KdfOptions::decode(&mut input)?;
+        assert!(input.is_empty());
+        assert_eq!(options.kdf_alg(), KdfAlg::Bcrypt);
+
+        let expected = BcryptParams::new(&hex!("9f5c1bd0a5a9e1501d2a3e7c4b86f0d2"), 16)?;
+        assert_eq!(options.bcrypt(), Some(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn decode_none_options() -> Result<()> {
+        let mut input: &[u8] = &hex!("00000000");
+        assert_eq!(KdfOptions::decode(&mut input)?, KdfOptions::None);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_malformed_bcrypt_options() {
+        // Trailing data after `rounds`
+        let mut input: &[u8] = &hex!("0000000a 00000001 aa 00000010 00");
+        assert!(KdfOptions::decode(&mut input).is_err());
+
+        // Zero rounds
+        let mut input: &[u8] = &hex!("00000009 00000001 aa 00000000");
+        assert!(KdfOptions::decode(&mut input).is_err());
+
+        // Empty salt
+        let mut input: &[u8] = &hex!("00000008 00000000 00000010");
+        assert!(KdfOptions::decode(&mut input).is_err());
+    }
+
+    #[test]
+    fn bcrypt_params_new() -> Result<()> {
+        let params = BcryptParams::new(&[0x42; BcryptParams::DEFAULT_SALT_LEN], 24)?;
+        assert_eq!(params.salt(), &[0x42; 16]);
+        assert_eq!(params.rounds(), 24);
+
+        assert!(BcryptParams::new(&[0x42; BcryptParams::MAX_SALT_LEN], 1).is_ok());
+        assert!(BcryptParams::new(&[0x42; BcryptParams::MAX_SALT_LEN + 1], 1).is_err());
+        assert!(BcryptParams::new(&[], 1).is_err());
+        assert!(BcryptParams::new(&[0x42; 16], 0).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_bcrypt_options() -> Result<()> {
+        use crate::base64::Encode;
+        use alloc::vec::Vec;
+
+        let mut input = BCRYPT_OPTIONS;
+        let options = KdfOptions::decode(&mut input)?;
+
+        let mut output = Vec::new();
+        options.encode(&mut output)?;
+        assert_eq!(output.len(), options.encoded_len()?);
+        assert_eq!(output, BCRYPT_OPTIONS);
+
+        let mut output = Vec::new();
+        KdfOptions::None.encode(&mut output)?;
+        assert_eq!(output, hex!("00000000"));
+        Ok(())
     }
 }
@@ -0,0 +1,157 @@
+//! FIDO/U2F security key attestation support.
+//!
+//! When enrolling a security key (`sk-*@openssh.com`) key, `ssh-keygen` can
+//! save the attestation information returned by the authenticator with
+//! `-O write-attestation=path`. This information can be used to check that
+//! the key was generated on a genuine hardware token, as described in
+//! OpenSSH's [PROTOCOL.u2f] specification.
+//!
+//! This module only parses the attestation: verifying the attestation
+//! certificate chain and the enrollment signature is up to the caller.
+//!
+//! [PROTOCOL.u2f]: https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.u2f
+
+use crate::{
+    base64::{Decode, DecoderExt, Encode, EncoderExt},
+    Error, Result,
+};
+use alloc::vec::Vec;
+
+/// Magic string identifying the original attestation format, which lacks
+/// the authenticator data.
+const VERSION_00: &str = "ssh-sk-attest-v00";
+
+/// Magic string identifying the current attestation format.
+const VERSION_01: &str = "ssh-sk-attest-v01";
+
+/// Maximum length of a format version string.
+const MAX_VERSION_LEN: usize = 17;
+
+/// Attestation information for a FIDO/U2F security key, as written by
+/// `ssh-keygen -O write-attestation`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SkAttestation {
+    /// DER-encoded X.509 attestation certificate.
+    certificate: Vec<u8>,
+
+    /// Enrollment signature made by the attestation key.
+    signature: Vec<u8>,
+
+    /// CBOR-encoded authenticator data, absent in the `v00` format.
+    authenticator_data: Option<Vec<u8>>,
+}
+
+impl SkAttestation {
+    /// Create new attestation information.
+    ///
+    /// Attestations without authenticator data are encoded in the original
+    /// `ssh-sk-attest-v00` format.
+    pub fn new(
+        certificate: Vec<u8>,
+        signature: Vec<u8>,
+        authenticator_data: Option<Vec<u8>>,
+    ) -> Self {
+        Self {
+            certificate,
+            signature,
+            authenticator_data,
+        }
+    }
+
+    /// Parse attestation information in either the `ssh-sk-attest-v00` or
+    /// `ssh-sk-attest-v01` format.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self> {
+        let attestation = Self::decode(&mut bytes)?;
+
+        if bytes.is_empty() {
+            Ok(attestation)
+        } else {
+            Err(Error::Length)
+        }
+    }
+
+    /// Serialize this attestation information.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.encode_vec()
+    }
+
+    /// Get the DER-encoded X.509 attestation certificate.
+    pub fn certificate(&self) -> &[u8] {
+        &self.certificate
+    }
+
+    /// Get the enrollment signature made by the attestation key.
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// Get the CBOR-encoded authenticator data, if present.
+    pub fn authenticator_data(&self) -> Option<&[u8]> {
+        self.authenticator_data.as_deref()
+    }
+
+    /// Get the format version string this attestation is encoded with.
+    fn version(&self) -> &'static str {
+        if self.authenticator_data.is_some() {
+            VERSION_01
+        } else {
+            VERSION_00
+        }
+    }
+}
+
+impl Decode for SkAttestation {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let mut buf = [0u8; MAX_VERSION_LEN];
+        let version = decoder.decode_str(&mut buf)?;
+
+        let has_authenticator_data = match version {
+            VERSION_00 => false,
+            VERSION_01 => true,
+            _ => return Err(Error::FormatEncoding),
+        };
+
+        let certificate = decoder.decode_byte_vec()?;
+        let signature = decoder.decode_byte_vec()?;
+        let authenticator_data = if has_authenticator_data {
+            Some(decoder.decode_byte_vec()?)
+        } else {
+            None
+        };
+
+        let _reserved_flags = decoder.decode_u32()?;
+        let _reserved = decoder.decode_byte_vec()?;
+
+        Ok(Self {
+            certificate,
+            signature,
+            authenticator_data,
+        })
+    }
+}
+
+impl Encode for SkAttestation {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + self.version().len()
+            + 4
+            + self.certificate.len()
+            + 4
+            + self.signature.len()
+            + self.authenticator_data.as_ref().map_or(0, |d| 4 + d.len())
+            + 4
+            + 4)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        encoder.encode_str(self.version())?;
+        encoder.encode_byte_slice(&self.certificate)?;
+        encoder.encode_byte_slice(&self.signature)?;
+
+        if let Some(authenticator_data) = &self.authenticator_data {
+            encoder.encode_byte_slice(authenticator_data)?;
+        }
+
+        encoder.encode_u32(0)?; // reserved flags
+        encoder.encode_str("") // reserved
+    }
+}
@@ -1,6 +1,6 @@
 //! Parser for `AuthorizedKeysFile`-formatted data.
 
-use crate::{Error, PublicKey, Result};
+use crate::{public::PublicKeyRef, Error, PublicKey, Result};
 use core::fmt;
 
 #[cfg(feature = "std")]
@@ -54,6 +54,14 @@ impl<'a> AuthorizedKeys<'a> {
         f(AuthorizedKeys::new(&input))
     }
 
+    /// Parse the next entry, borrowing it from the input.
+    ///
+    /// Unlike the [`Iterator`] impl, this doesn't allocate when parsing
+    /// Ed25519 and ECDSA keys.
+    pub fn next_ref(&mut self) -> Option<Result<EntryRef<'a>>> {
+        self.next_line_trimmed().map(TryInto::try_into)
+    }
+
     /// Get the next line, trimming any comments and trailing whitespace.
     ///
     /// Ignores empty lines.
@@ -98,23 +106,51 @@ impl<'a> TryFrom<&'a str> for Entry<'a> {
     type Error = Error;
 
     fn try_from(line: &'a str) -> Result<Self> {
-        // TODO(tarcieri): more liberal whitespace handling?
-        match line.matches(' ').count() {
-            1..=2 => Ok(Self {
-                options: Default::default(),
-                public_key: line.parse()?,
-            }),
-            3 => line
-                .split_once(' ')
-                .map(|(options_str, public_key_str)| {
-                    Ok(Self {
-                        options: options_str.try_into()?,
-                        public_key: public_key_str.parse()?,
-                    })
-                })
-                .ok_or(Error::FormatEncoding)?,
-            _ => Err(Error::FormatEncoding),
+        let (options, public_key) = split_options(line)?;
+
+        Ok(Self {
+            options,
+            public_key: public_key.parse()?,
+        })
+    }
+}
+
+/// Individual entry in an `authorized_keys` file, borrowed from the input.
+///
+/// See [`AuthorizedKeys::next_ref`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntryRef<'a> {
+    /// Options field, if present.
+    pub options: Options<'a>,
+
+    /// Public key
+    pub public_key: PublicKeyRef<'a>,
+}
+
+impl<'a> TryFrom<&'a str> for EntryRef<'a> {
+    type Error = Error;
+
+    fn try_from(line: &'a str) -> Result<Self> {
+        let (options, public_key) = split_options(line)?;
+
+        Ok(Self {
+            options,
+            public_key: PublicKeyRef::from_openssh(public_key)?,
+        })
+    }
+}
+
+/// Split an `authorized_keys` line into its options and the public key.
+fn split_options(line: &str) -> Result<(Options<'_>, &str)> {
+    // TODO(tarcieri): more liberal whitespace handling?
+    match line.matches(' ').count() {
+        1..=2 => Ok((Options::default(), line)),
+        3 => {
+            let (options_str, public_key_str) =
+                line.split_once(' ').ok_or(Error::FormatEncoding)?;
+            Ok((options_str.try_into()?, public_key_str))
         }
+        _ => Err(Error::FormatEncoding),
     }
 }
 
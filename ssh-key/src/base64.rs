@@ -10,9 +10,41 @@ use pem_rfc7468 as pem;
 #[cfg(feature = "alloc")]
 use alloc::{string::String, vec::Vec};
 
-/// Maximum size of a `usize` this library will accept.
+/// Maximum size of a `usize` this library will accept by default.
 const MAX_SIZE: usize = 0xFFFFF;
 
+/// Limits applied when decoding untrusted input.
+///
+/// Lengths in the SSH wire format are 32-bit prefixes chosen by whoever
+/// produced the input. These limits bound how much a forged prefix can make
+/// the decoder read (and allocate) before it notices the input is malformed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DecodeLimits {
+    /// Maximum size of a single length-prefixed element (e.g. a `string` or
+    /// `mpint`) in bytes.
+    pub max_element_len: usize,
+
+    /// Maximum number of bytes which may be read from the input in total.
+    pub max_total_len: usize,
+}
+
+impl DecodeLimits {
+    /// Default maximum size of a single length-prefixed element in bytes:
+    /// `0xFFFFF`, i.e. one byte less than 1 MiB.
+    ///
+    /// Elements of exactly this size are accepted.
+    pub const DEFAULT_MAX_ELEMENT_LEN: usize = MAX_SIZE;
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self {
+            max_element_len: Self::DEFAULT_MAX_ELEMENT_LEN,
+            max_total_len: usize::MAX,
+        }
+    }
+}
+
 /// Decoder trait.
 pub(crate) trait Decode: Sized {
     /// Attempt to decode a value of this type using the provided [`Decoder`].
@@ -20,12 +52,29 @@ pub(crate) trait Decode: Sized {
 }
 
 /// Encoder trait.
-pub(crate) trait Encode: Sized {
+///
+/// Implemented for the SSH wire format types in this crate (e.g. [`MPInt`],
+/// key data, and algorithms). The exact encoded length is available up front
+/// from [`Encode::encoded_len`], so buffers can be allocated (or checked) at
+/// their exact size before encoding, including on "heapless" targets.
+///
+/// [`MPInt`]: crate::MPInt
+pub trait Encode: Sized {
     /// Get the length of this type encoded in bytes, prior to Base64 encoding.
     fn encoded_len(&self) -> Result<usize>;
 
-    /// Attempt to encode a value of this type using the provided [`Encoder`].
+    /// Attempt to encode a value of this type using the provided encoder.
     fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()>;
+
+    /// Encode this value into a newly allocated [`Vec`] of exactly
+    /// [`Encode::encoded_len`] bytes.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn encode_vec(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(self.encoded_len()?);
+        self.encode(&mut buf)?;
+        Ok(buf)
+    }
 }
 
 /// Stateful Base64 decoder.
@@ -46,8 +95,29 @@ pub(crate) trait DecoderExt {
     /// - `Err(Error::Length)` if the exact amount of data couldn't be read
     fn decode_base64<'o>(&mut self, out: &'o mut [u8]) -> Result<&'o [u8]>;
 
+    /// Get the length of the remaining data after Base64 decoding.
+    fn remaining_len(&self) -> usize;
+
+    /// Maximum size of a single length-prefixed element in bytes.
+    fn max_element_len(&self) -> usize {
+        MAX_SIZE
+    }
+
+    /// Skip over the next `len` bytes without allocating.
+    fn skip(&mut self, mut len: usize) -> Result<()> {
+        let mut buf = [0u8; 48];
+
+        while len > 0 {
+            let chunk_len = len.min(buf.len());
+            self.decode_base64(&mut buf[..chunk_len])?;
+            len -= chunk_len;
+        }
+
+        Ok(())
+    }
+
     /// Decodes a single byte.
-    #[cfg(feature = "ecdsa")]
+    #[cfg(any(feature = "alloc", feature = "ecdsa"))]
     fn decode_u8(&mut self) -> Result<u8> {
         let mut buf = [0];
         self.decode_base64(&mut buf)?;
@@ -71,10 +141,12 @@ pub(crate) trait DecoderExt {
     ///
     /// Uses [`Decoder::decode_u32`] and then converts to a `usize`, handling
     /// potential overflow if `usize` is smaller than `u32`.
+    ///
+    /// Values larger than [`DecoderExt::max_element_len`] are rejected.
     fn decode_usize(&mut self) -> Result<usize> {
         let result = usize::try_from(self.decode_u32()?)?;
 
-        if result <= MAX_SIZE {
+        if result <= self.max_element_len() {
             Ok(result)
         } else {
             Err(Error::Length)
@@ -105,6 +177,12 @@ pub(crate) trait DecoderExt {
     #[cfg(feature = "alloc")]
     fn decode_byte_vec(&mut self) -> Result<Vec<u8>> {
         let len = self.decode_usize()?;
+
+        // Don't allocate more than the input could possibly contain
+        if len > self.remaining_len() {
+            return Err(Error::Length);
+        }
+
         let mut result = vec![0u8; len];
         self.decode_base64(&mut result)?;
         Ok(result)
@@ -142,22 +220,107 @@ impl DecoderExt for Decoder<'_> {
     fn decode_base64<'o>(&mut self, out: &'o mut [u8]) -> Result<&'o [u8]> {
         Ok(self.decode(out)?)
     }
+
+    fn remaining_len(&self) -> usize {
+        self.decoded_len()
+    }
 }
 
 impl DecoderExt for pem::Decoder<'_> {
     fn decode_base64<'o>(&mut self, out: &'o mut [u8]) -> Result<&'o [u8]> {
         Ok(self.decode(out)?)
     }
+
+    fn remaining_len(&self) -> usize {
+        self.decoded_len()
+    }
+}
+
+/// Decoder for raw binary data which has already been Base64-decoded.
+impl DecoderExt for &[u8] {
+    fn decode_base64<'o>(&mut self, out: &'o mut [u8]) -> Result<&'o [u8]> {
+        if self.len() < out.len() {
+            return Err(Error::Length);
+        }
+
+        let (bytes, rest) = self.split_at(out.len());
+        out.copy_from_slice(bytes);
+        *self = rest;
+        Ok(out)
+    }
+
+    fn remaining_len(&self) -> usize {
+        self.len()
+    }
+
+    fn skip(&mut self, len: usize) -> Result<()> {
+        *self = self.get(len..).ok_or(Error::Length)?;
+        Ok(())
+    }
+}
+
+/// Decoder which enforces the given [`DecodeLimits`] on an inner decoder.
+#[derive(Clone)]
+pub(crate) struct LimitedDecoder<D> {
+    /// Inner decoder.
+    inner: D,
+
+    /// Maximum size of a single length-prefixed element.
+    max_element_len: usize,
+
+    /// Number of bytes which may still be read.
+    budget: usize,
+}
+
+impl<D: DecoderExt> LimitedDecoder<D> {
+    /// Wrap the given decoder, enforcing the given limits.
+    pub(crate) fn new(inner: D, limits: DecodeLimits) -> Self {
+        Self {
+            inner,
+            max_element_len: limits.max_element_len,
+            budget: limits.max_total_len,
+        }
+    }
+
+    /// Borrow the inner decoder.
+    pub(crate) fn inner(&self) -> &D {
+        &self.inner
+    }
+}
+
+impl<D: DecoderExt> DecoderExt for LimitedDecoder<D> {
+    fn decode_base64<'o>(&mut self, out: &'o mut [u8]) -> Result<&'o [u8]> {
+        self.budget = self.budget.checked_sub(out.len()).ok_or(Error::Length)?;
+        self.inner.decode_base64(out)
+    }
+
+    fn remaining_len(&self) -> usize {
+        self.inner.remaining_len()
+    }
+
+    fn max_element_len(&self) -> usize {
+        self.max_element_len
+    }
 }
 
 /// Encoder extension trait.
-pub(crate) trait EncoderExt {
+///
+/// Implemented for Base64 and PEM encoders, as well as for `&mut [u8]` and
+/// `Vec<u8>` (with the `alloc` feature) which receive the raw binary
+/// encoding.
+pub trait EncoderExt {
     /// Encode the given byte slice as Base64.
     ///
     /// This is the base encoding method on which the rest of the trait is
     /// implemented in terms of.
     fn encode_base64(&mut self, bytes: &[u8]) -> Result<()>;
 
+    /// Encodes a single byte.
+    #[cfg(feature = "alloc")]
+    fn encode_u8(&mut self, byte: u8) -> Result<()> {
+        self.encode_base64(&[byte])
+    }
+
     /// Encode a `uint32` as described in [RFC4251 § 5]:
     ///
     /// > Represents a 32-bit unsigned integer.  Stored as four bytes in the
@@ -169,9 +332,20 @@ pub(crate) trait EncoderExt {
         self.encode_base64(&num.to_be_bytes())
     }
 
+    /// Encode a `uint64` as described in [RFC4251 § 5]:
+    ///
+    /// > Represents a 64-bit unsigned integer.  Stored as eight bytes in
+    /// > the order of decreasing significance (network byte order).
+    ///
+    /// [RFC4251 § 5]: https://datatracker.ietf.org/doc/html/rfc4251#section-5
+    #[cfg(feature = "alloc")]
+    fn encode_u64(&mut self, num: u64) -> Result<()> {
+        self.encode_base64(&num.to_be_bytes())
+    }
+
     /// Encode a `usize` as a `uint32` as described in [RFC4251 § 5].
     ///
-    /// Uses [`Encoder::encode_u32`] after converting from a `usize`, handling
+    /// Uses [`EncoderExt::encode_u32`] after converting from a `usize`, handling
     /// potential overflow if `usize` is bigger than `u32`.
     ///
     /// [RFC4251 § 5]: https://datatracker.ietf.org/doc/html/rfc4251#section-5
@@ -183,7 +357,7 @@ pub(crate) trait EncoderExt {
     ///
     /// > A byte represents an arbitrary 8-bit value (octet).  Fixed length
     /// > data is sometimes represented as an array of bytes, written
-    /// > byte[n], where n is the number of bytes in the array.
+    /// > byte\[n\], where n is the number of bytes in the array.
     ///
     /// [RFC4251 § 5]: https://datatracker.ietf.org/doc/html/rfc4251#section-5
     fn encode_byte_slice(&mut self, bytes: &[u8]) -> Result<()> {
@@ -224,3 +398,52 @@ impl EncoderExt for pem::Encoder<'_, '_> {
         Ok(self.encode(bytes)?)
     }
 }
+
+/// Encoder for raw binary data which writes into the front of a slice,
+/// which is advanced past the written bytes.
+impl EncoderExt for &mut [u8] {
+    fn encode_base64(&mut self, bytes: &[u8]) -> Result<()> {
+        if self.len() < bytes.len() {
+            return Err(Error::Length);
+        }
+
+        let (out, rest) = core::mem::take(self).split_at_mut(bytes.len());
+        out.copy_from_slice(bytes);
+        *self = rest;
+        Ok(())
+    }
+}
+
+/// Encoder for raw binary data which will be Base64-encoded separately.
+#[cfg(feature = "alloc")]
+impl EncoderExt for Vec<u8> {
+    fn encode_base64(&mut self, bytes: &[u8]) -> Result<()> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodeLimits, DecoderExt, LimitedDecoder};
+    use crate::{Error, Result};
+
+    #[test]
+    fn default_max_element_len() -> Result<()> {
+        let max_len = DecodeLimits::DEFAULT_MAX_ELEMENT_LEN;
+        assert_eq!(max_len, 1024 * 1024 - 1);
+
+        let at_limit = u32::try_from(max_len)?.to_be_bytes();
+        let over_limit = u32::try_from(max_len + 1)?.to_be_bytes();
+
+        assert_eq!((&mut &at_limit[..]).decode_usize(), Ok(max_len));
+        assert_eq!((&mut &over_limit[..]).decode_usize(), Err(Error::Length));
+
+        let mut decoder = LimitedDecoder::new(&at_limit[..], DecodeLimits::default());
+        assert_eq!(decoder.decode_usize(), Ok(max_len));
+
+        let mut decoder = LimitedDecoder::new(&over_limit[..], DecodeLimits::default());
+        assert_eq!(decoder.decode_usize(), Err(Error::Length));
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+//! Multiple precision integers with fixed-capacity storage
+
+use crate::{
+    base64::{Decode, DecoderExt, Encode, EncoderExt},
+    Error, Result,
+};
+use core::fmt;
+use zeroize::Zeroize;
+
+#[cfg(feature = "alloc")]
+use crate::MPInt;
+
+/// Multiple precision integer, a.k.a. "mpint", stored in a fixed-capacity
+/// buffer.
+///
+/// This is a heapless counterpart to [`MPInt`][`crate::MPInt`] which doesn't
+/// require `alloc`, allowing DSA and RSA key components to be decoded on
+/// embedded targets.
+///
+/// `N` is the maximum length of the serialized integer in bytes, including
+/// the leading zero byte of positive integers whose MSB is set. For example,
+/// the modulus of a 4096-bit RSA key needs `N = 513`.
+#[derive(Clone, Eq, PartialEq)]
+pub struct BoundedMPInt<const N: usize> {
+    /// Big endian-serialized integer value, followed by zeroes.
+    bytes: [u8; N],
+
+    /// Length of the serialized integer.
+    len: usize,
+}
+
+impl<const N: usize> BoundedMPInt<N> {
+    /// Create a new multiple precision integer from the given
+    /// big endian-encoded byte slice.
+    ///
+    /// Same as [`MPInt::from_bytes`][`crate::MPInt::from_bytes`], but returns
+    /// [`Error::Length`] if the integer doesn't fit in `N` bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        validate(bytes)?;
+
+        let mut buf = [0u8; N];
+        buf.get_mut(..bytes.len())
+            .ok_or(Error::Length)?
+            .copy_from_slice(bytes);
+
+        Ok(Self {
+            bytes: buf,
+            len: bytes.len(),
+        })
+    }
+
+    /// Create a new multiple precision integer from the given unsigned
+    /// big endian-encoded integer, stripping any leading zeroes and adding
+    /// a leading zero byte if the MSB is set.
+    ///
+    /// Returns [`Error::Length`] if the normalized integer doesn't fit in
+    /// `N` bytes.
+    pub fn from_positive_bytes(bytes: &[u8]) -> Result<Self> {
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        let bytes = &bytes[start..];
+        let offset = usize::from(bytes.first().map_or(false, |&b| b >= 0x80));
+        let len = offset + bytes.len();
+
+        let mut buf = [0u8; N];
+        buf.get_mut(offset..len)
+            .ok_or(Error::Length)?
+            .copy_from_slice(bytes);
+
+        Ok(Self { bytes: buf, len })
+    }
+
+    /// Get the big integer data encoded as big endian bytes.
+    ///
+    /// This slice will contain a leading zero if the value is positive but the
+    /// MSB is also set.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Get the bytes of a positive integer.
+    ///
+    /// # Returns
+    /// - `Some(bytes)` if the number is positive. The leading zero byte will be stripped.
+    /// - `None` if the value is negative
+    pub fn as_positive_bytes(&self) -> Option<&[u8]> {
+        match self.as_bytes() {
+            [0x00, rest @ ..] => Some(rest),
+            [byte, ..] if *byte < 0x80 => Some(self.as_bytes()),
+            _ => None,
+        }
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for BoundedMPInt<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> Decode for BoundedMPInt<N> {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let mut buf = [0u8; N];
+        let len = decoder.decode_byte_slice(&mut buf)?.len();
+        let result = Self::from_bytes(&buf[..len]);
+        buf.zeroize();
+        result
+    }
+}
+
+impl<const N: usize> Encode for BoundedMPInt<N> {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + self.len)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        encoder.encode_byte_slice(self.as_bytes())
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for BoundedMPInt<N> {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> TryFrom<&MPInt> for BoundedMPInt<N> {
+    type Error = Error;
+
+    fn try_from(mpint: &MPInt) -> Result<Self> {
+        Self::from_bytes(mpint.as_bytes())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<&BoundedMPInt<N>> for MPInt {
+    fn from(mpint: &BoundedMPInt<N>) -> MPInt {
+        MPInt::from_bytes(mpint.as_bytes()).expect("validated mpint")
+    }
+}
+
+impl<const N: usize> Zeroize for BoundedMPInt<N> {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+        self.len.zeroize();
+    }
+}
+
+impl<const N: usize> fmt::Debug for BoundedMPInt<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BoundedMPInt({:X})", self)
+    }
+}
+
+impl<const N: usize> fmt::Display for BoundedMPInt<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:X}", self)
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for BoundedMPInt<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for BoundedMPInt<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that the given bytes are a valid mpint serialization.
+pub(crate) fn validate(bytes: &[u8]) -> Result<()> {
+    match bytes {
+        // Unnecessary leading 0
+        [0x00] => Err(Error::FormatEncoding),
+        // Unnecessary leading 0
+        [0x00, n, ..] if *n < 0x80 => Err(Error::FormatEncoding),
+        // Unnecessary leading 0xFF on a negative number
+        [0xFF, n, ..] if *n >= 0x80 => Err(Error::FormatEncoding),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoundedMPInt;
+    use crate::{base64::Decode, Error, Result};
+    use hex_literal::hex;
+
+    #[test]
+    fn decode_positive() -> Result<()> {
+        let mut bytes = &hex!("00000003 0080ff")[..];
+        let n = BoundedMPInt::<3>::decode(&mut bytes)?;
+        assert_eq!(n.as_bytes(), &hex!("0080ff"));
+        assert_eq!(n.as_positive_bytes(), Some(&hex!("80ff")[..]));
+        assert!(bytes.is_empty());
+
+        #[cfg(feature = "alloc")]
+        {
+            use crate::base64::Encode;
+
+            let mut out = alloc::vec::Vec::new();
+            n.encode(&mut out)?;
+            assert_eq!(out, hex!("00000003 0080ff"));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_over_capacity() {
+        let mut bytes = &hex!("00000003 0080ff")[..];
+        assert_eq!(BoundedMPInt::<2>::decode(&mut bytes), Err(Error::Length));
+        assert_eq!(BoundedMPInt::<1>::from_bytes(&[1, 2]), Err(Error::Length));
+    }
+
+    #[test]
+    fn from_positive_bytes() -> Result<()> {
+        let n = BoundedMPInt::<3>::from_positive_bytes(&hex!("00 00 80 ff"))?;
+        assert_eq!(n.as_bytes(), &hex!("00 80 ff"));
+        assert_eq!(n, BoundedMPInt::from_bytes(n.as_bytes())?);
+
+        let n = BoundedMPInt::<0>::from_positive_bytes(&hex!("00"))?;
+        assert!(n.as_bytes().is_empty());
+
+        assert_eq!(
+            BoundedMPInt::<2>::from_positive_bytes(&hex!("80 ff")),
+            Err(Error::Length)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reject_extra_leading_zeroes() {
+        assert_eq!(
+            BoundedMPInt::<4>::from_bytes(&[0]),
+            Err(Error::FormatEncoding)
+        );
+        assert_eq!(
+            BoundedMPInt::<4>::from_bytes(&[0, 1]),
+            Err(Error::FormatEncoding)
+        );
+    }
+}
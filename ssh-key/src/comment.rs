@@ -0,0 +1,74 @@
+//! Fixed-capacity comment storage for "heapless" targets.
+
+use crate::{base64::DecoderExt, Error, Result};
+use core::{fmt, str};
+use zeroize::Zeroize;
+
+/// Key comment stored in a fixed-capacity buffer.
+///
+/// Used in place of a [`String`][`alloc::string::String`] when the `alloc`
+/// feature is disabled, so comments can still be read on embedded targets.
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub(crate) struct BoundedComment {
+    /// UTF-8 encoded comment, followed by zeroes.
+    bytes: [u8; Self::MAX_LEN],
+
+    /// Length of the comment.
+    len: usize,
+}
+
+impl BoundedComment {
+    /// Maximum length of a comment in bytes.
+    pub(crate) const MAX_LEN: usize = 256;
+
+    /// Store the given comment.
+    ///
+    /// Returns [`Error::Length`] if it's longer than [`Self::MAX_LEN`].
+    pub(crate) fn new(comment: &str) -> Result<Self> {
+        let mut bytes = [0u8; Self::MAX_LEN];
+        bytes
+            .get_mut(..comment.len())
+            .ok_or(Error::Length)?
+            .copy_from_slice(comment.as_bytes());
+
+        Ok(Self {
+            bytes,
+            len: comment.len(),
+        })
+    }
+
+    /// Decode a comment `string`.
+    pub(crate) fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let mut buf = [0u8; Self::MAX_LEN];
+        let result = decoder.decode_str(&mut buf).and_then(Self::new);
+        buf.zeroize();
+        result
+    }
+
+    /// Borrow the comment as a string slice.
+    pub(crate) fn as_str(&self) -> &str {
+        str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Default for BoundedComment {
+    fn default() -> Self {
+        Self {
+            bytes: [0u8; Self::MAX_LEN],
+            len: 0,
+        }
+    }
+}
+
+impl Zeroize for BoundedComment {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+        self.len.zeroize();
+    }
+}
+
+impl fmt::Debug for BoundedComment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
@@ -18,11 +18,27 @@ pub enum Error {
     /// Character encoding-related errors.
     CharacterEncoding,
 
+    /// Cryptographic errors (e.g. MAC verification failure).
+    ///
+    /// Decrypting a key with an incorrect passphrase results in this error.
+    Crypto,
+
+    /// DSA key validation errors.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    Dsa(DsaError),
+
     /// ECDSA key encoding errors.
     #[cfg(feature = "ecdsa")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
     Ecdsa(sec1::Error),
 
+    /// Private key is encrypted with a cipher this crate can't decrypt.
+    ///
+    /// Callers can use this to tell that a passphrase-protected key was
+    /// supplied, as opposed to one which is malformed.
+    Encrypted,
+
     /// Other format encoding errors.
     FormatEncoding,
 
@@ -34,11 +50,34 @@ pub enum Error {
     /// Invalid length.
     Length,
 
+    /// Signature namespace is empty or doesn't match the expected namespace.
+    #[cfg(feature = "sshsig")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sshsig")))]
+    Namespace,
+
     /// Overflow errors.
     Overflow,
 
+    /// Private key file is accessible by users other than its owner.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    Permissions,
+
     /// PEM encoding errors.
-    Pem,
+    Pem(pem_rfc7468::Error),
+
+    /// PKCS#8 errors, including ASN.1 DER and SPKI errors.
+    #[cfg(feature = "pkcs8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+    Pkcs8(pkcs8::Error),
+
+    /// Public key does not match the private key it's stored alongside.
+    PublicKeyMismatch,
+
+    /// Self-test failure: a known-answer test produced an unexpected result.
+    #[cfg(feature = "selftest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "selftest")))]
+    SelfTest,
 }
 
 impl fmt::Display for Error {
@@ -47,14 +86,27 @@ impl fmt::Display for Error {
             Error::Algorithm => f.write_str("unknown or unsupported algorithm"),
             Error::Base64(err) => write!(f, "Base64 encoding error: {}", err),
             Error::CharacterEncoding => f.write_str("character encoding invalid"),
+            Error::Crypto => f.write_str("cryptographic error"),
+            #[cfg(feature = "alloc")]
+            Error::Dsa(err) => write!(f, "DSA key error: {}", err),
             #[cfg(feature = "ecdsa")]
             Error::Ecdsa(err) => write!(f, "ECDSA encoding error: {}", err),
+            Error::Encrypted => f.write_str("private key is encrypted"),
             Error::FormatEncoding => f.write_str("format encoding error"),
             #[cfg(feature = "std")]
             Error::Io(err) => write!(f, "I/O error: {}", std::io::Error::from(*err)),
             Error::Length => f.write_str("length invalid"),
+            #[cfg(feature = "sshsig")]
+            Error::Namespace => f.write_str("signature namespace invalid"),
             Error::Overflow => f.write_str("internal overflow error"),
-            Error::Pem => f.write_str("PEM encoding error"),
+            #[cfg(feature = "std")]
+            Error::Permissions => f.write_str("private key file permissions are too open"),
+            Error::Pem(err) => write!(f, "PEM encoding error: {}", err),
+            #[cfg(feature = "pkcs8")]
+            Error::Pkcs8(err) => write!(f, "PKCS#8 error: {}", err),
+            Error::PublicKeyMismatch => f.write_str("public key does not match private key"),
+            #[cfg(feature = "selftest")]
+            Error::SelfTest => f.write_str("self-test failed"),
         }
     }
 }
@@ -62,6 +114,54 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+/// DSA key validation errors.
+///
+/// These are the result of structural checks on DSA keys as described in
+/// [FIPS 186-4 § 4.1], and do not guarantee a key is well-formed.
+///
+/// [FIPS 186-4 § 4.1]: https://csrc.nist.gov/publications/detail/fips/186/4/final
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DsaError {
+    /// Generator `g` is not in the range `1 < g < p`.
+    Generator,
+
+    /// Prime divisor `q` does not divide `p - 1`.
+    PrimeDivisor,
+
+    /// Prime divisor `q` is even, or its size is not permitted for the size
+    /// of `p`.
+    PrimeDivisorSize,
+
+    /// Prime modulus `p` is even, or is not 1024, 2048, or 3072 bits.
+    PrimeModulusSize,
+
+    /// Private key `x` is not in the range `0 < x < q`.
+    PrivateKey,
+
+    /// Public key `y` is not in the range `1 < y < p`.
+    PublicKey,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for DsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DsaError::Generator => f.write_str("generator out of range"),
+            DsaError::PrimeDivisor => f.write_str("prime divisor does not divide p - 1"),
+            DsaError::PrimeDivisorSize => f.write_str("prime divisor size invalid"),
+            DsaError::PrimeModulusSize => f.write_str("prime modulus size invalid"),
+            DsaError::PrivateKey => f.write_str("private key out of range"),
+            DsaError::PublicKey => f.write_str("public key out of range"),
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "std"))]
+impl std::error::Error for DsaError {}
+
 impl From<base64ct::Error> for Error {
     fn from(err: base64ct::Error) -> Error {
         Error::Base64(err)
@@ -86,6 +186,12 @@ impl From<core::num::TryFromIntError> for Error {
     }
 }
 
+impl From<core::num::ParseIntError> for Error {
+    fn from(_: core::num::ParseIntError) -> Error {
+        Error::FormatEncoding
+    }
+}
+
 impl From<core::str::Utf8Error> for Error {
     fn from(_: core::str::Utf8Error) -> Error {
         Error::CharacterEncoding
@@ -93,8 +199,8 @@ impl From<core::str::Utf8Error> for Error {
 }
 
 impl From<pem_rfc7468::Error> for Error {
-    fn from(_: pem_rfc7468::Error) -> Error {
-        Error::Pem
+    fn from(err: pem_rfc7468::Error) -> Error {
+        Error::Pem(err)
     }
 }
 
@@ -106,6 +212,14 @@ impl From<alloc::string::FromUtf8Error> for Error {
     }
 }
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<DsaError> for Error {
+    fn from(err: DsaError) -> Error {
+        Error::Dsa(err)
+    }
+}
+
 #[cfg(feature = "ecdsa")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
 impl From<sec1::Error> for Error {
@@ -114,6 +228,30 @@ impl From<sec1::Error> for Error {
     }
 }
 
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl From<pkcs8::Error> for Error {
+    fn from(err: pkcs8::Error) -> Error {
+        Error::Pkcs8(err)
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl From<pkcs8::der::Error> for Error {
+    fn from(err: pkcs8::der::Error) -> Error {
+        Error::Pkcs8(err.into())
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl From<pkcs8::spki::Error> for Error {
+    fn from(err: pkcs8::spki::Error) -> Error {
+        Error::Pkcs8(err.into())
+    }
+}
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl From<std::io::Error> for Error {
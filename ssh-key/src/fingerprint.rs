@@ -0,0 +1,131 @@
+//! Public key fingerprints.
+
+use crate::{
+    public::{KeyData, PublicKey},
+    Result,
+};
+use sha2::digest::{self, Digest};
+
+#[cfg(feature = "alloc")]
+use {
+    alloc::string::String,
+    base64ct::{Base64Unpadded, Encoding},
+};
+
+#[cfg(feature = "std")]
+use {sha2::Sha256, std::collections::HashMap};
+
+/// Types which have a public key fingerprint, e.g. for host key pinning.
+///
+/// This allows code to be generic over key-like objects. All fingerprints
+/// are computed over the binary public key blob as displayed by
+/// `ssh-keygen -l`, so e.g. a [`PublicKey`] and its [`KeyData`] have the
+/// same fingerprint.
+pub trait Fingerprint {
+    /// Compute the fingerprint using the digest `D`.
+    fn fingerprint<D: Digest>(&self) -> Result<digest::Output<D>>;
+
+    /// Compute the fingerprint using the digest `D` and encode it as
+    /// unpadded Base64.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn fingerprint_base64<D: Digest>(&self) -> Result<String> {
+        Ok(Base64Unpadded::encode_string(&self.fingerprint::<D>()?))
+    }
+}
+
+impl Fingerprint for KeyData {
+    fn fingerprint<D: Digest>(&self) -> Result<digest::Output<D>> {
+        KeyData::fingerprint::<D>(self)
+    }
+}
+
+impl Fingerprint for PublicKey {
+    fn fingerprint<D: Digest>(&self) -> Result<digest::Output<D>> {
+        PublicKey::fingerprint::<D>(self)
+    }
+}
+
+/// Index of public keys by their SHA-256 fingerprint.
+///
+/// Looking up a key hashes its blob once and then takes constant time
+/// regardless of the number of keys in the index, making this suitable for
+/// finding the key presented during authentication among e.g. the keys of a
+/// large `authorized_keys` file.
+///
+/// Keys are indexed by their [`KeyData`], so inserting the same key with a
+/// different comment replaces the existing entry.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, Default)]
+pub struct FingerprintMap {
+    /// Public keys, keyed by SHA-256 fingerprint.
+    keys: HashMap<[u8; 32], PublicKey>,
+}
+
+#[cfg(feature = "std")]
+impl FingerprintMap {
+    /// Create an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a public key, returning the previous entry for the same key.
+    pub fn insert(&mut self, public_key: PublicKey) -> Result<Option<PublicKey>> {
+        Ok(self.keys.insert(sha256(&public_key)?, public_key))
+    }
+
+    /// Insert all of the public keys from an iterator, e.g. the keys parsed
+    /// from an `authorized_keys` file, stopping at the first error.
+    pub fn try_extend(
+        &mut self,
+        public_keys: impl IntoIterator<Item = Result<PublicKey>>,
+    ) -> Result<()> {
+        for public_key in public_keys {
+            self.insert(public_key?)?;
+        }
+
+        Ok(())
+    }
+
+    /// Look up the entry for the given key.
+    pub fn get(&self, key: &impl Fingerprint) -> Result<Option<&PublicKey>> {
+        Ok(self.get_by_fingerprint(&sha256(key)?))
+    }
+
+    /// Look up an entry by SHA-256 fingerprint.
+    pub fn get_by_fingerprint(&self, fingerprint: &[u8; 32]) -> Option<&PublicKey> {
+        self.keys.get(fingerprint)
+    }
+
+    /// Does the index contain the given key?
+    pub fn contains(&self, key: &impl Fingerprint) -> Result<bool> {
+        Ok(self.get(key)?.is_some())
+    }
+
+    /// Remove the entry for the given key, returning it if present.
+    pub fn remove(&mut self, key: &impl Fingerprint) -> Result<Option<PublicKey>> {
+        Ok(self.keys.remove(&sha256(key)?))
+    }
+
+    /// Get the number of keys in the index.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Is the index empty?
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Iterate over the keys in the index, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &PublicKey> {
+        self.keys.values()
+    }
+}
+
+/// Compute the SHA-256 fingerprint of a key.
+#[cfg(feature = "std")]
+fn sha256(key: &impl Fingerprint) -> Result<[u8; 32]> {
+    Ok(key.fingerprint::<Sha256>()?.into())
+}
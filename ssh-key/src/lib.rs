@@ -114,28 +114,81 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod agent;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod attestation;
 pub mod authorized_keys;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod krl;
 pub mod private;
 pub mod public;
 
+#[cfg(feature = "ppk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ppk")))]
+pub mod ppk;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod rfc4716;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod ssh_config;
+#[cfg(feature = "sshsig")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sshsig")))]
+pub mod sshsig;
+
 mod algorithm;
 mod base64;
+mod bounded_mpint;
+#[cfg(not(feature = "alloc"))]
+mod comment;
 mod error;
 
+#[cfg(feature = "fingerprint")]
+mod fingerprint;
+
 #[cfg(feature = "alloc")]
 mod mpint;
+#[cfg(feature = "selftest")]
+mod selftest;
 
 pub use crate::{
-    algorithm::{Algorithm, CipherAlg, EcdsaCurve, KdfAlg, KdfOptions},
+    algorithm::{Algorithm, BcryptParams, CipherAlg, EcdsaCurve, HashAlg, KdfAlg, KdfOptions},
     authorized_keys::AuthorizedKeys,
+    base64::{DecodeLimits, Encode, EncoderExt},
+    bounded_mpint::BoundedMPInt,
     error::{Error, Result},
     private::PrivateKey,
     public::PublicKey,
 };
+pub use pem_rfc7468::{self as pem, LineEnding};
 
 #[cfg(feature = "alloc")]
-pub use crate::mpint::MPInt;
+pub use crate::{error::DsaError, mpint::MPInt, ssh_config::SshConfig};
+
+#[cfg(feature = "fingerprint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+pub use crate::fingerprint::Fingerprint;
+
+#[cfg(all(feature = "fingerprint", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "fingerprint", feature = "std"))))]
+pub use crate::fingerprint::FingerprintMap;
+
+#[cfg(feature = "selftest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "selftest")))]
+pub use crate::selftest::selftest;
 
 #[cfg(feature = "ecdsa")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
 pub use sec1;
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+pub use pkcs8;
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+pub use rand_core;
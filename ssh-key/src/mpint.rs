@@ -2,11 +2,12 @@
 
 use crate::{
     base64::{Decode, DecoderExt, Encode, EncoderExt},
-    Error, Result,
+    bounded_mpint, Error, Result,
 };
 use alloc::vec::Vec;
 use core::fmt;
-use zeroize::Zeroize;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, Zeroizing};
 
 /// Multiple precision integer, a.k.a. "mpint".
 ///
@@ -50,10 +51,35 @@ impl MPInt {
     ///
     /// Note that this method expects a leading zero on positive integers whose
     /// MSB is set, but does *NOT* expect a 4-byte length prefix.
+    ///
+    /// Returns [`Error::FormatEncoding`] if the value isn't in the canonical
+    /// form required by [RFC4251 § 5], i.e. if it has unnecessary leading
+    /// `0x00` or `0xFF` bytes.
+    ///
+    /// [RFC4251 § 5]: https://datatracker.ietf.org/doc/html/rfc4251#section-5
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
         bytes.try_into()
     }
 
+    /// Create a new multiple precision integer from the given unsigned
+    /// big endian-encoded integer, stripping any leading zeroes and adding
+    /// a leading zero byte if the MSB is set.
+    ///
+    /// Unlike [`MPInt::from_bytes`], this accepts any magnitude (e.g. as
+    /// produced by other encodings or fixed-width integer types) and
+    /// normalizes it into the canonical `mpint` form.
+    pub fn from_positive_bytes(bytes: &[u8]) -> Result<Self> {
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        let bytes = &bytes[start..];
+
+        let mut inner = Vec::with_capacity(bytes.len() + 1);
+        if bytes.first().map_or(false, |&b| b >= 0x80) {
+            inner.push(0);
+        }
+        inner.extend_from_slice(bytes);
+        inner.try_into()
+    }
+
     /// Get the big integer data encoded as big endian bytes.
     ///
     /// This slice will contain a leading zero if the value is positive but the
@@ -111,13 +137,8 @@ impl TryFrom<Vec<u8>> for MPInt {
     type Error = Error;
 
     fn try_from(bytes: Vec<u8>) -> Result<Self> {
-        match bytes.as_slice() {
-            // Unnecessary leading 0
-            [0x00] => Err(Error::FormatEncoding),
-            // Unnecessary leading 0
-            [0x00, n, ..] if *n < 0x80 => Err(Error::FormatEncoding),
-            _ => Ok(Self { inner: bytes }),
-        }
+        bounded_mpint::validate(&bytes)?;
+        Ok(Self { inner: bytes })
     }
 }
 
@@ -127,6 +148,12 @@ impl Zeroize for MPInt {
     }
 }
 
+impl ConstantTimeEq for MPInt {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+}
+
 impl fmt::Debug for MPInt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "MPInt({:X})", self)
@@ -157,6 +184,68 @@ impl fmt::UpperHex for MPInt {
     }
 }
 
+/// Compute `a mod m` for unsigned big endian integers, where `m` is non-zero.
+///
+/// This is binary long division keeping only the remainder, without
+/// data-dependent branches since `a` may be secret (e.g. a private exponent).
+/// The result is left-padded with zeroes to one byte longer than `m`.
+pub(crate) fn rem(a: &[u8], m: &[u8]) -> Zeroizing<Vec<u8>> {
+    debug_assert!(m.iter().any(|&byte| byte != 0));
+
+    // The extra byte of headroom ensures doubling the remainder can't overflow
+    let mut rem = Zeroizing::new(vec![0u8; m.len() + 1]);
+    let mut diff = Zeroizing::new(vec![0u8; m.len() + 1]);
+
+    for i in 0..(a.len() * 8) {
+        // rem = (rem << 1) | bit
+        let mut carry = (a[i / 8] >> (7 - i % 8)) & 1;
+
+        for byte in rem.iter_mut().rev() {
+            let next = *byte >> 7;
+            *byte = (*byte << 1) | carry;
+            carry = next;
+        }
+
+        // diff = rem - m
+        let borrow = sub(&rem, m, &mut diff);
+
+        // rem = if rem >= m { diff } else { rem }
+        let mask = borrow.wrapping_sub(1);
+
+        for (byte, &res) in rem.iter_mut().zip(diff.iter()) {
+            *byte ^= (*byte ^ res) & mask;
+        }
+    }
+
+    rem
+}
+
+/// Is the unsigned big endian integer `a` less than `b`?
+///
+/// Runs in time which depends only on the lengths of the inputs.
+pub(crate) fn lt(a: &[u8], b: &[u8]) -> bool {
+    let mut diff = Zeroizing::new(vec![0u8; a.len().max(b.len())]);
+    sub(a, b, &mut diff) == 1
+}
+
+/// Compute `out = a - b`, returning the final borrow.
+///
+/// Inputs are unsigned big endian integers which are implicitly left-padded
+/// with zeroes to the length of `out`.
+fn sub(a: &[u8], b: &[u8], out: &mut [u8]) -> u8 {
+    let byte = |n: &[u8], i: usize| n.len().checked_sub(i + 1).map_or(0, |j| n[j]);
+    let mut borrow = 0u8;
+
+    for (i, res) in out.iter_mut().rev().enumerate() {
+        let (diff, b1) = byte(a, i).overflowing_sub(byte(b, i));
+        let (diff, b2) = diff.overflowing_sub(borrow);
+        *res = diff;
+        borrow = (b1 | b2) as u8;
+    }
+
+    borrow
+}
+
 #[cfg(test)]
 mod tests {
     use super::MPInt;
@@ -175,6 +264,37 @@ mod tests {
         assert!(MPInt::from_bytes(&hex!("00 01")).is_err());
     }
 
+    #[test]
+    fn reject_extra_leading_ones() {
+        assert!(MPInt::from_bytes(&hex!("ff 80")).is_err());
+        assert!(MPInt::from_bytes(&hex!("ff ff 21")).is_err());
+        assert!(MPInt::from_bytes(&hex!("ff 7f")).is_ok());
+        assert!(MPInt::from_bytes(&hex!("ff")).is_ok());
+    }
+
+    #[test]
+    fn from_positive_bytes() -> crate::Result<()> {
+        assert_eq!(b"", MPInt::from_positive_bytes(b"")?.as_bytes());
+        assert_eq!(b"", MPInt::from_positive_bytes(&hex!("00 00"))?.as_bytes());
+        assert_eq!(
+            &hex!("01"),
+            MPInt::from_positive_bytes(&hex!("00 01"))?.as_bytes()
+        );
+        assert_eq!(
+            &hex!("7f"),
+            MPInt::from_positive_bytes(&hex!("7f"))?.as_bytes()
+        );
+        assert_eq!(
+            &hex!("00 80"),
+            MPInt::from_positive_bytes(&hex!("80"))?.as_bytes()
+        );
+        assert_eq!(
+            &hex!("00 ff 01"),
+            MPInt::from_positive_bytes(&hex!("00 00 ff 01"))?.as_bytes()
+        );
+        Ok(())
+    }
+
     #[test]
     fn decode_9a378f9b2e332a7() {
         assert!(MPInt::from_bytes(&hex!("09 a3 78 f9 b2 e3 32 a7")).is_ok());
@@ -1,11 +1,13 @@
 //! Digital Signature Algorithm (DSA) private keys.
 
 use crate::{
-    base64::{Decode, DecoderExt},
+    base64::{Decode, DecoderExt, Encode, EncoderExt},
+    mpint,
     public::DsaPublicKey,
-    MPInt, Result,
+    DsaError, MPInt, Result,
 };
 use core::fmt;
+use subtle::{Choice, ConstantTimeEq};
 use zeroize::Zeroize;
 
 /// Digital Signature Algorithm (DSA) private key.
@@ -39,6 +41,12 @@ impl AsRef<[u8]> for DsaPrivateKey {
     }
 }
 
+impl From<MPInt> for DsaPrivateKey {
+    fn from(inner: MPInt) -> Self {
+        Self { inner }
+    }
+}
+
 impl Decode for DsaPrivateKey {
     fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
         Ok(Self {
@@ -47,12 +55,48 @@ impl Decode for DsaPrivateKey {
     }
 }
 
+impl Encode for DsaPrivateKey {
+    fn encoded_len(&self) -> Result<usize> {
+        self.inner.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        self.inner.encode(encoder)
+    }
+}
+
 impl Drop for DsaPrivateKey {
     fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for DsaPrivateKey {
+    fn zeroize(&mut self) {
         self.inner.zeroize();
     }
 }
 
+impl ConstantTimeEq for DsaPrivateKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.inner.ct_eq(&other.inner)
+    }
+}
+
+impl Eq for DsaPrivateKey {}
+
+impl PartialEq for DsaPrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl fmt::Debug for DsaPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DsaPrivateKey").finish_non_exhaustive()
+    }
+}
+
 /// Digital Signature Algorithm (DSA) private/public keypair.
 #[derive(Clone)]
 pub struct DsaKeypair {
@@ -63,11 +107,80 @@ pub struct DsaKeypair {
     pub private: DsaPrivateKey,
 }
 
+impl DsaKeypair {
+    /// Permitted sizes of `p` and `q` in bits, as `(L, N)` pairs.
+    ///
+    /// These are the pairs described in FIPS 186-4 § 4.2. OpenSSH only
+    /// generates 1024-bit keys, but other implementations produce the others.
+    const SIZES: &'static [(usize, usize)] = &[(1024, 160), (2048, 224), (2048, 256), (3072, 256)];
+
+    /// Create a new DSA keypair, performing structural checks on the domain
+    /// parameters and keys.
+    ///
+    /// The checks are limited to those which are cheap to perform: the sizes
+    /// of `p` and `q`, that `q` divides `p - 1`, and the ranges of `g`, `y`,
+    /// and `x`.
+    pub fn new(public: DsaPublicKey, private: DsaPrivateKey) -> Result<Self> {
+        let p = public
+            .p
+            .as_positive_bytes()
+            .filter(|p| is_odd(p))
+            .filter(|p| Self::SIZES.iter().any(|&(l, _)| l == bit_len(p)))
+            .ok_or(DsaError::PrimeModulusSize)?;
+
+        let q = public
+            .q
+            .as_positive_bytes()
+            .filter(|q| is_odd(q) && Self::SIZES.contains(&(bit_len(p), bit_len(q))))
+            .ok_or(DsaError::PrimeDivisorSize)?;
+
+        // `p` is odd, so `p - 1` only clears its least significant bit
+        let mut p_minus_1 = p.to_vec();
+        if let Some(byte) = p_minus_1.last_mut() {
+            *byte &= !1;
+        }
+
+        if mpint::rem(&p_minus_1, q).iter().any(|&byte| byte != 0) {
+            return Err(DsaError::PrimeDivisor.into());
+        }
+
+        let in_range = |n: &MPInt, min: &[u8], max: &[u8]| {
+            n.as_positive_bytes()
+                .map_or(false, |n| mpint::lt(min, n) && mpint::lt(n, max))
+        };
+
+        if !in_range(&public.g, &[1], p) {
+            return Err(DsaError::Generator.into());
+        }
+
+        if !in_range(&public.y, &[1], p) {
+            return Err(DsaError::PublicKey.into());
+        }
+
+        if !in_range(&private.inner, &[0], q) {
+            return Err(DsaError::PrivateKey.into());
+        }
+
+        Ok(Self { public, private })
+    }
+}
+
 impl Decode for DsaKeypair {
     fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
         let public = DsaPublicKey::decode(decoder)?;
         let private = DsaPrivateKey::decode(decoder)?;
-        Ok(DsaKeypair { public, private })
+        DsaKeypair::new(public, private)
+    }
+}
+
+impl Encode for DsaKeypair {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.public.encoded_len()? + self.private.encoded_len()?)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        self.public.encode(encoder)?;
+        self.private.encode(encoder)
     }
 }
 
@@ -83,6 +196,26 @@ impl From<&DsaKeypair> for DsaPublicKey {
     }
 }
 
+impl Zeroize for DsaKeypair {
+    fn zeroize(&mut self) {
+        self.private.zeroize();
+    }
+}
+
+impl ConstantTimeEq for DsaKeypair {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Choice::from((self.public == other.public) as u8) & self.private.ct_eq(&other.private)
+    }
+}
+
+impl Eq for DsaKeypair {}
+
+impl PartialEq for DsaKeypair {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
 impl fmt::Debug for DsaKeypair {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("DsaKeypair")
@@ -90,3 +223,16 @@ impl fmt::Debug for DsaKeypair {
             .finish_non_exhaustive()
     }
 }
+
+/// Get the length of an unsigned big endian integer in bits.
+fn bit_len(bytes: &[u8]) -> usize {
+    match bytes.iter().position(|&byte| byte != 0) {
+        Some(i) => (bytes.len() - i) * 8 - bytes[i].leading_zeros() as usize,
+        None => 0,
+    }
+}
+
+/// Is the unsigned big endian integer odd?
+fn is_odd(bytes: &[u8]) -> bool {
+    bytes.last().map_or(false, |&byte| byte & 1 == 1)
+}
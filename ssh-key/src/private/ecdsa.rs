@@ -1,14 +1,49 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA) private keys.
 
 use crate::{
-    base64::{Decode, DecoderExt},
+    base64::{Decode, DecoderExt, Encode, EncoderExt},
     public::EcdsaPublicKey,
     Algorithm, EcdsaCurve, Error, Result,
 };
 use core::fmt;
 use sec1::consts::{U32, U48, U66};
+use subtle::{Choice, ConstantTimeEq};
 use zeroize::Zeroize;
 
+#[cfg(feature = "pkcs8")]
+use {
+    crate::public::ecdsa::{NISTP256_OID, NISTP384_OID, NISTP521_OID},
+    pkcs8::der::Decodable,
+};
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+use {crate::public::ecdsa::curve_oid, pkcs8::der::Encodable, zeroize::Zeroizing};
+
+#[cfg(feature = "pkcs8")]
+pub(super) use crate::public::ecdsa::ALGORITHM_OID;
+
+/// Order of the NIST P-256 elliptic curve group.
+const NISTP256_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x51,
+];
+
+/// Order of the NIST P-384 elliptic curve group.
+const NISTP384_ORDER: [u8; 48] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc7, 0x63, 0x4d, 0x81, 0xf4, 0x37, 0x2d, 0xdf,
+    0x58, 0x1a, 0x0d, 0xb2, 0x48, 0xb0, 0xa7, 0x7a, 0xec, 0xec, 0x19, 0x6a, 0xcc, 0xc5, 0x29, 0x73,
+];
+
+/// Order of the NIST P-521 elliptic curve group.
+const NISTP521_ORDER: [u8; 66] = [
+    0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xfa, 0x51, 0x86, 0x87, 0x83, 0xbf, 0x2f, 0x96, 0x6b, 0x7f, 0xcc, 0x01, 0x48, 0xf7, 0x09,
+    0xa5, 0xd0, 0x3b, 0xb5, 0xc9, 0xb8, 0x89, 0x9c, 0x47, 0xae, 0xbb, 0x6f, 0xb7, 0x1e, 0x91, 0x38,
+    0x64, 0x09,
+];
+
 /// Elliptic Curve Digital Signature Algorithm (ECDSA) private key.
 #[derive(Clone)]
 pub struct EcdsaPrivateKey<const SIZE: usize> {
@@ -22,21 +57,87 @@ impl<const SIZE: usize> EcdsaPrivateKey<SIZE> {
         self.bytes
     }
 
-    /// Decode ECDSA private key using the provided Base64 decoder.
-    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+    /// Decode ECDSA private key using the provided Base64 decoder, checking
+    /// that the scalar is in the range `[1, n-1]` for the given curve order.
+    fn decode(decoder: &mut impl DecoderExt, order: &[u8; SIZE]) -> Result<Self> {
         let len = decoder.decode_usize()?;
+        let mut private_key = Self { bytes: [0u8; SIZE] };
 
         if len == SIZE + 1 {
-            // Strip leading zero
-            // TODO(tarcieri): make sure leading zero was necessary
+            // Strip leading zero, which must have been necessary
             if decoder.decode_u8()? != 0 {
                 return Err(Error::FormatEncoding);
             }
+
+            decoder.decode_base64(&mut private_key.bytes)?;
+
+            if !private_key.needs_leading_zero() {
+                return Err(Error::FormatEncoding);
+            }
+        } else if len <= SIZE {
+            // `mpint` encoding omits leading zeroes of the scalar
+            decoder.decode_base64(&mut private_key.bytes[(SIZE - len)..])?;
+        } else {
+            return Err(Error::Crypto);
         }
 
-        let mut bytes = [0u8; SIZE];
-        decoder.decode_base64(&mut bytes)?;
-        Ok(Self { bytes })
+        if private_key.is_valid_scalar(order).into() {
+            Ok(private_key)
+        } else {
+            Err(Error::Crypto)
+        }
+    }
+
+    /// Is the scalar nonzero and less than the given curve order?
+    fn is_valid_scalar(&self, order: &[u8; SIZE]) -> Choice {
+        // Compute the borrow of `self - order` in constant time
+        let mut borrow = 0u16;
+
+        for (&byte, &order_byte) in self.bytes.iter().zip(order.iter()).rev() {
+            let diff = u16::from(byte)
+                .wrapping_sub(u16::from(order_byte))
+                .wrapping_sub(borrow);
+            borrow = diff >> 15;
+        }
+
+        let is_less = Choice::from(borrow as u8);
+        let is_zero = self.bytes[..].ct_eq(&[0u8; SIZE][..]);
+        is_less & !is_zero
+    }
+
+    /// Get the scalar without leading zeroes, as encoded in an `mpint`.
+    fn mpint_bytes(&self) -> &[u8] {
+        let start = self
+            .bytes
+            .iter()
+            .position(|&byte| byte != 0)
+            .unwrap_or(SIZE);
+
+        &self.bytes[start..]
+    }
+
+    /// Does the encoded scalar need a leading zero to be a positive `mpint`?
+    fn needs_leading_zero(&self) -> bool {
+        self.bytes.first().map_or(false, |&byte| byte >= 0x80)
+    }
+}
+
+impl<const SIZE: usize> Encode for EcdsaPrivateKey<SIZE> {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + usize::from(self.needs_leading_zero()) + self.mpint_bytes().len())
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        let bytes = self.mpint_bytes();
+
+        if self.needs_leading_zero() {
+            encoder.encode_usize(SIZE + 1)?;
+            encoder.encode_base64(&[0])?;
+        } else {
+            encoder.encode_usize(bytes.len())?;
+        }
+
+        encoder.encode_base64(bytes)
     }
 }
 
@@ -46,9 +147,15 @@ impl<const SIZE: usize> AsRef<[u8; SIZE]> for EcdsaPrivateKey<SIZE> {
     }
 }
 
+impl<const SIZE: usize> From<[u8; SIZE]> for EcdsaPrivateKey<SIZE> {
+    fn from(bytes: [u8; SIZE]) -> Self {
+        Self { bytes }
+    }
+}
+
 impl<const SIZE: usize> fmt::Debug for EcdsaPrivateKey<SIZE> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Ed25519PrivateKey").finish_non_exhaustive()
+        f.debug_struct("EcdsaPrivateKey").finish_non_exhaustive()
     }
 }
 
@@ -72,10 +179,30 @@ impl<const SIZE: usize> fmt::UpperHex for EcdsaPrivateKey<SIZE> {
 
 impl<const SIZE: usize> Drop for EcdsaPrivateKey<SIZE> {
     fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<const SIZE: usize> Zeroize for EcdsaPrivateKey<SIZE> {
+    fn zeroize(&mut self) {
         self.bytes.zeroize();
     }
 }
 
+impl<const SIZE: usize> ConstantTimeEq for EcdsaPrivateKey<SIZE> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes[..].ct_eq(&other.bytes[..])
+    }
+}
+
+impl<const SIZE: usize> Eq for EcdsaPrivateKey<SIZE> {}
+
+impl<const SIZE: usize> PartialEq for EcdsaPrivateKey<SIZE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
 /// Elliptic Curve Digital Signature Algorithm (ECDSA) private/public keypair.
 #[derive(Clone, Debug)]
 pub enum EcdsaKeypair {
@@ -145,21 +272,172 @@ impl Decode for EcdsaKeypair {
     fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
         match EcdsaPublicKey::decode(decoder)? {
             EcdsaPublicKey::NistP256(public) => {
-                let private = EcdsaPrivateKey::<32>::decode(decoder)?;
+                let private = EcdsaPrivateKey::decode(decoder, &NISTP256_ORDER)?;
                 Ok(Self::NistP256 { public, private })
             }
             EcdsaPublicKey::NistP384(public) => {
-                let private = EcdsaPrivateKey::<48>::decode(decoder)?;
+                let private = EcdsaPrivateKey::decode(decoder, &NISTP384_ORDER)?;
                 Ok(Self::NistP384 { public, private })
             }
             EcdsaPublicKey::NistP521(public) => {
-                let private = EcdsaPrivateKey::<66>::decode(decoder)?;
+                let private = EcdsaPrivateKey::decode(decoder, &NISTP521_ORDER)?;
                 Ok(Self::NistP521 { public, private })
             }
         }
     }
 }
 
+impl Encode for EcdsaKeypair {
+    fn encoded_len(&self) -> Result<usize> {
+        let public_len = EcdsaPublicKey::from(self).encoded_len()?;
+        let private_len = match self {
+            Self::NistP256 { private, .. } => private.encoded_len()?,
+            Self::NistP384 { private, .. } => private.encoded_len()?,
+            Self::NistP521 { private, .. } => private.encoded_len()?,
+        };
+
+        Ok(public_len + private_len)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        EcdsaPublicKey::from(self).encode(encoder)?;
+        match self {
+            Self::NistP256 { private, .. } => private.encode(encoder),
+            Self::NistP384 { private, .. } => private.encode(encoder),
+            Self::NistP521 { private, .. } => private.encode(encoder),
+        }
+    }
+}
+
+impl Zeroize for EcdsaKeypair {
+    fn zeroize(&mut self) {
+        match self {
+            Self::NistP256 { private, .. } => private.zeroize(),
+            Self::NistP384 { private, .. } => private.zeroize(),
+            Self::NistP521 { private, .. } => private.zeroize(),
+        }
+    }
+}
+
+impl ConstantTimeEq for EcdsaKeypair {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        match (self, other) {
+            (
+                Self::NistP256 { public, private },
+                Self::NistP256 {
+                    public: other_public,
+                    private: other_private,
+                },
+            ) => Choice::from((public == other_public) as u8) & private.ct_eq(other_private),
+            (
+                Self::NistP384 { public, private },
+                Self::NistP384 {
+                    public: other_public,
+                    private: other_private,
+                },
+            ) => Choice::from((public == other_public) as u8) & private.ct_eq(other_private),
+            (
+                Self::NistP521 { public, private },
+                Self::NistP521 {
+                    public: other_public,
+                    private: other_private,
+                },
+            ) => Choice::from((public == other_public) as u8) & private.ct_eq(other_private),
+            _ => Choice::from(0),
+        }
+    }
+}
+
+impl Eq for EcdsaKeypair {}
+
+impl PartialEq for EcdsaKeypair {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+/// Parse an ECDSA keypair from a PKCS#8 private key containing a SEC1
+/// `ECPrivateKey`.
+///
+/// The public key is not computed from the private key, so it must be present
+/// in either the `ECPrivateKey` or the PKCS#8 document.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl TryFrom<pkcs8::PrivateKeyInfo<'_>> for EcdsaKeypair {
+    type Error = pkcs8::Error;
+
+    fn try_from(pkcs8_key: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        pkcs8_key.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+        let ec_key = sec1::EcPrivateKey::from_der(pkcs8_key.private_key)?;
+
+        // The curve may be identified in either (or both) structures
+        let curve_oid = match (
+            pkcs8_key.algorithm.oids()?.1,
+            ec_key.parameters.and_then(|params| params.named_curve()),
+        ) {
+            (Some(oid), None) | (None, Some(oid)) => oid,
+            (Some(oid1), Some(oid2)) if oid1 == oid2 => oid1,
+            _ => return Err(pkcs8::Error::ParametersMalformed),
+        };
+
+        let public = ec_key
+            .public_key
+            .or(pkcs8_key.public_key)
+            .and_then(|bytes| EcdsaPublicKey::from_sec1_bytes(bytes).ok())
+            .ok_or(pkcs8::Error::KeyMalformed)?;
+
+        let private = ec_key.private_key;
+
+        match (curve_oid, public) {
+            (NISTP256_OID, EcdsaPublicKey::NistP256(public)) => Ok(Self::NistP256 {
+                public,
+                private: pkcs8_private_key(private, &NISTP256_ORDER)?,
+            }),
+            (NISTP384_OID, EcdsaPublicKey::NistP384(public)) => Ok(Self::NistP384 {
+                public,
+                private: pkcs8_private_key(private, &NISTP384_ORDER)?,
+            }),
+            (NISTP521_OID, EcdsaPublicKey::NistP521(public)) => Ok(Self::NistP521 {
+                public,
+                private: pkcs8_private_key(private, &NISTP521_ORDER)?,
+            }),
+            (NISTP256_OID | NISTP384_OID | NISTP521_OID, _) => Err(pkcs8::Error::KeyMalformed),
+            (oid, _) => Err(pkcs8::spki::Error::OidUnknown { oid }.into()),
+        }
+    }
+}
+
+/// Serialize an ECDSA keypair as a PKCS#8 private key containing a SEC1
+/// `ECPrivateKey`.
+///
+/// The curve is identified in the PKCS#8 `AlgorithmIdentifier`, and the
+/// public key is included in the `ECPrivateKey`.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl pkcs8::EncodePrivateKey for EcdsaKeypair {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<pkcs8::PrivateKeyDocument> {
+        let curve_oid = curve_oid(self.curve());
+
+        let ec_key = sec1::EcPrivateKey {
+            private_key: self.private_key_bytes(),
+            parameters: None,
+            public_key: Some(self.public_key_bytes()),
+        };
+
+        let private_key = Zeroizing::new(ec_key.to_vec()?);
+
+        pkcs8::PrivateKeyInfo {
+            algorithm: pkcs8::AlgorithmIdentifier {
+                oid: ALGORITHM_OID,
+                parameters: Some((&curve_oid).into()),
+            },
+            private_key: &private_key,
+            public_key: None,
+        }
+        .to_der()
+    }
+}
+
 impl From<EcdsaKeypair> for EcdsaPublicKey {
     fn from(keypair: EcdsaKeypair) -> EcdsaPublicKey {
         EcdsaPublicKey::from(&keypair)
@@ -175,3 +453,21 @@ impl From<&EcdsaKeypair> for EcdsaPublicKey {
         }
     }
 }
+
+/// Parse the fixed-size private scalar of an `ECPrivateKey`, checking that
+/// it is in the range `[1, n-1]` for the given curve order.
+#[cfg(feature = "pkcs8")]
+fn pkcs8_private_key<const SIZE: usize>(
+    bytes: &[u8],
+    order: &[u8; SIZE],
+) -> pkcs8::Result<EcdsaPrivateKey<SIZE>> {
+    let private_key = <[u8; SIZE]>::try_from(bytes)
+        .map(EcdsaPrivateKey::from)
+        .map_err(|_| pkcs8::Error::KeyMalformed)?;
+
+    if private_key.is_valid_scalar(order).into() {
+        Ok(private_key)
+    } else {
+        Err(pkcs8::Error::KeyMalformed)
+    }
+}
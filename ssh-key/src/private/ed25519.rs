@@ -3,13 +3,23 @@
 //! Edwards Digital Signature Algorithm (EdDSA) over Curve25519.
 
 use crate::{
-    base64::{Decode, DecoderExt},
+    base64::{Decode, DecoderExt, Encode, EncoderExt},
     public::Ed25519PublicKey,
     Error, Result,
 };
 use core::fmt;
+use subtle::{Choice, ConstantTimeEq};
 use zeroize::{Zeroize, Zeroizing};
 
+#[cfg(feature = "pkcs8")]
+use pkcs8::der::{asn1::OctetString, Decodable};
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+use pkcs8::der::Encodable;
+
+#[cfg(feature = "pkcs8")]
+pub(super) use crate::public::ed25519::ALGORITHM_OID;
+
 /// Ed25519 private key.
 // TODO(tarcieri): use `ed25519::PrivateKey`? (doesn't exist yet)
 #[derive(Clone)]
@@ -31,6 +41,12 @@ impl AsRef<[u8; Self::BYTE_SIZE]> for Ed25519PrivateKey {
     }
 }
 
+impl From<[u8; Self::BYTE_SIZE]> for Ed25519PrivateKey {
+    fn from(bytes: [u8; Self::BYTE_SIZE]) -> Self {
+        Self(bytes)
+    }
+}
+
 impl fmt::Debug for Ed25519PrivateKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Ed25519PrivateKey").finish_non_exhaustive()
@@ -57,10 +73,30 @@ impl fmt::UpperHex for Ed25519PrivateKey {
 
 impl Drop for Ed25519PrivateKey {
     fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for Ed25519PrivateKey {
+    fn zeroize(&mut self) {
         self.0.zeroize();
     }
 }
 
+impl ConstantTimeEq for Ed25519PrivateKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
+impl Eq for Ed25519PrivateKey {}
+
+impl PartialEq for Ed25519PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
 /// Ed25519 private/public keypair.
 #[derive(Clone)]
 pub struct Ed25519Keypair {
@@ -109,6 +145,17 @@ impl Decode for Ed25519Keypair {
     }
 }
 
+impl Encode for Ed25519Keypair {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.public.encoded_len()? + 4 + Self::BYTE_SIZE)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        self.public.encode(encoder)?;
+        encoder.encode_byte_slice(&*Zeroizing::new(self.to_bytes()))
+    }
+}
+
 impl From<Ed25519Keypair> for Ed25519PublicKey {
     fn from(keypair: Ed25519Keypair) -> Ed25519PublicKey {
         keypair.public
@@ -121,6 +168,85 @@ impl From<&Ed25519Keypair> for Ed25519PublicKey {
     }
 }
 
+/// Parse an Ed25519 keypair from a PKCS#8 private key as described in [RFC8410].
+///
+/// The public key is not computed from the private key, so it must be present
+/// in the PKCS#8 document (i.e. a v2 "OneAsymmetricKey" as defined in RFC5958).
+///
+/// [RFC8410]: https://datatracker.ietf.org/doc/html/rfc8410
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl TryFrom<pkcs8::PrivateKeyInfo<'_>> for Ed25519Keypair {
+    type Error = pkcs8::Error;
+
+    fn try_from(pkcs8_key: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        pkcs8_key.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+
+        if pkcs8_key.algorithm.parameters.is_some() {
+            return Err(pkcs8::Error::ParametersMalformed);
+        }
+
+        // `CurvePrivateKey ::= OCTET STRING`, nested inside `privateKey`
+        let private = OctetString::from_der(pkcs8_key.private_key)?
+            .as_bytes()
+            .try_into()
+            .map(Ed25519PrivateKey)
+            .map_err(|_| pkcs8::Error::KeyMalformed)?;
+
+        let public = pkcs8_key
+            .public_key
+            .ok_or(pkcs8::Error::KeyMalformed)?
+            .try_into()
+            .map(Ed25519PublicKey)
+            .map_err(|_| pkcs8::Error::KeyMalformed)?;
+
+        Ok(Self { public, private })
+    }
+}
+
+/// Serialize an Ed25519 keypair as a PKCS#8 v2 private key as described in
+/// [RFC8410], including the public key.
+///
+/// [RFC8410]: https://datatracker.ietf.org/doc/html/rfc8410
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl pkcs8::EncodePrivateKey for Ed25519Keypair {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<pkcs8::PrivateKeyDocument> {
+        // `CurvePrivateKey ::= OCTET STRING`, nested inside `privateKey`
+        let private_key = Zeroizing::new(OctetString::new(self.private.as_ref())?.to_vec()?);
+
+        pkcs8::PrivateKeyInfo {
+            algorithm: pkcs8::AlgorithmIdentifier {
+                oid: ALGORITHM_OID,
+                parameters: None,
+            },
+            private_key: &private_key,
+            public_key: Some(self.public.as_ref()),
+        }
+        .to_der()
+    }
+}
+
+impl Zeroize for Ed25519Keypair {
+    fn zeroize(&mut self) {
+        self.private.zeroize();
+    }
+}
+
+impl ConstantTimeEq for Ed25519Keypair {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Choice::from((self.public == other.public) as u8) & self.private.ct_eq(&other.private)
+    }
+}
+
+impl Eq for Ed25519Keypair {}
+
+impl PartialEq for Ed25519Keypair {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
 impl fmt::Debug for Ed25519Keypair {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Ed25519Keypair")
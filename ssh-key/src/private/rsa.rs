@@ -1,13 +1,28 @@
 //! Rivest–Shamir–Adleman (RSA) private keys.
 
 use crate::{
-    base64::{Decode, DecoderExt},
+    base64::{Decode, DecoderExt, Encode, EncoderExt},
     public::RsaPublicKey,
     MPInt, Result,
 };
 use core::fmt;
+use subtle::{Choice, ConstantTimeEq};
 use zeroize::Zeroize;
 
+#[cfg(all(feature = "pkcs1", feature = "pkcs8"))]
+use {
+    crate::mpint,
+    alloc::vec::Vec,
+    pkcs8::der::{
+        asn1::{Any, UIntBytes},
+        Decodable, Encodable,
+    },
+    zeroize::Zeroizing,
+};
+
+#[cfg(all(feature = "pkcs1", feature = "pkcs8"))]
+pub(super) use crate::public::rsa::ALGORITHM_OID;
+
 /// RSA private key.
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 #[derive(Clone)]
@@ -35,8 +50,30 @@ impl Decode for RsaPrivateKey {
     }
 }
 
+impl Encode for RsaPrivateKey {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.d.encoded_len()?
+            + self.iqmp.encoded_len()?
+            + self.p.encoded_len()?
+            + self.q.encoded_len()?)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        self.d.encode(encoder)?;
+        self.iqmp.encode(encoder)?;
+        self.p.encode(encoder)?;
+        self.q.encode(encoder)
+    }
+}
+
 impl Drop for RsaPrivateKey {
     fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for RsaPrivateKey {
+    fn zeroize(&mut self) {
         self.d.zeroize();
         self.iqmp.zeroize();
         self.p.zeroize();
@@ -44,6 +81,29 @@ impl Drop for RsaPrivateKey {
     }
 }
 
+impl ConstantTimeEq for RsaPrivateKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.d.ct_eq(&other.d)
+            & self.iqmp.ct_eq(&other.iqmp)
+            & self.p.ct_eq(&other.p)
+            & self.q.ct_eq(&other.q)
+    }
+}
+
+impl Eq for RsaPrivateKey {}
+
+impl PartialEq for RsaPrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl fmt::Debug for RsaPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaPrivateKey").finish_non_exhaustive()
+    }
+}
+
 /// RSA private/public keypair.
 #[derive(Clone)]
 pub struct RsaKeypair {
@@ -64,6 +124,107 @@ impl Decode for RsaKeypair {
     }
 }
 
+impl Encode for RsaKeypair {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.public.n.encoded_len()?
+            + self.public.e.encoded_len()?
+            + self.private.encoded_len()?)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        // Unlike the public key, the modulus comes first in the private key
+        self.public.n.encode(encoder)?;
+        self.public.e.encode(encoder)?;
+        self.private.encode(encoder)
+    }
+}
+
+/// Parse an RSA keypair from a PKCS#8 private key containing a PKCS#1
+/// `RSAPrivateKey`.
+///
+/// Multi-prime RSA keys are not supported by SSH and are rejected.
+#[cfg(all(feature = "pkcs1", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "pkcs1", feature = "pkcs8"))))]
+impl TryFrom<pkcs8::PrivateKeyInfo<'_>> for RsaKeypair {
+    type Error = pkcs8::Error;
+
+    fn try_from(pkcs8_key: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        pkcs8_key.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+
+        if pkcs8_key.algorithm.parameters_any()? != Any::NULL {
+            return Err(pkcs8::Error::ParametersMalformed);
+        }
+
+        let rsa_key = pkcs1::RsaPrivateKey::from_der(pkcs8_key.private_key)?;
+
+        if rsa_key.version() != pkcs1::Version::TwoPrime {
+            return Err(pkcs8::Error::KeyMalformed);
+        }
+
+        let mpint = |uint: UIntBytes<'_>| {
+            MPInt::from_positive_bytes(uint.as_bytes()).map_err(|_| pkcs8::Error::KeyMalformed)
+        };
+
+        Ok(Self {
+            public: RsaPublicKey {
+                e: mpint(rsa_key.public_exponent)?,
+                n: mpint(rsa_key.modulus)?,
+            },
+            private: RsaPrivateKey {
+                d: mpint(rsa_key.private_exponent)?,
+                iqmp: mpint(rsa_key.coefficient)?,
+                p: mpint(rsa_key.prime1)?,
+                q: mpint(rsa_key.prime2)?,
+            },
+        })
+    }
+}
+
+/// Serialize an RSA keypair as a PKCS#8 private key containing a PKCS#1
+/// `RSAPrivateKey`.
+///
+/// SSH doesn't store the CRT exponents `d mod (p-1)` and `d mod (q-1)`, so
+/// they are computed from the private exponent and prime factors.
+#[cfg(all(feature = "pkcs1", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "pkcs1", feature = "pkcs8"))))]
+impl pkcs8::EncodePrivateKey for RsaKeypair {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<pkcs8::PrivateKeyDocument> {
+        fn positive(mpint: &MPInt) -> pkcs8::Result<&[u8]> {
+            mpint.as_positive_bytes().ok_or(pkcs8::Error::KeyMalformed)
+        }
+
+        let d = positive(&self.private.d)?;
+        let p = positive(&self.private.p)?;
+        let q = positive(&self.private.q)?;
+        let exponent1 = crt_exponent(d, p)?;
+        let exponent2 = crt_exponent(d, q)?;
+
+        let rsa_key = pkcs1::RsaPrivateKey {
+            modulus: UIntBytes::new(positive(&self.public.n)?)?,
+            public_exponent: UIntBytes::new(positive(&self.public.e)?)?,
+            private_exponent: UIntBytes::new(d)?,
+            prime1: UIntBytes::new(p)?,
+            prime2: UIntBytes::new(q)?,
+            exponent1: UIntBytes::new(&exponent1)?,
+            exponent2: UIntBytes::new(&exponent2)?,
+            coefficient: UIntBytes::new(positive(&self.private.iqmp)?)?,
+            other_prime_infos: None,
+        };
+
+        let private_key = Zeroizing::new(rsa_key.to_vec()?);
+
+        pkcs8::PrivateKeyInfo {
+            algorithm: pkcs8::AlgorithmIdentifier {
+                oid: ALGORITHM_OID,
+                parameters: Some(Any::NULL),
+            },
+            private_key: &private_key,
+            public_key: None,
+        }
+        .to_der()
+    }
+}
+
 impl From<RsaKeypair> for RsaPublicKey {
     fn from(keypair: RsaKeypair) -> RsaPublicKey {
         keypair.public
@@ -76,6 +237,26 @@ impl From<&RsaKeypair> for RsaPublicKey {
     }
 }
 
+impl Zeroize for RsaKeypair {
+    fn zeroize(&mut self) {
+        self.private.zeroize();
+    }
+}
+
+impl ConstantTimeEq for RsaKeypair {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Choice::from((self.public == other.public) as u8) & self.private.ct_eq(&other.private)
+    }
+}
+
+impl Eq for RsaKeypair {}
+
+impl PartialEq for RsaKeypair {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
 impl fmt::Debug for RsaKeypair {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("RsaKeypair")
@@ -83,3 +264,21 @@ impl fmt::Debug for RsaKeypair {
             .finish_non_exhaustive()
     }
 }
+
+/// Compute the CRT exponent `d mod (prime - 1)`.
+#[cfg(all(feature = "pkcs1", feature = "pkcs8"))]
+fn crt_exponent(d: &[u8], prime: &[u8]) -> pkcs8::Result<Zeroizing<Vec<u8>>> {
+    // `prime` is odd, so `prime - 1` only clears its least significant bit
+    let mut modulus = Zeroizing::new(prime.to_vec());
+
+    match modulus.last_mut() {
+        Some(byte) if *byte & 1 == 1 => *byte &= !1,
+        _ => return Err(pkcs8::Error::KeyMalformed),
+    }
+
+    if modulus.iter().all(|&byte| byte == 0) {
+        return Err(pkcs8::Error::KeyMalformed);
+    }
+
+    Ok(mpint::rem(d, &modulus))
+}
@@ -0,0 +1,213 @@
+//! Security key (FIDO/U2F) private keys.
+//!
+//! The private key itself never leaves the security key. Instead, OpenSSH
+//! private key files for these keys contain the public key along with a set
+//! of flags and a "key handle" used by the authenticator to locate (or
+//! recover) the private key, as described in OpenSSH's [PROTOCOL.u2f]
+//! specification.
+//!
+//! [PROTOCOL.u2f]: https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.u2f
+
+use crate::{
+    base64::{Decode, DecoderExt, Encode, EncoderExt},
+    public, Result,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
+
+/// Security key (FIDO/U2F) ECDSA/NIST P-256 private key stub.
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "ecdsa"))))]
+#[derive(Clone)]
+pub struct SkEcdsaSha2NistP256 {
+    /// Public key.
+    pub public: public::SkEcdsaSha2NistP256,
+
+    /// Security key flags (e.g. user presence required).
+    pub flags: u8,
+
+    /// Key handle used by the authenticator.
+    pub key_handle: Vec<u8>,
+
+    /// Reserved data.
+    pub reserved: Vec<u8>,
+}
+
+#[cfg(feature = "ecdsa")]
+impl Decode for SkEcdsaSha2NistP256 {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        Ok(Self {
+            public: public::SkEcdsaSha2NistP256::decode(decoder)?,
+            flags: decoder.decode_u8()?,
+            key_handle: decoder.decode_byte_vec()?,
+            reserved: decoder.decode_byte_vec()?,
+        })
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Encode for SkEcdsaSha2NistP256 {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.public.encoded_len()? + 1 + 4 + self.key_handle.len() + 4 + self.reserved.len())
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        self.public.encode(encoder)?;
+        encoder.encode_u8(self.flags)?;
+        encoder.encode_byte_slice(&self.key_handle)?;
+        encoder.encode_byte_slice(&self.reserved)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<SkEcdsaSha2NistP256> for public::SkEcdsaSha2NistP256 {
+    fn from(keypair: SkEcdsaSha2NistP256) -> public::SkEcdsaSha2NistP256 {
+        keypair.public.clone()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<&SkEcdsaSha2NistP256> for public::SkEcdsaSha2NistP256 {
+    fn from(keypair: &SkEcdsaSha2NistP256) -> public::SkEcdsaSha2NistP256 {
+        keypair.public.clone()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl fmt::Debug for SkEcdsaSha2NistP256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SkEcdsaSha2NistP256")
+            .field("public", &self.public)
+            .field("flags", &self.flags)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Drop for SkEcdsaSha2NistP256 {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Zeroize for SkEcdsaSha2NistP256 {
+    fn zeroize(&mut self) {
+        self.key_handle.zeroize();
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl ConstantTimeEq for SkEcdsaSha2NistP256 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let public_eq = self.public == other.public
+            && self.flags == other.flags
+            && self.reserved == other.reserved;
+
+        Choice::from(public_eq as u8) & self.key_handle.ct_eq(&other.key_handle)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Eq for SkEcdsaSha2NistP256 {}
+
+#[cfg(feature = "ecdsa")]
+impl PartialEq for SkEcdsaSha2NistP256 {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+/// Security key (FIDO/U2F) Ed25519 private key stub.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone)]
+pub struct SkEd25519 {
+    /// Public key.
+    pub public: public::SkEd25519,
+
+    /// Security key flags (e.g. user presence required).
+    pub flags: u8,
+
+    /// Key handle used by the authenticator.
+    pub key_handle: Vec<u8>,
+
+    /// Reserved data.
+    pub reserved: Vec<u8>,
+}
+
+impl Decode for SkEd25519 {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        Ok(Self {
+            public: public::SkEd25519::decode(decoder)?,
+            flags: decoder.decode_u8()?,
+            key_handle: decoder.decode_byte_vec()?,
+            reserved: decoder.decode_byte_vec()?,
+        })
+    }
+}
+
+impl Encode for SkEd25519 {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.public.encoded_len()? + 1 + 4 + self.key_handle.len() + 4 + self.reserved.len())
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        self.public.encode(encoder)?;
+        encoder.encode_u8(self.flags)?;
+        encoder.encode_byte_slice(&self.key_handle)?;
+        encoder.encode_byte_slice(&self.reserved)
+    }
+}
+
+impl From<SkEd25519> for public::SkEd25519 {
+    fn from(keypair: SkEd25519) -> public::SkEd25519 {
+        keypair.public.clone()
+    }
+}
+
+impl From<&SkEd25519> for public::SkEd25519 {
+    fn from(keypair: &SkEd25519) -> public::SkEd25519 {
+        keypair.public.clone()
+    }
+}
+
+impl fmt::Debug for SkEd25519 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SkEd25519")
+            .field("public", &self.public)
+            .field("flags", &self.flags)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for SkEd25519 {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for SkEd25519 {
+    fn zeroize(&mut self) {
+        self.key_handle.zeroize();
+    }
+}
+
+impl ConstantTimeEq for SkEd25519 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let public_eq = self.public == other.public
+            && self.flags == other.flags
+            && self.reserved == other.reserved;
+
+        Choice::from(public_eq as u8) & self.key_handle.ct_eq(&other.key_handle)
+    }
+}
+
+impl Eq for SkEd25519 {}
+
+impl PartialEq for SkEd25519 {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
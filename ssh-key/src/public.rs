@@ -5,21 +5,29 @@
 #[cfg(feature = "alloc")]
 mod dsa;
 #[cfg(feature = "ecdsa")]
-mod ecdsa;
-mod ed25519;
+pub(crate) mod ecdsa;
+pub(crate) mod ed25519;
+mod key_data_ref;
 mod openssh;
+pub(crate) mod rsa;
 #[cfg(feature = "alloc")]
-mod rsa;
+mod sk;
 
 #[cfg(feature = "ecdsa")]
 pub use self::ecdsa::EcdsaPublicKey;
-pub use self::ed25519::Ed25519PublicKey;
+#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+pub use self::sk::SkEcdsaSha2NistP256;
 #[cfg(feature = "alloc")]
-pub use self::{dsa::DsaPublicKey, rsa::RsaPublicKey};
+pub use self::{dsa::DsaPublicKey, rsa::RsaPublicKey, sk::SkEd25519};
+pub use self::{
+    ed25519::Ed25519PublicKey,
+    key_data_ref::{KeyDataRef, PublicKeyRef},
+    rsa::BoundedRsaPublicKey,
+};
 
 use crate::{
-    base64::{self, Decode, DecoderExt, Encode, EncoderExt},
-    Algorithm, Error, Result,
+    base64::{self, Decode, DecoderExt, Encode, EncoderExt, LimitedDecoder},
+    Algorithm, DecodeLimits, Error, Result,
 };
 use core::str::FromStr;
 
@@ -27,9 +35,38 @@ use core::str::FromStr;
 use alloc::{
     borrow::ToOwned,
     string::{String, ToString},
+    vec::Vec,
 };
 
+#[cfg(not(feature = "alloc"))]
+use crate::comment::BoundedComment;
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+use serde::{de, ser, Deserialize, Serialize};
+
+#[cfg(feature = "fingerprint")]
+use sha2::digest::{self, Digest};
+
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+use base64ct::{Base64Unpadded, Encoding};
+
+#[cfg(feature = "pkcs8")]
+use pkcs8::spki;
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+use pkcs8::EncodePublicKey;
+
+#[cfg(feature = "sshsig")]
+use crate::sshsig::SshSig;
+
+#[cfg(feature = "std")]
+use std::{fs, io::BufRead, iter, path::Path};
+
 /// SSH public key.
+///
+/// The [`PartialEq`] and [`Ord`] impls compare both the key data and the
+/// comment, so the same key with two different comments compares unequal.
+/// Use [`PublicKey::key_eq`] to check if two public keys are the same key.
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct PublicKey {
     /// Key data.
@@ -39,6 +76,10 @@ pub struct PublicKey {
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     pub comment: String,
+
+    /// Comment on the key, stored in a fixed-capacity buffer.
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) comment: BoundedComment,
 }
 
 impl PublicKey {
@@ -50,11 +91,20 @@ impl PublicKey {
     /// ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAILM+rvN+ot98qgEN796jTiQfZfG1KaT0PtFDJ/XFSqti foo@bar.com
     /// ```
     pub fn from_openssh(input: impl AsRef<[u8]>) -> Result<Self> {
+        Self::from_openssh_with_limits(input, DecodeLimits::default())
+    }
+
+    /// Parse an OpenSSH-formatted public key, enforcing the given
+    /// [`DecodeLimits`] on the decoded data.
+    ///
+    /// Useful when parsing keys from untrusted sources.
+    pub fn from_openssh_with_limits(input: impl AsRef<[u8]>, limits: DecodeLimits) -> Result<Self> {
         let encapsulation = openssh::Encapsulation::decode(input.as_ref())?;
-        let mut decoder = base64::Decoder::new(encapsulation.base64_data)?;
+        let decoder = base64::Decoder::new(encapsulation.base64_data)?;
+        let mut decoder = LimitedDecoder::new(decoder, limits);
         let key_data = KeyData::decode(&mut decoder)?;
 
-        if !decoder.is_finished() {
+        if !decoder.inner().is_finished() {
             return Err(Error::Length);
         }
 
@@ -67,17 +117,40 @@ impl PublicKey {
             key_data,
             #[cfg(feature = "alloc")]
             comment: encapsulation.comment.to_owned(),
+            #[cfg(not(feature = "alloc"))]
+            comment: BoundedComment::new(encapsulation.comment)?,
         })
     }
 
-    /// Encode OpenSSH-formatted (PEM) public key.
-    pub fn encode_openssh<'o>(&self, out: &'o mut [u8]) -> Result<&'o str> {
-        #[cfg(not(feature = "alloc"))]
-        let comment = "";
+    /// Create a new public key with the given comment.
+    ///
+    /// Without the `alloc` feature, returns [`Error::Length`] if the comment
+    /// is longer than 256 bytes.
+    pub fn new(key_data: KeyData, comment: &str) -> Result<Self> {
+        Ok(Self {
+            key_data,
+            #[cfg(feature = "alloc")]
+            comment: comment.to_owned(),
+            #[cfg(not(feature = "alloc"))]
+            comment: BoundedComment::new(comment)?,
+        })
+    }
+
+    /// Get the comment on the key (e.g. email address).
+    ///
+    /// This is available without the `alloc` feature, in which case the
+    /// comment is stored in a fixed-capacity buffer and keys with comments
+    /// longer than 256 bytes are rejected with [`Error::Length`] when parsed.
+    pub fn comment(&self) -> &str {
         #[cfg(feature = "alloc")]
-        let comment = &self.comment;
+        return &self.comment;
+        #[cfg(not(feature = "alloc"))]
+        return self.comment.as_str();
+    }
 
-        openssh::Encapsulation::encode(out, self.algorithm().as_str(), comment, |encoder| {
+    /// Encode OpenSSH-formatted (PEM) public key.
+    pub fn encode_openssh<'o>(&self, out: &'o mut [u8]) -> Result<&'o str> {
+        openssh::Encapsulation::encode(out, self.algorithm().as_str(), self.comment(), |encoder| {
             self.key_data.encode(encoder)
         })
     }
@@ -97,10 +170,110 @@ impl PublicKey {
         Ok(String::from_utf8(buf)?)
     }
 
+    /// Parse OpenSSH-formatted public keys from `reader`, one per line.
+    ///
+    /// Blank lines and lines beginning with `#` are skipped. Every other line
+    /// yields a result, so a malformed key doesn't prevent the rest of the
+    /// input from being parsed. Iteration stops after an I/O error.
+    ///
+    /// To parse `authorized_keys` files, which may also contain options, see
+    /// [`AuthorizedKeys`][`crate::AuthorizedKeys`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_openssh_lines(mut reader: impl BufRead) -> impl Iterator<Item = Result<Self>> {
+        let mut line = String::new();
+        let mut finished = false;
+
+        iter::from_fn(move || {
+            while !finished {
+                line.clear();
+
+                match reader.read_line(&mut line) {
+                    Ok(0) => finished = true,
+                    Ok(_) => match line.trim() {
+                        l if l.is_empty() || l.starts_with('#') => continue,
+                        l => return Some(Self::from_openssh(l)),
+                    },
+                    Err(err) => {
+                        finished = true;
+                        return Some(Err(err.into()));
+                    }
+                }
+            }
+
+            None
+        })
+    }
+
+    /// Read an OpenSSH-formatted public key from a file.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_openssh_file(path: impl AsRef<Path>) -> Result<Self> {
+        let input = fs::read_to_string(path)?;
+        Self::from_openssh(&input)
+    }
+
+    /// Write this public key to a file as an OpenSSH-formatted public key,
+    /// followed by a newline.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_openssh_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut encoded = self.to_openssh()?;
+        encoded.push('\n');
+        Ok(fs::write(path, encoded)?)
+    }
+
     /// Get the digital signature [`Algorithm`] used by this key.
     pub fn algorithm(&self) -> Algorithm {
         self.key_data.algorithm()
     }
+
+    /// Is this the same key as `other`, ignoring any differences in their
+    /// comments?
+    ///
+    /// This is equivalent to comparing their [`KeyData`].
+    pub fn key_eq(&self, other: &PublicKey) -> bool {
+        self.key_data == other.key_data
+    }
+
+    /// Verify an `SSHSIG` detached signature over `msg` made by this key,
+    /// as with `ssh-keygen -Y verify`.
+    ///
+    /// Returns [`Error::Namespace`] if the signature wasn't made for the
+    /// given `namespace`, or [`Error::PublicKeyMismatch`] if it was made by a
+    /// different key. Otherwise `verifier` is called with the signature and
+    /// the [`SshSig::signed_data`] it should be a signature over, and should
+    /// return [`Error::Crypto`] if the signature is invalid.
+    #[cfg(feature = "sshsig")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sshsig")))]
+    pub fn verify_detached_with_namespace(
+        &self,
+        namespace: &str,
+        msg: &[u8],
+        signature: &SshSig,
+        verifier: impl FnOnce(&SshSig, &[u8]) -> Result<()>,
+    ) -> Result<()> {
+        signature.verify(&self.key_data, namespace, msg, verifier)
+    }
+
+    /// Compute the fingerprint of this public key using the digest `D`.
+    ///
+    /// See [`KeyData::fingerprint`].
+    #[cfg(feature = "fingerprint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+    pub fn fingerprint<D: Digest>(&self) -> Result<digest::Output<D>> {
+        self.key_data.fingerprint::<D>()
+    }
+
+    /// Compute the fingerprint of this public key using the digest `D` and
+    /// encode it as unpadded Base64.
+    ///
+    /// See [`KeyData::fingerprint_base64`].
+    #[cfg(all(feature = "alloc", feature = "fingerprint"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "fingerprint"))))]
+    pub fn fingerprint_base64<D: Digest>(&self) -> Result<String> {
+        self.key_data.fingerprint_base64::<D>()
+    }
 }
 
 impl FromStr for PublicKey {
@@ -118,7 +291,70 @@ impl ToString for PublicKey {
     }
 }
 
+/// Serialize a [`PublicKey`] as an OpenSSH-formatted public key string.
+/// Convert a SPKI `SubjectPublicKeyInfo` into a [`PublicKey`] with an empty
+/// comment.
+///
+/// See the `TryFrom` impl on [`KeyData`] for supported algorithms.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl TryFrom<spki::SubjectPublicKeyInfo<'_>> for PublicKey {
+    type Error = spki::Error;
+
+    fn try_from(spki: spki::SubjectPublicKeyInfo<'_>) -> spki::Result<Self> {
+        Ok(Self {
+            key_data: KeyData::try_from(spki)?,
+            comment: Default::default(),
+        })
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl spki::DecodePublicKey for PublicKey {}
+
+/// Serialize a [`PublicKey`] as a SPKI `SubjectPublicKeyInfo`.
+///
+/// The comment is not preserved. See the `EncodePublicKey` impl on
+/// [`KeyData`] for supported algorithms.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl EncodePublicKey for PublicKey {
+    fn to_public_key_der(&self) -> spki::Result<spki::PublicKeyDocument> {
+        self.key_data.to_public_key_der()
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "serde"))))]
+impl Serialize for PublicKey {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        use ser::Error;
+        let openssh = self.to_openssh().map_err(S::Error::custom)?;
+        serializer.serialize_str(&openssh)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "serde"))))]
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        use de::Error;
+        let openssh = String::deserialize(deserializer)?;
+        Self::from_openssh(&openssh).map_err(D::Error::custom)
+    }
+}
+
 /// Public key data.
+///
+/// Unlike [`PublicKey`], this doesn't include a comment, so the [`PartialEq`]
+/// impl can be used to check if two keys are the same key.
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum KeyData {
@@ -139,9 +375,39 @@ pub enum KeyData {
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     Rsa(RsaPublicKey),
+
+    /// Security key (FIDO/U2F) ECDSA/NIST P-256 public key data.
+    #[cfg(all(feature = "alloc", feature = "ecdsa"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "ecdsa"))))]
+    SkEcdsaSha2NistP256(SkEcdsaSha2NistP256),
+
+    /// Security key (FIDO/U2F) Ed25519 public key data.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    SkEd25519(SkEd25519),
 }
 
 impl KeyData {
+    /// Parse a binary SSH public key blob, i.e. the Base64-decoded key data
+    /// of an OpenSSH public key, as exchanged in the SSH protocol (e.g. as a
+    /// host key during key exchange).
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self> {
+        let key_data = Self::decode(&mut bytes)?;
+
+        if bytes.is_empty() {
+            Ok(key_data)
+        } else {
+            Err(Error::Length)
+        }
+    }
+
+    /// Serialize this public key as a binary SSH public key blob.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.encode_vec()
+    }
+
     /// Get the [`Algorithm`] for this public key.
     pub fn algorithm(&self) -> Algorithm {
         match self {
@@ -152,9 +418,36 @@ impl KeyData {
             Self::Ed25519(_) => Algorithm::Ed25519,
             #[cfg(feature = "alloc")]
             Self::Rsa(_) => Algorithm::Rsa,
+            #[cfg(all(feature = "alloc", feature = "ecdsa"))]
+            Self::SkEcdsaSha2NistP256(_) => Algorithm::SkEcdsaSha2NistP256,
+            #[cfg(feature = "alloc")]
+            Self::SkEd25519(_) => Algorithm::SkEd25519,
         }
     }
 
+    /// Compute the fingerprint of this public key using the digest `D`.
+    ///
+    /// Fingerprints are computed over the binary key blob (the Base64 part
+    /// of an OpenSSH public key), as displayed by `ssh-keygen -l`.
+    #[cfg(feature = "fingerprint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+    pub fn fingerprint<D: Digest>(&self) -> Result<digest::Output<D>> {
+        let mut encoder = DigestEncoder(D::new());
+        self.encode(&mut encoder)?;
+        Ok(encoder.0.finalize())
+    }
+
+    /// Compute the fingerprint of this public key using the digest `D` and
+    /// encode it as unpadded Base64.
+    ///
+    /// With SHA-256 this matches the output of `ssh-keygen -l` following the
+    /// `SHA256:` prefix.
+    #[cfg(all(feature = "alloc", feature = "fingerprint"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "fingerprint"))))]
+    pub fn fingerprint_base64<D: Digest>(&self) -> Result<String> {
+        Ok(Base64Unpadded::encode_string(&self.fingerprint::<D>()?))
+    }
+
     /// Get DSA public key if this key is the correct type.
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -194,6 +487,26 @@ impl KeyData {
         }
     }
 
+    /// Get FIDO/U2F ECDSA/NIST P-256 public key if this key is the correct type.
+    #[cfg(all(feature = "alloc", feature = "ecdsa"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "ecdsa"))))]
+    pub fn sk_ecdsa_p256(&self) -> Option<&SkEcdsaSha2NistP256> {
+        match self {
+            Self::SkEcdsaSha2NistP256(sk) => Some(sk),
+            _ => None,
+        }
+    }
+
+    /// Get FIDO/U2F Ed25519 public key if this key is the correct type.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn sk_ed25519(&self) -> Option<&SkEd25519> {
+        match self {
+            Self::SkEd25519(sk) => Some(sk),
+            _ => None,
+        }
+    }
+
     /// Is this key a DSA key?
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -219,6 +532,20 @@ impl KeyData {
     pub fn is_rsa(&self) -> bool {
         matches!(self, Self::Rsa(_))
     }
+
+    /// Is this key a FIDO/U2F ECDSA/NIST P-256 key?
+    #[cfg(all(feature = "alloc", feature = "ecdsa"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "ecdsa"))))]
+    pub fn is_sk_ecdsa_p256(&self) -> bool {
+        matches!(self, Self::SkEcdsaSha2NistP256(_))
+    }
+
+    /// Is this key a FIDO/U2F Ed25519 key?
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn is_sk_ed25519(&self) -> bool {
+        matches!(self, Self::SkEd25519(_))
+    }
 }
 
 impl Decode for KeyData {
@@ -234,6 +561,12 @@ impl Decode for KeyData {
             Algorithm::Ed25519 => Ed25519PublicKey::decode(decoder).map(Self::Ed25519),
             #[cfg(feature = "alloc")]
             Algorithm::Rsa => RsaPublicKey::decode(decoder).map(Self::Rsa),
+            #[cfg(all(feature = "alloc", feature = "ecdsa"))]
+            Algorithm::SkEcdsaSha2NistP256 => {
+                SkEcdsaSha2NistP256::decode(decoder).map(Self::SkEcdsaSha2NistP256)
+            }
+            #[cfg(feature = "alloc")]
+            Algorithm::SkEd25519 => SkEd25519::decode(decoder).map(Self::SkEd25519),
             #[allow(unreachable_patterns)]
             _ => Err(Error::Algorithm),
         }
@@ -251,6 +584,10 @@ impl Encode for KeyData {
             Self::Ed25519(key) => key.encoded_len()?,
             #[cfg(feature = "alloc")]
             Self::Rsa(key) => key.encoded_len()?,
+            #[cfg(all(feature = "alloc", feature = "ecdsa"))]
+            Self::SkEcdsaSha2NistP256(sk) => sk.encoded_len()?,
+            #[cfg(feature = "alloc")]
+            Self::SkEd25519(sk) => sk.encoded_len()?,
         };
 
         Ok(alg_len + key_len)
@@ -266,6 +603,67 @@ impl Encode for KeyData {
             Self::Ed25519(key) => key.encode(encoder),
             #[cfg(feature = "alloc")]
             Self::Rsa(key) => key.encode(encoder),
+            #[cfg(all(feature = "alloc", feature = "ecdsa"))]
+            Self::SkEcdsaSha2NistP256(sk) => sk.encode(encoder),
+            #[cfg(feature = "alloc")]
+            Self::SkEd25519(sk) => sk.encode(encoder),
         }
     }
 }
+
+/// Convert a SPKI `SubjectPublicKeyInfo` into public key data.
+///
+/// Supports Ed25519 and ECDSA keys, as well as RSA keys when the `pkcs1`
+/// feature is enabled.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl TryFrom<spki::SubjectPublicKeyInfo<'_>> for KeyData {
+    type Error = spki::Error;
+
+    fn try_from(spki: spki::SubjectPublicKeyInfo<'_>) -> spki::Result<Self> {
+        match spki.algorithm.oid {
+            #[cfg(feature = "ecdsa")]
+            ecdsa::ALGORITHM_OID => EcdsaPublicKey::try_from(spki).map(Self::Ecdsa),
+            ed25519::ALGORITHM_OID => Ed25519PublicKey::try_from(spki).map(Self::Ed25519),
+            #[cfg(all(feature = "alloc", feature = "pkcs1"))]
+            rsa::ALGORITHM_OID => RsaPublicKey::try_from(spki).map(Self::Rsa),
+            oid => Err(spki::Error::OidUnknown { oid }),
+        }
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl spki::DecodePublicKey for KeyData {}
+
+/// Serialize public key data as a SPKI `SubjectPublicKeyInfo`.
+///
+/// Supports Ed25519 and ECDSA keys, as well as RSA keys when the `pkcs1`
+/// feature is enabled. Other key types return [`spki::Error::KeyMalformed`].
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl EncodePublicKey for KeyData {
+    fn to_public_key_der(&self) -> spki::Result<spki::PublicKeyDocument> {
+        match self {
+            #[cfg(feature = "ecdsa")]
+            Self::Ecdsa(key) => key.to_public_key_der(),
+            Self::Ed25519(key) => key.to_public_key_der(),
+            #[cfg(feature = "pkcs1")]
+            Self::Rsa(key) => key.to_public_key_der(),
+            #[allow(unreachable_patterns)]
+            _ => Err(spki::Error::KeyMalformed),
+        }
+    }
+}
+
+/// Encoder which feeds its input into a [`Digest`].
+#[cfg(feature = "fingerprint")]
+struct DigestEncoder<D>(D);
+
+#[cfg(feature = "fingerprint")]
+impl<D: Digest> EncoderExt for DigestEncoder<D> {
+    fn encode_base64(&mut self, bytes: &[u8]) -> Result<()> {
+        self.0.update(bytes);
+        Ok(())
+    }
+}
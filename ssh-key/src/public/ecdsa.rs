@@ -7,6 +7,30 @@ use crate::{
 use core::fmt;
 use sec1::consts::{U32, U48, U66};
 
+#[cfg(feature = "pkcs8")]
+use pkcs8::{spki, ObjectIdentifier};
+
+/// Algorithm OID for elliptic curve keys (`id-ecPublicKey`), as defined in
+/// [RFC5480].
+///
+/// [RFC5480]: https://datatracker.ietf.org/doc/html/rfc5480
+#[cfg(feature = "pkcs8")]
+pub(crate) const ALGORITHM_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// OID for the NIST P-256 elliptic curve (a.k.a. `secp256r1`).
+#[cfg(feature = "pkcs8")]
+pub(crate) const NISTP256_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+/// OID for the NIST P-384 elliptic curve (a.k.a. `secp384r1`).
+#[cfg(feature = "pkcs8")]
+pub(crate) const NISTP384_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+/// OID for the NIST P-521 elliptic curve (a.k.a. `secp521r1`).
+#[cfg(feature = "pkcs8")]
+pub(crate) const NISTP521_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.35");
+
 /// Elliptic Curve Digital Signature Algorithm (ECDSA) public key.
 ///
 /// Public keys are represented as [`sec1::EncodedPoint`] and require the
@@ -115,6 +139,58 @@ impl Encode for EcdsaPublicKey {
     }
 }
 
+/// Parse an ECDSA public key from a SPKI `SubjectPublicKeyInfo`.
+///
+/// The curve must be identified by the `AlgorithmIdentifier` parameters.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl TryFrom<spki::SubjectPublicKeyInfo<'_>> for EcdsaPublicKey {
+    type Error = spki::Error;
+
+    fn try_from(spki: spki::SubjectPublicKeyInfo<'_>) -> spki::Result<Self> {
+        spki.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+        let curve_oid = spki.algorithm.parameters_oid()?;
+        let public_key = Self::from_sec1_bytes(spki.subject_public_key)
+            .map_err(|_| spki::Error::KeyMalformed)?;
+
+        match (curve_oid, public_key.curve()) {
+            (NISTP256_OID, EcdsaCurve::NistP256)
+            | (NISTP384_OID, EcdsaCurve::NistP384)
+            | (NISTP521_OID, EcdsaCurve::NistP521) => Ok(public_key),
+            (NISTP256_OID | NISTP384_OID | NISTP521_OID, _) => Err(spki::Error::KeyMalformed),
+            (oid, _) => Err(spki::Error::OidUnknown { oid }),
+        }
+    }
+}
+
+/// Serialize an ECDSA public key as a SPKI `SubjectPublicKeyInfo`.
+///
+/// The curve is identified in the `AlgorithmIdentifier` parameters.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl spki::EncodePublicKey for EcdsaPublicKey {
+    fn to_public_key_der(&self) -> spki::Result<spki::PublicKeyDocument> {
+        spki::SubjectPublicKeyInfo {
+            algorithm: spki::AlgorithmIdentifier {
+                oid: ALGORITHM_OID,
+                parameters: Some((&curve_oid(self.curve())).into()),
+            },
+            subject_public_key: self.as_sec1_bytes(),
+        }
+        .try_into()
+    }
+}
+
+/// Get the OID for the given elliptic curve.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+pub(crate) fn curve_oid(curve: EcdsaCurve) -> ObjectIdentifier {
+    match curve {
+        EcdsaCurve::NistP256 => NISTP256_OID,
+        EcdsaCurve::NistP384 => NISTP384_OID,
+        EcdsaCurve::NistP521 => NISTP521_OID,
+    }
+}
+
 impl fmt::Display for EcdsaPublicKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:X}", self)
@@ -8,6 +8,16 @@ use crate::{
 };
 use core::fmt;
 
+#[cfg(feature = "pkcs8")]
+use pkcs8::spki;
+
+/// Algorithm OID for Ed25519 keys, as defined in [RFC8410].
+///
+/// [RFC8410]: https://datatracker.ietf.org/doc/html/rfc8410
+#[cfg(feature = "pkcs8")]
+pub(crate) const ALGORITHM_OID: pkcs8::ObjectIdentifier =
+    pkcs8::ObjectIdentifier::new_unwrap("1.3.101.112");
+
 /// Ed25519 public key.
 // TODO(tarcieri): use `ed25519::PublicKey`? (doesn't exist yet)
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
@@ -47,6 +57,42 @@ impl Encode for Ed25519PublicKey {
     }
 }
 
+/// Parse an Ed25519 public key from a SPKI `SubjectPublicKeyInfo`.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl TryFrom<spki::SubjectPublicKeyInfo<'_>> for Ed25519PublicKey {
+    type Error = spki::Error;
+
+    fn try_from(spki: spki::SubjectPublicKeyInfo<'_>) -> spki::Result<Self> {
+        spki.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+
+        if spki.algorithm.parameters.is_some() {
+            return Err(spki::Error::KeyMalformed);
+        }
+
+        spki.subject_public_key
+            .try_into()
+            .map(Self)
+            .map_err(|_| spki::Error::KeyMalformed)
+    }
+}
+
+/// Serialize an Ed25519 public key as a SPKI `SubjectPublicKeyInfo`.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl spki::EncodePublicKey for Ed25519PublicKey {
+    fn to_public_key_der(&self) -> spki::Result<spki::PublicKeyDocument> {
+        spki::SubjectPublicKeyInfo {
+            algorithm: spki::AlgorithmIdentifier {
+                oid: ALGORITHM_OID,
+                parameters: None,
+            },
+            subject_public_key: self.as_ref(),
+        }
+        .try_into()
+    }
+}
+
 impl fmt::Display for Ed25519PublicKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:X}", self)
@@ -0,0 +1,175 @@
+//! Borrowed public keys.
+
+use super::{openssh::Encapsulation, Ed25519PublicKey, KeyData};
+use crate::{
+    base64::{self, Decode},
+    Algorithm, Error, Result,
+};
+use core::str;
+
+#[cfg(feature = "alloc")]
+use {super::PublicKey, alloc::borrow::ToOwned};
+
+#[cfg(feature = "ecdsa")]
+use super::EcdsaPublicKey;
+
+/// Public key data borrowed from an OpenSSH-formatted public key.
+///
+/// Ed25519 and ECDSA keys are decoded into fixed-size buffers without any
+/// heap allocations. Keys for all other algorithms are kept Base64-encoded
+/// and only decoded on demand by [`KeyDataRef::to_key_data`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum KeyDataRef<'a> {
+    /// Elliptic Curve Digital Signature Algorithm (ECDSA) public key data.
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    Ecdsa(EcdsaPublicKey),
+
+    /// Ed25519 public key data.
+    Ed25519(Ed25519PublicKey),
+
+    /// Base64-encoded public key data for any other algorithm.
+    ///
+    /// Only the algorithm at the beginning of the key data has been checked.
+    Other {
+        /// Public key algorithm.
+        algorithm: Algorithm,
+
+        /// Base64-encoded public key data, including the algorithm.
+        base64_data: &'a str,
+    },
+}
+
+impl<'a> KeyDataRef<'a> {
+    /// Get the [`Algorithm`] for this public key.
+    pub fn algorithm(&self) -> Algorithm {
+        match self {
+            #[cfg(feature = "ecdsa")]
+            Self::Ecdsa(key) => key.algorithm(),
+            Self::Ed25519(_) => Algorithm::Ed25519,
+            Self::Other { algorithm, .. } => *algorithm,
+        }
+    }
+
+    /// Get the ECDSA public key if this key is the correct type.
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    pub fn ecdsa(&self) -> Option<&EcdsaPublicKey> {
+        match self {
+            Self::Ecdsa(key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Get the Ed25519 public key if this key is the correct type.
+    pub fn ed25519(&self) -> Option<&Ed25519PublicKey> {
+        match self {
+            Self::Ed25519(key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Decode the owned [`KeyData`] for this key.
+    ///
+    /// Returns [`Error::Algorithm`] if the algorithm isn't supported with the
+    /// enabled crate features, e.g. RSA keys without `alloc`.
+    pub fn to_key_data(&self) -> Result<KeyData> {
+        match self {
+            #[cfg(feature = "ecdsa")]
+            Self::Ecdsa(key) => Ok(KeyData::Ecdsa(*key)),
+            Self::Ed25519(key) => Ok(KeyData::Ed25519(*key)),
+            Self::Other {
+                algorithm,
+                base64_data,
+            } => {
+                let mut decoder = base64::Decoder::new(base64_data.as_bytes())?;
+                let key_data = KeyData::decode(&mut decoder)?;
+
+                if !decoder.is_finished() {
+                    return Err(Error::Length);
+                }
+
+                if key_data.algorithm() != *algorithm {
+                    return Err(Error::Algorithm);
+                }
+
+                Ok(key_data)
+            }
+        }
+    }
+}
+
+/// SSH public key borrowed from an OpenSSH-formatted public key.
+///
+/// This is a counterpart to [`PublicKey`][`crate::PublicKey`] which borrows
+/// its comment from the input, and which parses Ed25519 and ECDSA keys with
+/// zero heap allocations. It's intended for hot paths like servers which
+/// parse `authorized_keys` on every authentication attempt.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicKeyRef<'a> {
+    /// Key data.
+    pub key_data: KeyDataRef<'a>,
+
+    /// Comment on the key (e.g. email address).
+    pub comment: &'a str,
+}
+
+impl<'a> PublicKeyRef<'a> {
+    /// Parse an OpenSSH-formatted public key.
+    ///
+    /// See [`PublicKey::from_openssh`][`crate::PublicKey::from_openssh`].
+    pub fn from_openssh(input: &'a str) -> Result<Self> {
+        let encapsulation = Encapsulation::decode(input.as_bytes())?;
+        let base64_data = str::from_utf8(encapsulation.base64_data)?;
+        let mut decoder = base64::Decoder::new(encapsulation.base64_data)?;
+        let algorithm = Algorithm::decode(&mut decoder)?;
+
+        // Verify that the algorithm in the Base64-encoded data matches the text
+        if encapsulation.algorithm_id != algorithm.as_str() {
+            return Err(Error::Algorithm);
+        }
+
+        let key_data = match algorithm {
+            #[cfg(feature = "ecdsa")]
+            Algorithm::Ecdsa(curve) => match EcdsaPublicKey::decode(&mut decoder)? {
+                key if key.curve() == curve => KeyDataRef::Ecdsa(key),
+                _ => return Err(Error::Algorithm),
+            },
+            Algorithm::Ed25519 => KeyDataRef::Ed25519(Ed25519PublicKey::decode(&mut decoder)?),
+            _ => {
+                return Ok(Self {
+                    key_data: KeyDataRef::Other {
+                        algorithm,
+                        base64_data,
+                    },
+                    comment: encapsulation.comment,
+                })
+            }
+        };
+
+        if !decoder.is_finished() {
+            return Err(Error::Length);
+        }
+
+        Ok(Self {
+            key_data,
+            comment: encapsulation.comment,
+        })
+    }
+
+    /// Get the [`Algorithm`] for this public key.
+    pub fn algorithm(&self) -> Algorithm {
+        self.key_data.algorithm()
+    }
+
+    /// Decode an owned [`PublicKey`] from this key.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_public_key(&self) -> Result<PublicKey> {
+        Ok(PublicKey {
+            key_data: self.key_data.to_key_data()?,
+            comment: self.comment.to_owned(),
+        })
+    }
+}
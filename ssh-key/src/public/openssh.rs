@@ -27,7 +27,6 @@ pub(crate) struct Encapsulation<'a> {
     pub(super) base64_data: &'a [u8],
 
     /// Comment
-    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
     pub(super) comment: &'a str,
 }
 
@@ -84,7 +83,7 @@ fn decode_segment<'a>(bytes: &mut &'a [u8]) -> Result<&'a [u8]> {
 
     loop {
         match *bytes {
-            [b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'-' | b'/' | b'=', rest @ ..] => {
+            [byte, rest @ ..] if is_segment_char(*byte) => {
                 // Valid character; continue
                 *bytes = rest;
                 len += 1;
@@ -107,6 +106,18 @@ fn decode_segment<'a>(bytes: &mut &'a [u8]) -> Result<&'a [u8]> {
     }
 }
 
+/// Is the given byte valid in a segment of the public key?
+///
+/// Segments contain either an algorithm identifier or Base64 data. `@` and `.`
+/// appear in identifiers of the form `name@domain` (e.g.
+/// `sk-ssh-ed25519@openssh.com`).
+fn is_segment_char(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'-' | b'/' | b'=' | b'@' | b'.'
+    )
+}
+
 /// Parse a segment of the public key as a `&str`.
 fn decode_segment_str<'a>(bytes: &mut &'a [u8]) -> Result<&'a str> {
     str::from_utf8(decode_segment(bytes)?).map_err(|_| Error::CharacterEncoding)
@@ -1,13 +1,37 @@
 //! Rivest–Shamir–Adleman (RSA) public keys.
 
+use super::openssh::Encapsulation;
 use crate::{
-    base64::{Decode, DecoderExt, Encode, EncoderExt},
-    MPInt, Result,
+    base64::{self, Decode, DecoderExt, Encode, EncoderExt},
+    Algorithm, BoundedMPInt, Error, Result,
 };
 
+#[cfg(feature = "alloc")]
+use crate::MPInt;
+
+#[cfg(all(feature = "pkcs1", feature = "pkcs8"))]
+use pkcs8::ObjectIdentifier;
+
+#[cfg(all(feature = "alloc", feature = "pkcs1", feature = "pkcs8"))]
+use pkcs8::{
+    der::{
+        asn1::{Any, UIntBytes},
+        Decodable, Encodable,
+    },
+    spki,
+};
+
+/// Algorithm OID for RSA keys (`rsaEncryption`), as defined in [RFC8017].
+///
+/// [RFC8017]: https://datatracker.ietf.org/doc/html/rfc8017
+#[cfg(all(feature = "pkcs1", feature = "pkcs8"))]
+pub(crate) const ALGORITHM_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
 /// RSA public key.
 ///
 /// Described in [RFC4253 § 6.6](https://datatracker.ietf.org/doc/html/rfc4253#section-6.6):
+#[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct RsaPublicKey {
@@ -18,6 +42,7 @@ pub struct RsaPublicKey {
     pub n: MPInt,
 }
 
+#[cfg(feature = "alloc")]
 impl Decode for RsaPublicKey {
     fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
         let e = MPInt::decode(decoder)?;
@@ -26,6 +51,7 @@ impl Decode for RsaPublicKey {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Encode for RsaPublicKey {
     fn encoded_len(&self) -> Result<usize> {
         Ok(self.e.encoded_len()? + self.n.encoded_len()?)
@@ -36,3 +62,140 @@ impl Encode for RsaPublicKey {
         self.n.encode(encoder)
     }
 }
+
+/// Parse an RSA public key from a SPKI `SubjectPublicKeyInfo` containing a
+/// PKCS#1 `RSAPublicKey`.
+#[cfg(all(feature = "alloc", feature = "pkcs1", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "pkcs1", feature = "pkcs8"))))]
+impl TryFrom<spki::SubjectPublicKeyInfo<'_>> for RsaPublicKey {
+    type Error = spki::Error;
+
+    fn try_from(spki: spki::SubjectPublicKeyInfo<'_>) -> spki::Result<Self> {
+        spki.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+
+        if spki.algorithm.parameters_any()? != Any::NULL {
+            return Err(spki::Error::KeyMalformed);
+        }
+
+        let rsa_key = pkcs1::RsaPublicKey::from_der(spki.subject_public_key)?;
+
+        let mpint = |uint: UIntBytes<'_>| {
+            MPInt::from_positive_bytes(uint.as_bytes()).map_err(|_| spki::Error::KeyMalformed)
+        };
+
+        Ok(Self {
+            e: mpint(rsa_key.public_exponent)?,
+            n: mpint(rsa_key.modulus)?,
+        })
+    }
+}
+
+/// Serialize an RSA public key as a SPKI `SubjectPublicKeyInfo` containing a
+/// PKCS#1 `RSAPublicKey`.
+#[cfg(all(feature = "alloc", feature = "pkcs1", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "pkcs1", feature = "pkcs8"))))]
+impl spki::EncodePublicKey for RsaPublicKey {
+    fn to_public_key_der(&self) -> spki::Result<spki::PublicKeyDocument> {
+        fn positive(mpint: &MPInt) -> spki::Result<&[u8]> {
+            mpint.as_positive_bytes().ok_or(spki::Error::KeyMalformed)
+        }
+
+        let rsa_key = pkcs1::RsaPublicKey {
+            modulus: UIntBytes::new(positive(&self.n)?)?,
+            public_exponent: UIntBytes::new(positive(&self.e)?)?,
+        }
+        .to_vec()?;
+
+        spki::SubjectPublicKeyInfo {
+            algorithm: spki::AlgorithmIdentifier {
+                oid: ALGORITHM_OID,
+                parameters: Some(Any::NULL),
+            },
+            subject_public_key: &rsa_key,
+        }
+        .try_into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<&BoundedRsaPublicKey<N>> for RsaPublicKey {
+    fn from(public_key: &BoundedRsaPublicKey<N>) -> RsaPublicKey {
+        RsaPublicKey {
+            e: MPInt::from(&public_key.e),
+            n: MPInt::from(&public_key.n),
+        }
+    }
+}
+
+/// RSA public key with fixed-capacity storage.
+///
+/// This is a heapless counterpart to [`RsaPublicKey`][`crate::public::RsaPublicKey`]
+/// for use on targets without `alloc`. `N` is the capacity of each of the
+/// exponent and modulus in bytes, e.g. `N = 513` for keys up to 4096 bits.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoundedRsaPublicKey<const N: usize> {
+    /// RSA public exponent.
+    pub e: BoundedMPInt<N>,
+
+    /// RSA modulus.
+    pub n: BoundedMPInt<N>,
+}
+
+impl<const N: usize> BoundedRsaPublicKey<N> {
+    /// Parse an OpenSSH-formatted `ssh-rsa` public key.
+    ///
+    /// Returns [`Error::Algorithm`] if the key isn't an RSA key, and
+    /// [`Error::Length`] if either of its components exceeds `N` bytes.
+    pub fn from_openssh(input: impl AsRef<[u8]>) -> Result<Self> {
+        let encapsulation = Encapsulation::decode(input.as_ref())?;
+
+        if encapsulation.algorithm_id != Algorithm::Rsa.as_str() {
+            return Err(Error::Algorithm);
+        }
+
+        let mut decoder = base64::Decoder::new(encapsulation.base64_data)?;
+
+        if Algorithm::decode(&mut decoder)? != Algorithm::Rsa {
+            return Err(Error::Algorithm);
+        }
+
+        let public_key = Self::decode(&mut decoder)?;
+
+        if !decoder.is_finished() {
+            return Err(Error::Length);
+        }
+
+        Ok(public_key)
+    }
+}
+
+impl<const N: usize> Decode for BoundedRsaPublicKey<N> {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let e = BoundedMPInt::decode(decoder)?;
+        let n = BoundedMPInt::decode(decoder)?;
+        Ok(Self { e, n })
+    }
+}
+
+impl<const N: usize> Encode for BoundedRsaPublicKey<N> {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.e.encoded_len()? + self.n.encoded_len()?)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        self.e.encode(encoder)?;
+        self.n.encode(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> TryFrom<&RsaPublicKey> for BoundedRsaPublicKey<N> {
+    type Error = Error;
+
+    fn try_from(public_key: &RsaPublicKey) -> Result<Self> {
+        Ok(Self {
+            e: BoundedMPInt::try_from(&public_key.e)?,
+            n: BoundedMPInt::try_from(&public_key.n)?,
+        })
+    }
+}
@@ -0,0 +1,107 @@
+//! Security key (FIDO/U2F) public keys.
+//!
+//! Keys stored on hardware security keys, as described in OpenSSH's
+//! [PROTOCOL.u2f] specification.
+//!
+//! [PROTOCOL.u2f]: https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.u2f
+
+use super::Ed25519PublicKey;
+use crate::{
+    base64::{Decode, DecoderExt, Encode, EncoderExt},
+    Result,
+};
+use alloc::string::String;
+
+#[cfg(feature = "ecdsa")]
+use {
+    super::EcdsaPublicKey,
+    crate::{EcdsaCurve, Error},
+    sec1::consts::U32,
+};
+
+/// Security key (FIDO/U2F) ECDSA/NIST P-256 public key.
+///
+/// Encoded as `sk-ecdsa-sha2-nistp256@openssh.com`.
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "ecdsa"))))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct SkEcdsaSha2NistP256 {
+    /// Elliptic curve point representing the public key.
+    pub ec_point: sec1::EncodedPoint<U32>,
+
+    /// FIDO/U2F "application" (e.g. `ssh:`) the key is scoped to.
+    pub application: String,
+}
+
+#[cfg(feature = "ecdsa")]
+impl SkEcdsaSha2NistP256 {
+    /// Get the [`EcdsaCurve`] for this key, which is always NIST P-256.
+    pub fn curve(&self) -> EcdsaCurve {
+        EcdsaCurve::NistP256
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Decode for SkEcdsaSha2NistP256 {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let ec_point = match EcdsaPublicKey::decode(decoder)? {
+            EcdsaPublicKey::NistP256(point) => point,
+            _ => return Err(Error::Algorithm),
+        };
+
+        let application = decoder.decode_string()?;
+
+        Ok(Self {
+            ec_point,
+            application,
+        })
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Encode for SkEcdsaSha2NistP256 {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(EcdsaPublicKey::NistP256(self.ec_point).encoded_len()? + 4 + self.application.len())
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        EcdsaPublicKey::NistP256(self.ec_point).encode(encoder)?;
+        encoder.encode_str(&self.application)
+    }
+}
+
+/// Security key (FIDO/U2F) Ed25519 public key.
+///
+/// Encoded as `sk-ssh-ed25519@openssh.com`.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct SkEd25519 {
+    /// Ed25519 public key.
+    pub public_key: Ed25519PublicKey,
+
+    /// FIDO/U2F "application" (e.g. `ssh:`) the key is scoped to.
+    pub application: String,
+}
+
+impl Decode for SkEd25519 {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let public_key = Ed25519PublicKey::decode(decoder)?;
+        let application = decoder.decode_string()?;
+
+        Ok(Self {
+            public_key,
+            application,
+        })
+    }
+}
+
+impl Encode for SkEd25519 {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.public_key.encoded_len()? + 4 + self.application.len())
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        self.public_key.encode(encoder)?;
+        encoder.encode_str(&self.application)
+    }
+}
@@ -0,0 +1,111 @@
+//! Runtime self-test.
+//!
+//! Known-answer tests intended to be run at startup (e.g. as a power-on
+//! self-test) by applications embedding this crate, to detect problems in the
+//! build or the environment before any untrusted keys are processed.
+
+use crate::{
+    base64::{self, Decode, Encode},
+    private::KeypairData,
+    Error, PrivateKey, PublicKey, Result,
+};
+
+/// Size of the buffers used to hold Base64-encoded keys.
+const BUF_SIZE: usize = 2048;
+
+/// Embedded `(private key, public key)` fixtures for each enabled algorithm.
+const KEYS: &[(&str, &str)] = &[
+    #[cfg(feature = "alloc")]
+    (
+        include_str!("../tests/examples/id_dsa_1024"),
+        include_str!("../tests/examples/id_dsa_1024.pub"),
+    ),
+    #[cfg(feature = "ecdsa")]
+    (
+        include_str!("../tests/examples/id_ecdsa_p256"),
+        include_str!("../tests/examples/id_ecdsa_p256.pub"),
+    ),
+    #[cfg(feature = "ecdsa")]
+    (
+        include_str!("../tests/examples/id_ecdsa_p384"),
+        include_str!("../tests/examples/id_ecdsa_p384.pub"),
+    ),
+    #[cfg(feature = "ecdsa")]
+    (
+        include_str!("../tests/examples/id_ecdsa_p521"),
+        include_str!("../tests/examples/id_ecdsa_p521.pub"),
+    ),
+    (
+        include_str!("../tests/examples/id_ed25519"),
+        include_str!("../tests/examples/id_ed25519.pub"),
+    ),
+    #[cfg(feature = "alloc")]
+    (
+        include_str!("../tests/examples/id_rsa_3072"),
+        include_str!("../tests/examples/id_rsa_3072.pub"),
+    ),
+];
+
+/// Run the self-test for every algorithm enabled in this build.
+///
+/// For each algorithm, an embedded OpenSSH keypair is decoded, its private and
+/// public halves are checked for consistency, and both are re-encoded to check
+/// the round trip is lossless.
+///
+/// This crate does not implement signing, so signature known-answer tests are
+/// not included.
+///
+/// # Returns
+/// - `Ok(())` if all tests passed
+/// - `Err(Error::SelfTest)` if a test produced an unexpected result
+/// - other errors if an embedded key failed to decode
+pub fn selftest() -> Result<()> {
+    for (private_key, public_key) in KEYS {
+        test_keypair(private_key, public_key)?;
+    }
+
+    Ok(())
+}
+
+/// Test the given OpenSSH-formatted private and public keys.
+fn test_keypair(private_key: &str, public_key: &str) -> Result<()> {
+    let private_key = PrivateKey::from_openssh(private_key)?;
+    let expected = PublicKey::from_openssh(public_key)?;
+    check(private_key.public_key().key_data == expected.key_data)?;
+
+    // Public key round trip
+    let mut buf = [0u8; BUF_SIZE];
+    let encoded = expected.encode_openssh(&mut buf)?;
+    #[cfg(feature = "alloc")]
+    check(encoded == public_key.trim_end())?;
+    // Without `alloc` the comment isn't retained, so only the prefix matches
+    #[cfg(not(feature = "alloc"))]
+    check(public_key.starts_with(encoded))?;
+
+    // Private key round trip
+    let mut buf = [0u8; BUF_SIZE];
+    let encoded = encode_keypair(&private_key.key_data, &mut buf)?;
+
+    let mut decoder = base64::Decoder::new(encoded.as_bytes())?;
+    let decoded = KeypairData::decode(&mut decoder)?;
+    check(decoder.is_finished())?;
+
+    let mut buf = [0u8; BUF_SIZE];
+    check(encode_keypair(&decoded, &mut buf)? == encoded)
+}
+
+/// Encode the given keypair as Base64.
+fn encode_keypair<'o>(key_data: &KeypairData, out: &'o mut [u8]) -> Result<&'o str> {
+    let mut encoder = base64::Encoder::new(out)?;
+    key_data.encode(&mut encoder)?;
+    Ok(encoder.finish()?)
+}
+
+/// Fail the self-test unless the given condition holds.
+fn check(condition: bool) -> Result<()> {
+    if condition {
+        Ok(())
+    } else {
+        Err(Error::SelfTest)
+    }
+}
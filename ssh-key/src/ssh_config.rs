@@ -0,0 +1,291 @@
+//! Parser for OpenSSH client configuration files (`ssh_config`).
+
+use crate::{Error, Result};
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+/// Character that begins a comment
+const COMMENT_DELIMITER: char = '#';
+
+/// Parser for the subset of OpenSSH client configuration, typically found in
+/// `~/.ssh/config`, which determines the keys used for a given host.
+///
+/// For a full description of the format, see:
+/// <https://man7.org/linux/man-pages/man5/ssh_config.5.html>
+///
+/// Options are grouped in sections which begin with a `Host` line containing
+/// one or more patterns. Patterns may contain the `*` and `?` wildcards and
+/// may be negated with `!`. Options which appear before the first `Host`
+/// line apply to all hosts.
+///
+/// The following options are interpreted by [`SshConfig::resolve`]:
+///
+/// - `HostName`
+/// - `HostKeyAlias`
+/// - `IdentityFile`
+///
+/// Other options are ignored, as are `Match` sections and `Include`
+/// directives. `%` tokens, environment variables and `~` in values are not
+/// expanded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SshConfig<'a> {
+    /// Sections of the file, in order.
+    sections: Vec<Section<'a>>,
+}
+
+impl<'a> SshConfig<'a> {
+    /// Parse the given configuration.
+    pub fn new(input: &'a str) -> Result<Self> {
+        let mut sections = vec![Section {
+            patterns: Some(vec!["*"]),
+            options: Vec::new(),
+        }];
+
+        for line in input.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with(COMMENT_DELIMITER) {
+                continue;
+            }
+
+            let (keyword, args) = split_keyword(line)?;
+
+            if keyword.eq_ignore_ascii_case("Host") {
+                if args.is_empty() {
+                    return Err(Error::FormatEncoding);
+                }
+
+                sections.push(Section {
+                    patterns: Some(args),
+                    options: Vec::new(),
+                });
+            } else if keyword.eq_ignore_ascii_case("Match") {
+                sections.push(Section {
+                    patterns: None,
+                    options: Vec::new(),
+                });
+            } else {
+                if is_single_valued(keyword) && args.len() != 1 {
+                    return Err(Error::FormatEncoding);
+                }
+
+                if let Some(section) = sections.last_mut() {
+                    section.options.push((keyword, args));
+                }
+            }
+        }
+
+        Ok(Self { sections })
+    }
+
+    /// Read and parse a file from the filesystem, calling the given closure
+    /// with an [`SshConfig`] which borrows from a temporary buffer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_file<T, F>(path: impl AsRef<Path>, f: F) -> Result<T>
+    where
+        F: FnOnce(SshConfig<'_>) -> Result<T>,
+    {
+        let input = fs::read_to_string(path)?;
+        f(SshConfig::new(&input)?)
+    }
+
+    /// Resolve the configuration which applies to the given host, i.e. the
+    /// name given on the `ssh` command line.
+    ///
+    /// As in OpenSSH, the first value obtained for each option is used,
+    /// except for `IdentityFile` where all values are used in order.
+    pub fn resolve(&self, host: &str) -> HostConfig<'a> {
+        let mut config = HostConfig::default();
+
+        for section in self.sections.iter().filter(|s| s.matches(host)) {
+            for (keyword, args) in &section.options {
+                let value = match args.first() {
+                    Some(&value) => value,
+                    None => continue,
+                };
+
+                if keyword.eq_ignore_ascii_case("HostName") {
+                    config.host_name.get_or_insert(value);
+                } else if keyword.eq_ignore_ascii_case("HostKeyAlias") {
+                    config.host_key_alias.get_or_insert(value);
+                } else if keyword.eq_ignore_ascii_case("IdentityFile") {
+                    config.identity_files.push(value);
+                }
+            }
+        }
+
+        config
+    }
+}
+
+/// Configuration which applies to a particular host.
+///
+/// See [`SshConfig::resolve`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HostConfig<'a> {
+    /// Real host name to connect to (`HostName`), if present.
+    pub host_name: Option<&'a str>,
+
+    /// Alias used instead of the host name when looking up the host key in
+    /// `known_hosts` files (`HostKeyAlias`), if present.
+    pub host_key_alias: Option<&'a str>,
+
+    /// Identity files to authenticate with (`IdentityFile`), in order.
+    ///
+    /// When empty, OpenSSH falls back on its default identity files.
+    pub identity_files: Vec<&'a str>,
+}
+
+impl<'a> HostConfig<'a> {
+    /// Get the name under which the host key for `host` is looked up in
+    /// `known_hosts` files: the `HostKeyAlias` if present, otherwise the
+    /// `HostName` if present, otherwise `host` itself.
+    pub fn known_hosts_name<'b>(&'b self, host: &'b str) -> &'b str {
+        self.host_key_alias.or(self.host_name).unwrap_or(host)
+    }
+}
+
+/// Section of an `ssh_config` file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Section<'a> {
+    /// `Host` patterns, or `None` for `Match` sections, which are unsupported.
+    patterns: Option<Vec<&'a str>>,
+
+    /// Keywords and arguments of the options in this section.
+    options: Vec<(&'a str, Vec<&'a str>)>,
+}
+
+impl Section<'_> {
+    /// Does this section apply to the given host?
+    ///
+    /// A host matches if it matches any of the patterns, unless it also
+    /// matches a negated pattern.
+    fn matches(&self, host: &str) -> bool {
+        let patterns = match &self.patterns {
+            Some(patterns) => patterns,
+            None => return false,
+        };
+
+        let mut matched = false;
+
+        for pattern in patterns {
+            match pattern.strip_prefix('!') {
+                Some(negated) if match_pattern(negated.as_bytes(), host.as_bytes()) => {
+                    return false
+                }
+                Some(_) => (),
+                None => matched |= match_pattern(pattern.as_bytes(), host.as_bytes()),
+            }
+        }
+
+        matched
+    }
+}
+
+/// Options which take exactly one argument.
+fn is_single_valued(keyword: &str) -> bool {
+    ["HostName", "HostKeyAlias", "IdentityFile"]
+        .iter()
+        .any(|k| keyword.eq_ignore_ascii_case(k))
+}
+
+/// Split a line into its keyword and arguments.
+///
+/// The keyword may be separated from the arguments by whitespace and/or a
+/// single `=`. Arguments are whitespace-separated, and may be enclosed in
+/// double quotes to contain whitespace.
+fn split_keyword(line: &str) -> Result<(&str, Vec<&str>)> {
+    let end = line
+        .find(|c: char| c.is_ascii_whitespace() || c == '=')
+        .unwrap_or(line.len());
+
+    let (keyword, mut rest) = line.split_at(end);
+    rest = rest.trim_start();
+
+    if let Some(r) = rest.strip_prefix('=') {
+        rest = r;
+    }
+
+    let mut args = Vec::new();
+
+    loop {
+        rest = rest.trim_start();
+
+        if rest.is_empty() {
+            return Ok((keyword, args));
+        }
+
+        let (arg, r) = match rest.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"').ok_or(Error::FormatEncoding)?;
+                (&quoted[..end], &quoted[(end + 1)..])
+            }
+            None => {
+                let end = rest
+                    .find(|c: char| c.is_ascii_whitespace())
+                    .unwrap_or(rest.len());
+                rest.split_at(end)
+            }
+        };
+
+        args.push(arg);
+        rest = r;
+    }
+}
+
+/// Match a host against a pattern containing `*` and `?` wildcards,
+/// ignoring ASCII case.
+fn match_pattern(pattern: &[u8], host: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => host.is_empty(),
+        Some((b'*', rest)) => (0..=host.len()).any(|i| match_pattern(rest, &host[i..])),
+        Some((b'?', rest)) => !host.is_empty() && match_pattern(rest, &host[1..]),
+        Some((byte, rest)) => match host.split_first() {
+            Some((h, host)) if h.eq_ignore_ascii_case(byte) => match_pattern(rest, host),
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{match_pattern, split_keyword};
+    use crate::Error;
+
+    #[test]
+    fn keyword_separators() {
+        for line in [
+            "HostName example.com",
+            "HostName=example.com",
+            "HostName = example.com",
+        ] {
+            assert_eq!(split_keyword(line), Ok(("HostName", vec!["example.com"])));
+        }
+    }
+
+    #[test]
+    fn quoted_args() {
+        assert_eq!(
+            split_keyword("IdentityFile \"~/my keys/id_ed25519\""),
+            Ok(("IdentityFile", vec!["~/my keys/id_ed25519"]))
+        );
+        assert_eq!(
+            split_keyword("IdentityFile \"~/my keys"),
+            Err(Error::FormatEncoding)
+        );
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(match_pattern(b"*", b"example.com"));
+        assert!(match_pattern(b"*.example.com", b"www.example.com"));
+        assert!(match_pattern(b"*.EXAMPLE.com", b"www.example.COM"));
+        assert!(!match_pattern(b"*.example.com", b"example.com"));
+        assert!(match_pattern(b"host?", b"host1"));
+        assert!(!match_pattern(b"host?", b"host"));
+        assert!(!match_pattern(b"host?", b"host12"));
+    }
+}
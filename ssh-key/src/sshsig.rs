@@ -0,0 +1,286 @@
+//! `SSHSIG` detached signature support.
+//!
+//! Detached signatures over arbitrary messages (e.g. files or git commits),
+//! as produced by `ssh-keygen -Y sign` and described in OpenSSH's
+//! [PROTOCOL.sshsig] specification.
+//!
+//! Each signature is bound to a namespace (e.g. `file` or `git`) so that a
+//! signature made for one purpose can't be accepted for another. Signatures
+//! are created with [`PrivateKey::sign_detached_with_namespace`] and verified
+//! with [`PublicKey::verify_detached_with_namespace`].
+//!
+//! This crate doesn't implement any signature algorithms itself, so both
+//! methods take a callback which performs the actual signing or verification
+//! of the signed data, e.g. using a signature crate or an SSH agent.
+//!
+//! [PROTOCOL.sshsig]: https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.sshsig
+//! [`PrivateKey::sign_detached_with_namespace`]: crate::PrivateKey::sign_detached_with_namespace
+//! [`PublicKey::verify_detached_with_namespace`]: crate::PublicKey::verify_detached_with_namespace
+
+use crate::{
+    base64::{Decode, DecoderExt, Encode, EncoderExt},
+    private::KeypairData,
+    public::KeyData,
+    Algorithm, Error, HashAlg, Result,
+};
+use alloc::{string::String, vec::Vec};
+use pem_rfc7468::{self as pem, LineEnding, PemLabel};
+use sha2::{Digest, Sha256, Sha512};
+
+/// Magic preamble at the beginning of signatures and signed data.
+const MAGIC_PREAMBLE: &[u8; 6] = b"SSHSIG";
+
+/// Signature format version.
+const SIG_VERSION: u32 = 1;
+
+/// Line width used by the PEM encoding of signatures.
+const PEM_LINE_WIDTH: usize = 70;
+
+/// `SSHSIG` detached signature.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SshSig {
+    /// Public key of the signer.
+    public_key: KeyData,
+
+    /// Namespace the signature is bound to.
+    namespace: String,
+
+    /// Hash function applied to the message.
+    hash_alg: HashAlg,
+
+    /// Signature algorithm.
+    algorithm: Algorithm,
+
+    /// Signature produced by `algorithm`.
+    signature: Vec<u8>,
+}
+
+impl SshSig {
+    /// Create a signature from a signature over [`SshSig::signed_data`]
+    /// produced by an external signer, e.g. an SSH agent.
+    ///
+    /// Returns [`Error::Algorithm`] if `algorithm` can't be used with
+    /// `public_key`, or [`Error::Namespace`] if `namespace` is empty.
+    pub fn new(
+        public_key: KeyData,
+        namespace: impl Into<String>,
+        hash_alg: HashAlg,
+        algorithm: Algorithm,
+        signature: Vec<u8>,
+    ) -> Result<Self> {
+        let namespace = namespace.into();
+
+        if namespace.is_empty() {
+            return Err(Error::Namespace);
+        }
+
+        if algorithm.key_algorithm() != public_key.algorithm() {
+            return Err(Error::Algorithm);
+        }
+
+        Ok(Self {
+            public_key,
+            namespace,
+            hash_alg,
+            algorithm,
+            signature,
+        })
+    }
+
+    /// Parse a PEM-encoded (`-----BEGIN SSH SIGNATURE-----`) signature.
+    pub fn from_pem(input: impl AsRef<[u8]>) -> Result<Self> {
+        let mut pem_decoder = pem::Decoder::new_detect_wrap(input.as_ref())?;
+        pem_decoder.check_type_label(Self::TYPE_LABEL)?;
+
+        let signature = Self::decode(&mut pem_decoder)?;
+
+        if pem_decoder.remaining_len() != 0 {
+            return Err(Error::Length);
+        }
+
+        Ok(signature)
+    }
+
+    /// Encode this signature as PEM.
+    pub fn to_pem(&self, line_ending: LineEnding) -> Result<String> {
+        let base64_len = (((self.encoded_len()? * 4) / 3) + 3) & !3;
+        let nlines = (base64_len + PEM_LINE_WIDTH - 1) / PEM_LINE_WIDTH;
+        let boundaries_len = 2 * (Self::TYPE_LABEL.len() + 16 + line_ending.len());
+        let mut buf = vec![0u8; base64_len + nlines * line_ending.len() + boundaries_len];
+
+        let mut pem_encoder =
+            pem::Encoder::new_wrapped(Self::TYPE_LABEL, PEM_LINE_WIDTH, line_ending, &mut buf)?;
+
+        self.encode(&mut pem_encoder)?;
+        let encoded_len = pem_encoder.finish()?;
+        buf.truncate(encoded_len);
+        Ok(String::from_utf8(buf)?)
+    }
+
+    /// Compute the data which is signed to produce a signature over `msg`
+    /// with the given namespace and hash function.
+    ///
+    /// This is only needed when signing with an external signer: see
+    /// [`SshSig::new`].
+    pub fn signed_data(namespace: &str, hash_alg: HashAlg, msg: &[u8]) -> Result<Vec<u8>> {
+        if namespace.is_empty() {
+            return Err(Error::Namespace);
+        }
+
+        let mut data = Vec::new();
+        data.extend_from_slice(MAGIC_PREAMBLE);
+        data.encode_str(namespace)?;
+        data.encode_str("")?; // reserved
+        hash_alg.encode(&mut data)?;
+
+        match hash_alg {
+            HashAlg::Sha256 => data.encode_byte_slice(&Sha256::digest(msg))?,
+            HashAlg::Sha512 => data.encode_byte_slice(&Sha512::digest(msg))?,
+        }
+
+        Ok(data)
+    }
+
+    /// Get the public key of the signer.
+    pub fn public_key(&self) -> &KeyData {
+        &self.public_key
+    }
+
+    /// Get the namespace the signature is bound to.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Get the hash function applied to the message.
+    pub fn hash_alg(&self) -> HashAlg {
+        self.hash_alg
+    }
+
+    /// Get the signature algorithm.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Get the raw signature produced by [`SshSig::algorithm`].
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// Sign `msg` with the given keypair, using `signer` to sign the
+    /// [`SshSig::signed_data`].
+    pub(crate) fn sign(
+        keypair: &KeypairData,
+        namespace: &str,
+        hash_alg: HashAlg,
+        msg: &[u8],
+        signer: impl FnOnce(&KeypairData, &[u8]) -> Result<(Algorithm, Vec<u8>)>,
+    ) -> Result<Self> {
+        let signed_data = Self::signed_data(namespace, hash_alg, msg)?;
+        let (algorithm, signature) = signer(keypair, &signed_data)?;
+        Self::new(
+            KeyData::from(keypair),
+            namespace,
+            hash_alg,
+            algorithm,
+            signature,
+        )
+    }
+
+    /// Verify this signature over `msg` for the given public key and
+    /// namespace, using `verifier` to verify the signature over the
+    /// [`SshSig::signed_data`].
+    pub(crate) fn verify(
+        &self,
+        public_key: &KeyData,
+        namespace: &str,
+        msg: &[u8],
+        verifier: impl FnOnce(&Self, &[u8]) -> Result<()>,
+    ) -> Result<()> {
+        if &self.public_key != public_key {
+            return Err(Error::PublicKeyMismatch);
+        }
+
+        if self.namespace != namespace {
+            return Err(Error::Namespace);
+        }
+
+        let signed_data = Self::signed_data(namespace, self.hash_alg, msg)?;
+        verifier(self, &signed_data)
+    }
+
+    /// Length of the encoded signature blob, i.e. the algorithm followed by
+    /// the raw signature.
+    fn signature_blob_len(&self) -> Result<usize> {
+        Ok(self.algorithm.encoded_len()? + 4 + self.signature.len())
+    }
+}
+
+impl Decode for SshSig {
+    fn decode(decoder: &mut impl DecoderExt) -> Result<Self> {
+        let mut magic_preamble = [0u8; MAGIC_PREAMBLE.len()];
+        decoder.decode_base64(&mut magic_preamble)?;
+
+        if &magic_preamble != MAGIC_PREAMBLE || decoder.decode_u32()? != SIG_VERSION {
+            return Err(Error::FormatEncoding);
+        }
+
+        let public_key = decode_blob(decoder, |reader| KeyData::decode(reader))?;
+        let namespace = decoder.decode_string()?;
+        let _reserved = decoder.decode_byte_vec()?;
+        let hash_alg = HashAlg::decode(decoder)?;
+        let (algorithm, signature) = decode_blob(decoder, |reader| {
+            Ok((Algorithm::decode(reader)?, reader.decode_byte_vec()?))
+        })?;
+
+        Self::new(public_key, namespace, hash_alg, algorithm, signature)
+    }
+}
+
+impl Encode for SshSig {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(MAGIC_PREAMBLE.len()
+            + 4
+            + 4
+            + self.public_key.encoded_len()?
+            + 4
+            + self.namespace.len()
+            + 4
+            + self.hash_alg.encoded_len()?
+            + 4
+            + self.signature_blob_len()?)
+    }
+
+    fn encode(&self, encoder: &mut impl EncoderExt) -> Result<()> {
+        encoder.encode_base64(MAGIC_PREAMBLE)?;
+        encoder.encode_u32(SIG_VERSION)?;
+        encoder.encode_usize(self.public_key.encoded_len()?)?;
+        self.public_key.encode(encoder)?;
+        encoder.encode_str(&self.namespace)?;
+        encoder.encode_str("")?; // reserved
+        self.hash_alg.encode(encoder)?;
+        encoder.encode_usize(self.signature_blob_len()?)?;
+        self.algorithm.encode(encoder)?;
+        encoder.encode_byte_slice(&self.signature)
+    }
+}
+
+impl PemLabel for SshSig {
+    const TYPE_LABEL: &'static str = "SSH SIGNATURE";
+}
+
+/// Decode a `string` containing a value decoded with `f`, which must consume
+/// the entire string.
+fn decode_blob<T>(
+    decoder: &mut impl DecoderExt,
+    f: impl FnOnce(&mut &[u8]) -> Result<T>,
+) -> Result<T> {
+    let blob = decoder.decode_byte_vec()?;
+    let mut reader = blob.as_slice();
+    let value = f(&mut reader)?;
+
+    if reader.is_empty() {
+        Ok(value)
+    } else {
+        Err(Error::Length)
+    }
+}
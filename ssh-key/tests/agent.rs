@@ -0,0 +1,366 @@
+//! SSH agent protocol tests.
+
+#![cfg(feature = "alloc")]
+
+use hex_literal::hex;
+use ssh_key::{
+    agent::{AddIdentity, Request, Response, SessionBind, SignRequest},
+    Algorithm, Error, HashAlg, PrivateKey, PublicKey,
+};
+
+#[cfg(feature = "ecdsa")]
+use ssh_key::agent::Constraint;
+
+/// Ed25519 OpenSSH-formatted private key
+const OPENSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519");
+
+/// ECDSA/P-256 OpenSSH-formatted private key
+#[cfg(feature = "ecdsa")]
+const OPENSSH_ECDSA_P256_EXAMPLE: &str = include_str!("examples/id_ecdsa_p256");
+
+/// RSA (3072-bit) OpenSSH-formatted private key
+const OPENSSH_RSA_3072_EXAMPLE: &str = include_str!("examples/id_rsa_3072");
+
+/// Ed25519 OpenSSH-formatted public key
+const OSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519.pub");
+
+/// `SSH_AGENTC_ADD_IDENTITY` request sent by `ssh-add id_ed25519`
+const ADD_IDENTITY_ED25519: &[u8] = include_bytes!("examples/agent_add_identity_ed25519.bin");
+
+/// `SSH_AGENTC_ADD_ID_CONSTRAINED` request sent by `ssh-add -t 60 -c id_ecdsa_p256`
+#[cfg(feature = "ecdsa")]
+const ADD_ID_CONSTRAINED_ECDSA_P256: &[u8] =
+    include_bytes!("examples/agent_add_id_constrained_ecdsa_p256.bin");
+
+/// `SSH_AGENTC_ADD_IDENTITY` request sent by `ssh-add id_rsa_3072`
+const ADD_IDENTITY_RSA_3072: &[u8] = include_bytes!("examples/agent_add_identity_rsa_3072.bin");
+
+/// `SSH_AGENT_IDENTITIES_ANSWER` response listing `id_ed25519`
+const IDENTITIES_ANSWER: &[u8] = include_bytes!("examples/agent_identities_answer.bin");
+
+/// `SSH_AGENTC_SIGN_REQUEST` request sent by `ssh-keygen -Y sign`
+const SIGN_REQUEST: &[u8] = include_bytes!("examples/agent_sign_request.bin");
+
+/// `SSH_AGENT_SIGN_RESPONSE` response to the above request
+const SIGN_RESPONSE: &[u8] = include_bytes!("examples/agent_sign_response.bin");
+
+/// `SSH_AGENTC_REMOVE_IDENTITY` request sent by `ssh-add -d id_ed25519.pub`
+const REMOVE_IDENTITY: &[u8] = include_bytes!("examples/agent_remove_identity.bin");
+
+#[test]
+fn decode_and_encode_simple_messages() {
+    let request_identities = hex!("000000010b");
+    let remove_all_identities = hex!("0000000113");
+    let failure = hex!("0000000105");
+    let success = hex!("0000000106");
+
+    let request = Request::decode(&request_identities).unwrap();
+    assert!(matches!(request, Request::RequestIdentities));
+    assert_eq!(&request_identities, request.encode().unwrap().as_slice());
+
+    let request = Request::decode(&remove_all_identities).unwrap();
+    assert!(matches!(request, Request::RemoveAllIdentities));
+    assert_eq!(&remove_all_identities, request.encode().unwrap().as_slice());
+
+    let response = Response::decode(&failure).unwrap();
+    assert_eq!(Response::Failure, response);
+    assert_eq!(&failure, response.encode().unwrap().as_slice());
+
+    let response = Response::decode(&success).unwrap();
+    assert_eq!(Response::Success, response);
+    assert_eq!(&success, response.encode().unwrap().as_slice());
+
+    let extension_failure = hex!("000000011c");
+    let response = Response::decode(&extension_failure).unwrap();
+    assert_eq!(Response::ExtensionFailure, response);
+    assert_eq!(&extension_failure, response.encode().unwrap().as_slice());
+}
+
+#[test]
+fn decode_add_identity_ed25519() {
+    let private_key = PrivateKey::from_openssh(OPENSSH_ED25519_EXAMPLE).unwrap();
+
+    let identity = match Request::decode(ADD_IDENTITY_ED25519).unwrap() {
+        Request::AddIdentity(identity) => identity,
+        other => panic!("unexpected request: {:?}", other),
+    };
+
+    assert_eq!("user@example.com", identity.comment);
+    assert!(identity.constraints.is_empty());
+
+    let keypair = identity.key_data.ed25519().unwrap();
+    let expected = private_key.key_data.ed25519().unwrap();
+    assert_eq!(expected.public, keypair.public);
+    assert_eq!(expected.to_bytes(), keypair.to_bytes());
+}
+
+#[test]
+fn encode_add_identity_ed25519() {
+    let private_key = PrivateKey::from_openssh(OPENSSH_ED25519_EXAMPLE).unwrap();
+    let request = Request::AddIdentity(AddIdentity {
+        key_data: private_key.key_data.clone(),
+        comment: private_key.comment.clone(),
+        constraints: Vec::new(),
+    });
+
+    assert_eq!(ADD_IDENTITY_ED25519, request.encode().unwrap());
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_add_id_constrained_ecdsa_p256() {
+    let private_key = PrivateKey::from_openssh(OPENSSH_ECDSA_P256_EXAMPLE).unwrap();
+
+    let identity = match Request::decode(ADD_ID_CONSTRAINED_ECDSA_P256).unwrap() {
+        Request::AddIdentity(identity) => identity,
+        other => panic!("unexpected request: {:?}", other),
+    };
+
+    assert_eq!("user@example.com", identity.comment);
+    assert_eq!(
+        vec![Constraint::Lifetime(60), Constraint::Confirm],
+        identity.constraints
+    );
+
+    let keypair = identity.key_data.ecdsa().unwrap();
+    let expected = private_key.key_data.ecdsa().unwrap();
+    assert_eq!(expected.public_key_bytes(), keypair.public_key_bytes());
+    assert_eq!(expected.private_key_bytes(), keypair.private_key_bytes());
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn encode_add_id_constrained_ecdsa_p256() {
+    let private_key = PrivateKey::from_openssh(OPENSSH_ECDSA_P256_EXAMPLE).unwrap();
+    let request = Request::AddIdentity(AddIdentity {
+        key_data: private_key.key_data.clone(),
+        comment: private_key.comment.clone(),
+        constraints: vec![Constraint::Lifetime(60), Constraint::Confirm],
+    });
+
+    assert_eq!(ADD_ID_CONSTRAINED_ECDSA_P256, request.encode().unwrap());
+}
+
+#[test]
+fn add_identity_rsa_3072() {
+    let private_key = PrivateKey::from_openssh(OPENSSH_RSA_3072_EXAMPLE).unwrap();
+
+    let identity = match Request::decode(ADD_IDENTITY_RSA_3072).unwrap() {
+        Request::AddIdentity(identity) => identity,
+        other => panic!("unexpected request: {:?}", other),
+    };
+
+    assert_eq!("user@example.com", identity.comment);
+
+    let keypair = identity.key_data.rsa().unwrap();
+    let expected = private_key.key_data.rsa().unwrap();
+    assert_eq!(expected.public, keypair.public);
+    assert_eq!(expected.private.d, keypair.private.d);
+    assert_eq!(expected.private.iqmp, keypair.private.iqmp);
+    assert_eq!(expected.private.p, keypair.private.p);
+    assert_eq!(expected.private.q, keypair.private.q);
+
+    assert_eq!(
+        ADD_IDENTITY_RSA_3072,
+        Request::AddIdentity(identity).encode().unwrap()
+    );
+}
+
+#[test]
+fn identities_answer() {
+    let public_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let response = Response::decode(IDENTITIES_ANSWER).unwrap();
+    assert_eq!(Response::IdentitiesAnswer(vec![public_key]), response);
+    assert_eq!(IDENTITIES_ANSWER, response.encode().unwrap());
+}
+
+#[test]
+fn sign_request_and_response() {
+    let public_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+
+    let request = match Request::decode(SIGN_REQUEST).unwrap() {
+        Request::SignRequest(request) => request,
+        other => panic!("unexpected request: {:?}", other),
+    };
+
+    assert_eq!(public_key.key_data, request.key_data);
+    assert!(request.data.starts_with(b"SSHSIG"));
+    assert_eq!(0, request.flags);
+    assert_eq!(
+        SIGN_REQUEST,
+        Request::SignRequest(request).encode().unwrap()
+    );
+
+    let signature = match Response::decode(SIGN_RESPONSE).unwrap() {
+        Response::SignResponse(signature) => signature,
+        other => panic!("unexpected response: {:?}", other),
+    };
+
+    // `string` algorithm name followed by the 64-byte Ed25519 signature
+    assert_eq!(&hex!("0000000b") as &[u8], &signature[..4]);
+    assert_eq!(b"ssh-ed25519", &signature[4..15]);
+    assert_eq!(4 + 11 + 4 + 64, signature.len());
+    assert_eq!(
+        SIGN_RESPONSE,
+        Response::SignResponse(signature).encode().unwrap()
+    );
+}
+
+#[test]
+fn sign_request_flags() {
+    let public_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let request = Request::SignRequest(SignRequest {
+        key_data: public_key.key_data,
+        data: b"hello".to_vec(),
+        flags: SignRequest::RSA_SHA2_512,
+    });
+
+    let encoded = request.encode().unwrap();
+    assert_eq!(&hex!("00000004"), &encoded[encoded.len() - 4..]);
+
+    match Request::decode(&encoded).unwrap() {
+        Request::SignRequest(request) => {
+            assert_eq!(b"hello", request.data.as_slice());
+            assert_eq!(SignRequest::RSA_SHA2_512, request.flags);
+        }
+        other => panic!("unexpected request: {:?}", other),
+    }
+}
+
+#[test]
+fn sign_request_signature_algorithm() {
+    let rsa_key = PrivateKey::from_openssh(OPENSSH_RSA_3072_EXAMPLE)
+        .unwrap()
+        .public_key()
+        .key_data;
+
+    for alg in [
+        Algorithm::Rsa,
+        Algorithm::RsaSha2(HashAlg::Sha256),
+        Algorithm::RsaSha2(HashAlg::Sha512),
+    ] {
+        let request = SignRequest::new(rsa_key.clone(), b"hello".to_vec(), alg).unwrap();
+        assert_eq!(alg, request.signature_algorithm());
+    }
+
+    let request = SignRequest::new(
+        rsa_key.clone(),
+        b"hello".to_vec(),
+        Algorithm::RsaSha2(HashAlg::Sha512),
+    )
+    .unwrap();
+    assert_eq!(SignRequest::RSA_SHA2_512, request.flags);
+
+    let ed25519_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE)
+        .unwrap()
+        .key_data;
+    let request = SignRequest {
+        key_data: ed25519_key.clone(),
+        data: b"hello".to_vec(),
+        flags: SignRequest::RSA_SHA2_512,
+    };
+    assert_eq!(Algorithm::Ed25519, request.signature_algorithm());
+
+    assert_eq!(
+        Err(Error::Algorithm),
+        SignRequest::new(
+            ed25519_key,
+            b"hello".to_vec(),
+            Algorithm::RsaSha2(HashAlg::Sha256)
+        )
+    );
+    assert_eq!(
+        Err(Error::Algorithm),
+        SignRequest::new(rsa_key, b"hello".to_vec(), Algorithm::Ed25519)
+    );
+}
+
+#[test]
+fn remove_identity() {
+    let public_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let request = Request::RemoveIdentity(public_key.key_data);
+    assert_eq!(REMOVE_IDENTITY, request.encode().unwrap());
+
+    match Request::decode(REMOVE_IDENTITY).unwrap() {
+        Request::RemoveIdentity(key_data) => assert!(key_data.is_ed25519()),
+        other => panic!("unexpected request: {:?}", other),
+    }
+}
+
+#[test]
+fn session_bind() {
+    let public_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let session_bind = SessionBind {
+        host_key: public_key.key_data,
+        session_id: vec![0x42; 32],
+        signature: vec![0x17; 83],
+        is_forwarding: true,
+    };
+
+    let encoded = Request::SessionBind(session_bind.clone()).encode().unwrap();
+    assert_eq!(&hex!("1b00000018"), &encoded[4..9]);
+    assert_eq!(b"session-bind@openssh.com", &encoded[9..33]);
+    assert_eq!(&hex!("01"), &encoded[encoded.len() - 1..]);
+
+    match Request::decode(&encoded).unwrap() {
+        Request::SessionBind(decoded) => assert_eq!(session_bind, decoded),
+        other => panic!("unexpected request: {:?}", other),
+    }
+
+    // Invalid `is_forwarding` boolean
+    let mut invalid = encoded.clone();
+    *invalid.last_mut().unwrap() = 2;
+    assert_eq!(
+        Error::FormatEncoding,
+        Request::decode(&invalid).unwrap_err()
+    );
+
+    // Unsupported extension
+    let mut unsupported = encoded;
+    unsupported[9] = b'x';
+    assert_eq!(
+        Error::FormatEncoding,
+        Request::decode(&unsupported).unwrap_err()
+    );
+}
+
+#[test]
+fn reject_malformed_messages() {
+    // Length prefix doesn't match the message length
+    assert_eq!(
+        Error::Length,
+        Request::decode(&hex!("000000020b")).unwrap_err()
+    );
+    assert_eq!(
+        Error::Length,
+        Request::decode(&hex!("000000010b00")).unwrap_err()
+    );
+
+    // Trailing data after the message contents
+    assert_eq!(
+        Error::Length,
+        Request::decode(&hex!("000000020b00")).unwrap_err()
+    );
+
+    // Empty message
+    assert_eq!(
+        Error::Length,
+        Response::decode(&hex!("00000000")).unwrap_err()
+    );
+
+    // Unknown message types
+    assert_eq!(
+        Error::FormatEncoding,
+        Request::decode(&hex!("0000000109")).unwrap_err()
+    );
+    assert_eq!(
+        Error::FormatEncoding,
+        Response::decode(&hex!("000000010b")).unwrap_err()
+    );
+
+    // Truncated message
+    let truncated = &REMOVE_IDENTITY[..REMOVE_IDENTITY.len() - 1];
+    let mut message = Vec::from(truncated);
+    message[3] -= 1;
+    assert!(Request::decode(&message).is_err());
+}
@@ -0,0 +1,73 @@
+//! FIDO/U2F security key attestation tests.
+
+#![cfg(feature = "alloc")]
+
+use hex_literal::hex;
+use ssh_key::{attestation::SkAttestation, Error};
+
+/// `ssh-sk-attest-v01` attestation, with placeholder contents
+const ATTESTATION_V01_EXAMPLE: &[u8] = &hex!(
+    "00000011" "7373682d736b2d6174746573742d763031" // "ssh-sk-attest-v01"
+    "00000004" "30820100"                           // certificate
+    "00000003" "304402"                             // signature
+    "00000002" "a101"                               // authenticator data
+    "00000000"                                      // reserved flags
+    "00000000"                                      // reserved
+);
+
+/// `ssh-sk-attest-v00` attestation, with placeholder contents
+const ATTESTATION_V00_EXAMPLE: &[u8] = &hex!(
+    "00000011" "7373682d736b2d6174746573742d763030" // "ssh-sk-attest-v00"
+    "00000004" "30820100"                           // certificate
+    "00000003" "304402"                             // signature
+    "00000000"                                      // reserved flags
+    "00000000"                                      // reserved
+);
+
+#[test]
+fn decode_v01() {
+    let attestation = SkAttestation::from_bytes(ATTESTATION_V01_EXAMPLE).unwrap();
+    assert_eq!(attestation.certificate(), &hex!("30820100"));
+    assert_eq!(attestation.signature(), &hex!("304402"));
+    assert_eq!(attestation.authenticator_data(), Some(&hex!("a101")[..]));
+    assert_eq!(attestation.to_bytes().unwrap(), ATTESTATION_V01_EXAMPLE);
+}
+
+#[test]
+fn decode_v00() {
+    let attestation = SkAttestation::from_bytes(ATTESTATION_V00_EXAMPLE).unwrap();
+    assert_eq!(attestation.certificate(), &hex!("30820100"));
+    assert_eq!(attestation.signature(), &hex!("304402"));
+    assert_eq!(attestation.authenticator_data(), None);
+    assert_eq!(attestation.to_bytes().unwrap(), ATTESTATION_V00_EXAMPLE);
+}
+
+#[test]
+fn encode_new() {
+    let attestation = SkAttestation::new(
+        hex!("30820100").to_vec(),
+        hex!("304402").to_vec(),
+        Some(hex!("a101").to_vec()),
+    );
+    assert_eq!(attestation.to_bytes().unwrap(), ATTESTATION_V01_EXAMPLE);
+}
+
+#[test]
+fn reject_malformed() {
+    let mut unknown_version = ATTESTATION_V01_EXAMPLE.to_vec();
+    unknown_version[20] = b'2';
+    assert_eq!(
+        SkAttestation::from_bytes(&unknown_version),
+        Err(Error::FormatEncoding)
+    );
+
+    let mut trailing_data = ATTESTATION_V00_EXAMPLE.to_vec();
+    trailing_data.push(0);
+    assert_eq!(
+        SkAttestation::from_bytes(&trailing_data),
+        Err(Error::Length)
+    );
+
+    let truncated = &ATTESTATION_V01_EXAMPLE[..ATTESTATION_V01_EXAMPLE.len() - 1];
+    assert!(SkAttestation::from_bytes(truncated).is_err());
+}
@@ -2,7 +2,13 @@
 
 #![cfg(all(feature = "ecdsa", feature = "std"))]
 
-use ssh_key::AuthorizedKeys;
+use ssh_key::{public::KeyDataRef, Algorithm, AuthorizedKeys};
+
+#[cfg(feature = "fingerprint")]
+use {
+    sha2::Sha256,
+    ssh_key::{FingerprintMap, PublicKey},
+};
 
 // TODO(tarcieri): test file permissions
 #[test]
@@ -28,8 +34,79 @@ fn read_example_file() {
         assert_eq!(entry4.public_key.to_string(), "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAACAQC0WRHtxuxefSJhpIxGq4ibGFgwYnESPm8C3JFM88A1JJLoprenklrd7VJ+VH3Ov/bQwZwLyRU5dRmfR/SWTtIPWs7tToJVayKKDB+/qoXmM5ui/0CU2U4rCdQ6PdaCJdC7yFgpPL8WexjWN06+eSIKYz1AAXbx9rRv1iasslK/KUqtsqzVliagI6jl7FPO2GhRZMcso6LsZGgSxuYf/Lp0D/FcBU8GkeOo1Sx5xEt8H8bJcErtCe4Blb8JxcW6EXO3sReb4z+zcR07gumPgFITZ6hDA8sSNuvo/AlWg0IKTeZSwHHVknWdQqDJ0uczE837caBxyTZllDNIGkBjCIIOFzuTT76HfYc/7CTTGk07uaNkUFXKN79xDiFOX8JQ1ZZMZvGOTwWjuT9CqgdTvQRORbRWwOYv3MH8re9ykw3Ip6lrPifY7s6hOaAKry/nkGPMt40m1TdiW98MTIpooE7W+WXu96ax2l2OJvxX8QR7l+LFlKnkIEEJd/ItF1G22UmOjkVwNASTwza/hlY+8DoVvEmwum/nMgH2TwQT3bTQzF9s9DOJkH4d8p4Mw4gEDjNx0EgUFA91ysCAeUMQQyIvuR8HXXa+VcvhOOO5mmBcVhxJ3qUOJTyDBsT0932Zb4mNtkxdigoVxu+iiwk0vwtvKwGVDYdyMP5EAQeEIP1t0w== user4@example.com");
         assert_eq!(entry4.public_key.comment, "user4@example.com");
 
+        let entry5 = authorized_keys.next().unwrap()?;
+        assert_eq!(entry5.options.to_string(), "no-touch-required");
+        assert_eq!(entry5.public_key.to_string(), "sk-ssh-ed25519@openssh.com AAAAGnNrLXNzaC1lZDI1NTE5QG9wZW5zc2guY29tAAAAILM+rvN+ot98qgEN796jTiQfZfG1KaT0PtFDJ/XFSqtiAAAABHNzaDo= user5@example.com");
+        assert_eq!(entry5.public_key.comment, "user5@example.com");
+
         assert_eq!(authorized_keys.next(), None);
         Ok(())
     })
     .unwrap();
 }
+
+#[test]
+fn borrowed_entries() {
+    let input = include_str!("examples/authorized_keys");
+    let mut borrowed = AuthorizedKeys::new(input);
+
+    let entry1 = borrowed.next_ref().unwrap().unwrap();
+    assert_eq!(entry1.public_key.comment, "user1@example.com");
+    assert!(entry1.public_key.key_data.ed25519().is_some());
+
+    let entry2 = borrowed.next_ref().unwrap().unwrap();
+    assert_eq!(entry2.options.to_string(), "command=\"/usr/bin/date\"");
+    assert!(entry2.public_key.key_data.ecdsa().is_some());
+
+    let entry3 = borrowed.next_ref().unwrap().unwrap();
+    assert!(matches!(
+        entry3.public_key.key_data,
+        KeyDataRef::Other {
+            algorithm: Algorithm::Dsa,
+            ..
+        }
+    ));
+
+    let mut borrowed = AuthorizedKeys::new(input);
+
+    for expected in AuthorizedKeys::new(input) {
+        let expected = expected.unwrap();
+        let entry = borrowed.next_ref().unwrap().unwrap();
+        assert_eq!(expected.options, entry.options);
+        assert_eq!(
+            expected.public_key,
+            entry.public_key.to_public_key().unwrap()
+        );
+    }
+
+    assert_eq!(borrowed.next_ref(), None);
+}
+
+#[cfg(feature = "fingerprint")]
+#[test]
+fn fingerprint_map() {
+    let input = include_str!("examples/authorized_keys");
+    let mut map = FingerprintMap::new();
+    map.try_extend(AuthorizedKeys::new(input).map(|entry| entry.map(|e| e.public_key)))
+        .unwrap();
+    assert_eq!(map.len(), 5);
+
+    // Keys are looked up by their key data, regardless of comment
+    let key = PublicKey::from_openssh(include_str!("examples/id_ed25519.pub")).unwrap();
+    assert_eq!(map.get(&key).unwrap().unwrap().comment, "user1@example.com");
+    assert!(map.contains(&key.key_data).unwrap());
+
+    let fingerprint = key.fingerprint::<Sha256>().unwrap().into();
+    assert_eq!(map.get_by_fingerprint(&fingerprint), map.get(&key).unwrap());
+
+    let other = PublicKey::from_openssh(include_str!("examples/id_ecdsa_p384.pub")).unwrap();
+    assert_eq!(map.get(&other).unwrap(), None);
+
+    // Re-inserting a key replaces its entry
+    let previous = map.insert(key.clone()).unwrap().unwrap();
+    assert_eq!(previous.comment, "user1@example.com");
+    assert_eq!(map.len(), 5);
+
+    assert_eq!(map.remove(&key).unwrap(), Some(key));
+    assert_eq!(map.len(), 4);
+}
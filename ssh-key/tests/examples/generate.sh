@@ -12,3 +12,106 @@ ssh-keygen -t ecdsa -b 521 -f id_ecdsa_p521 -C user@example.com
 ssh-keygen -t ed25519 -f id_ed25519 -C user@example.com
 ssh-keygen -t rsa -b 3072 -f id_rsa_3072 -C user@example.com
 ssh-keygen -t rsa -b 4096 -f id_rsa_4096 -C user@example.com
+
+# RSA key whose PPK private blob is a multiple of the AES block size (704
+# bytes), regenerated until the key components had the required lengths
+ssh-keygen -t rsa -b 2192 -f id_rsa_2192 -C user@example.com
+rm id_rsa_2192.pub
+
+# Encrypted with the passphrase `hunter42`
+ssh-keygen -t ed25519 -f id_ed25519_encrypted -N hunter42 -C user@example.com
+rm id_ed25519_encrypted.pub
+
+# PuTTY (PPK) conversions of the keys above. The encrypted examples use the
+# passphrase `hunter42`. Equivalent files can be produced with e.g.:
+#
+#   puttygen id_ed25519 -O private -o id_ed25519.ppk --ppk-param version=3
+#   puttygen id_rsa_3072 -O private -o id_rsa_3072.ppk --ppk-param version=2
+
+# PKCS#8 (DER) conversions of the keys above
+for key in id_ecdsa_p256 id_ecdsa_p384 id_rsa_3072; do
+    cp $key $key.tmp
+    ssh-keygen -p -m PKCS8 -N "" -f $key.tmp
+    openssl pkcs8 -topk8 -nocrypt -in $key.tmp -outform DER -out $key.pkcs8.der
+    rm $key.tmp
+done
+
+# SPKI (DER) conversions of the public keys above
+for key in id_ecdsa_p256 id_rsa_3072; do
+    ssh-keygen -e -m PKCS8 -f $key.pub | openssl pkey -pubin -outform DER -out $key.spki.der
+done
+
+# Neither `ssh-keygen` nor OpenSSL can export OpenSSH Ed25519 keys as PKCS#8.
+# `id_ed25519_v1.pkcs8.der` (PKCS#8 v1, which lacks the public key) was
+# produced using pyca/cryptography:
+python3 -c '
+from cryptography.hazmat.primitives import serialization as s
+key = s.load_ssh_private_key(open("id_ed25519", "rb").read(), None)
+der = key.private_bytes(s.Encoding.DER, s.PrivateFormat.PKCS8, s.NoEncryption())
+open("id_ed25519_v1.pkcs8.der", "wb").write(der)
+'
+# `id_ed25519.pkcs8.der` is the PKCS#8 v2 (RFC5958 `OneAsymmetricKey`)
+# equivalent including the public key, assembled by hand following the example
+# in RFC8410 Section 10.3.
+
+# RFC4716 ("SSH2 PUBLIC KEY") conversions of the keys above, with the comment
+# replaced by the one on the original key
+for key in id_ed25519 id_rsa_3072; do
+    ssh-keygen -e -f $key.pub | sed 's/^Comment: .*/Comment: "user@example.com"/' > $key.rfc4716
+done
+
+# `id_ecdsa_p256.rfc4716` was assembled by hand to exercise the `Subject`
+# header, private-use `x-` headers, and header continuation lines, following
+# the examples in RFC4716 Section 3.6.
+
+# SSH agent protocol messages, captured by a proxy logging the traffic between
+# `ssh-add`/`ssh-keygen` and `ssh-agent` while running:
+#
+#   ssh-add id_ed25519                         # agent_add_identity_ed25519.bin
+#   ssh-add -l                                 # agent_identities_answer.bin
+#   printf hello > msg
+#   ssh-keygen -Y sign -n file -f id_ed25519.pub msg
+#                                              # agent_sign_request.bin
+#                                              # agent_sign_response.bin
+#   ssh-add -t 60 -c id_ecdsa_p256             # agent_add_id_constrained_ecdsa_p256.bin
+#   ssh-add id_rsa_3072                        # agent_add_identity_rsa_3072.bin
+#   ssh-add -d id_ed25519.pub                  # agent_remove_identity.bin
+
+# Generating security key (FIDO/U2F) keys requires an authenticator, so
+# `id_sk_ed25519.pub` and `id_sk_ecdsa_p256.pub` were assembled by hand from
+# `id_ed25519.pub` and `id_ecdsa_p256.pub` with an application of `ssh:`, and
+# checked using `ssh-keygen -l`. The corresponding unencrypted private keys
+# `id_sk_ed25519` and `id_sk_ecdsa_p256` were likewise assembled with a flags
+# byte of `0x01` (user presence required), a 64-byte placeholder key handle,
+# and empty reserved data, and checked using `ssh-keygen -y`.
+
+# OpenSSH never writes more than one key per private key file, so `id_multi`
+# was assembled by hand from the keys in `id_ed25519` and `id_rsa_3072` (in
+# that order), using the check bytes of `id_ed25519`.
+
+# `id_ed25519_mismatch` is `id_ed25519` with the last bit of the public key
+# section (but not the private key section) flipped.
+
+# `id_ed25519_bad_padding` is `id_ed25519` with its last padding byte changed
+# from `0x05` to `0x06`, and `id_ed25519_bad_length` is `id_ed25519` with the
+# declared length of the private key section increased by 8.
+
+# Key revocation lists (KRLs) revoking keys by blob, SHA-1 hash, and SHA-256
+# hash, and certificates issued by `id_ed25519` by serial number
+cat > keys.krl.spec <<SPEC
+key: $(cat id_ed25519.pub)
+sha1: $(cat id_rsa_3072.pub)
+hash: $(ssh-keygen -lf id_ecdsa_p256.pub | awk '{print $2}')
+SPEC
+ssh-keygen -k -f keys.krl -z 7 keys.krl.spec
+printf 'serial: 1-100\nserial: 150\nserial: 500-1000\n' > certs.krl.spec
+ssh-keygen -k -f certs.krl -s id_ed25519.pub -z 2 certs.krl.spec
+rm keys.krl.spec certs.krl.spec
+
+# SSHSIG detached signatures of the message `hello world\n`
+printf 'hello world\n' > msg
+ssh-keygen -Y sign -f id_dsa_1024 -n file msg
+mv msg.sig id_dsa_1024.sshsig
+ssh-keygen -Y sign -f id_ed25519 -n git -O hashalg=sha256 msg
+mv msg.sig id_ed25519.sshsig
+rm msg
@@ -0,0 +1,86 @@
+//! Key revocation list (KRL) tests.
+
+#![cfg(feature = "alloc")]
+
+use hex_literal::hex;
+use ssh_key::{krl::KrlBuilder, Error, PublicKey};
+
+/// Ed25519 OpenSSH-formatted public key
+const OSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519.pub");
+
+/// KRL revoking keys by blob, SHA-1 hash, and SHA-256 hash
+const KEYS_KRL_EXAMPLE: &[u8] = include_bytes!("examples/keys.krl");
+
+/// KRL revoking certificates issued by `id_ed25519` by serial number
+const CERTS_KRL_EXAMPLE: &[u8] = include_bytes!("examples/certs.krl");
+
+/// SHA-1 hash of the `id_rsa_3072` public key blob
+const RSA_3072_SHA1: [u8; 20] = hex!("49bb277e3b0844a57aef5da6c73a9082e35ece22");
+
+/// SHA-256 hash of the `id_ecdsa_p256` public key blob
+const ECDSA_P256_SHA256: [u8; 32] =
+    hex!("250e85574adfeeaa891d9a888f8ccd1fc795d2807c28b2a1f4fa61dc54c3f7c8");
+
+#[test]
+fn build_keys_krl() {
+    let key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+
+    let mut krl = KrlBuilder::new();
+    krl.version = 7;
+    krl.generated_date = 0x6acf5a04;
+    krl.revoke_key(&key.key_data).unwrap();
+    krl.revoke_key(&key.key_data).unwrap();
+    krl.revoke_sha1(RSA_3072_SHA1)
+        .revoke_sha256(ECDSA_P256_SHA256);
+
+    assert_eq!(KEYS_KRL_EXAMPLE, krl.to_bytes().unwrap().as_slice());
+}
+
+#[test]
+fn build_certs_krl() {
+    let ca_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+
+    let mut krl = KrlBuilder::new();
+    krl.version = 2;
+    krl.generated_date = 0x6acf5a09;
+    krl.revoke_serials(&ca_key.key_data, 600..=1000).unwrap();
+    krl.revoke_serial(&ca_key.key_data, 150).unwrap();
+    krl.revoke_serials(&ca_key.key_data, 1..=50).unwrap();
+    krl.revoke_serials(&ca_key.key_data, 51..=100).unwrap();
+    krl.revoke_serials(&ca_key.key_data, 500..=700).unwrap();
+    krl.revoke_serial(&ca_key.key_data, 42).unwrap();
+
+    assert_eq!(CERTS_KRL_EXAMPLE, krl.to_bytes().unwrap().as_slice());
+}
+
+#[test]
+fn reject_invalid_serials() {
+    let ca_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let mut krl = KrlBuilder::new();
+
+    assert_eq!(
+        krl.revoke_serial(&ca_key.key_data, 0).err(),
+        Some(Error::FormatEncoding)
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = 2..=1;
+    assert_eq!(
+        krl.revoke_serials(&ca_key.key_data, empty).err(),
+        Some(Error::FormatEncoding)
+    );
+    assert_eq!(krl, KrlBuilder::new());
+}
+
+#[cfg(feature = "fingerprint")]
+#[test]
+fn revoke_key_sha256() {
+    let key = PublicKey::from_openssh(include_str!("examples/id_ecdsa_p256.pub")).unwrap();
+
+    let mut by_key = KrlBuilder::new();
+    by_key.revoke_key_sha256(&key.key_data).unwrap();
+
+    let mut by_hash = KrlBuilder::new();
+    by_hash.revoke_sha256(ECDSA_P256_SHA256);
+
+    assert_eq!(by_hash, by_key);
+}
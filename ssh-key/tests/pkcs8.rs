@@ -0,0 +1,177 @@
+//! PKCS#8 private key tests.
+
+#![cfg(feature = "pkcs8")]
+
+use ssh_key::{
+    pkcs8::{DecodePrivateKey, EncodePrivateKey},
+    Algorithm, PrivateKey,
+};
+
+#[cfg(feature = "ecdsa")]
+use ssh_key::EcdsaCurve;
+
+/// Ed25519 OpenSSH-formatted private key
+const OSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519");
+
+/// ECDSA/P-256 OpenSSH-formatted private key
+#[cfg(feature = "ecdsa")]
+const OSSH_ECDSA_P256_EXAMPLE: &str = include_str!("examples/id_ecdsa_p256");
+
+/// ECDSA/P-384 OpenSSH-formatted private key
+#[cfg(feature = "ecdsa")]
+const OSSH_ECDSA_P384_EXAMPLE: &str = include_str!("examples/id_ecdsa_p384");
+
+/// RSA (3072-bit) OpenSSH-formatted private key
+#[cfg(all(feature = "alloc", feature = "pkcs1"))]
+const OSSH_RSA_3072_EXAMPLE: &str = include_str!("examples/id_rsa_3072");
+
+/// Ed25519 PKCS#8 v2 private key (including the public key)
+const PKCS8_ED25519_EXAMPLE: &[u8] = include_bytes!("examples/id_ed25519.pkcs8.der");
+
+/// Ed25519 PKCS#8 v1 private key (without the public key)
+const PKCS8_ED25519_V1_EXAMPLE: &[u8] = include_bytes!("examples/id_ed25519_v1.pkcs8.der");
+
+/// ECDSA/P-256 PKCS#8 private key
+#[cfg(feature = "ecdsa")]
+const PKCS8_ECDSA_P256_EXAMPLE: &[u8] = include_bytes!("examples/id_ecdsa_p256.pkcs8.der");
+
+/// ECDSA/P-384 PKCS#8 private key
+#[cfg(feature = "ecdsa")]
+const PKCS8_ECDSA_P384_EXAMPLE: &[u8] = include_bytes!("examples/id_ecdsa_p384.pkcs8.der");
+
+/// RSA (3072-bit) PKCS#8 private key
+#[cfg(all(feature = "alloc", feature = "pkcs1"))]
+const PKCS8_RSA_3072_EXAMPLE: &[u8] = include_bytes!("examples/id_rsa_3072.pkcs8.der");
+
+#[test]
+fn decode_ed25519_pkcs8() {
+    let key = PrivateKey::from_pkcs8_der(PKCS8_ED25519_EXAMPLE).unwrap();
+    let expected = PrivateKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    assert_eq!(Algorithm::Ed25519, key.algorithm());
+    assert_eq!(expected.public_key().key_data, key.public_key().key_data);
+    assert_eq!(
+        expected.key_data.ed25519().unwrap().private.as_ref(),
+        key.key_data.ed25519().unwrap().private.as_ref()
+    );
+}
+
+#[test]
+fn reject_ed25519_pkcs8_v1_without_public_key() {
+    assert_eq!(
+        PrivateKey::from_pkcs8_der(PKCS8_ED25519_V1_EXAMPLE).unwrap_err(),
+        ssh_key::pkcs8::Error::KeyMalformed
+    );
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_ecdsa_p256_pkcs8() {
+    let key = PrivateKey::from_pkcs8_der(PKCS8_ECDSA_P256_EXAMPLE).unwrap();
+    let expected = PrivateKey::from_openssh(OSSH_ECDSA_P256_EXAMPLE).unwrap();
+    assert_eq!(Algorithm::Ecdsa(EcdsaCurve::NistP256), key.algorithm());
+    assert_eq!(expected.public_key().key_data, key.public_key().key_data);
+    assert_eq!(
+        expected.key_data.ecdsa().unwrap().private_key_bytes(),
+        key.key_data.ecdsa().unwrap().private_key_bytes()
+    );
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_ecdsa_p384_pkcs8() {
+    let key = PrivateKey::from_pkcs8_der(PKCS8_ECDSA_P384_EXAMPLE).unwrap();
+    let expected = PrivateKey::from_openssh(OSSH_ECDSA_P384_EXAMPLE).unwrap();
+    assert_eq!(Algorithm::Ecdsa(EcdsaCurve::NistP384), key.algorithm());
+    assert_eq!(expected.public_key().key_data, key.public_key().key_data);
+    assert_eq!(
+        expected.key_data.ecdsa().unwrap().private_key_bytes(),
+        key.key_data.ecdsa().unwrap().private_key_bytes()
+    );
+}
+
+#[cfg(all(feature = "alloc", feature = "pkcs1"))]
+#[test]
+fn decode_rsa_3072_pkcs8() {
+    let key = PrivateKey::from_pkcs8_der(PKCS8_RSA_3072_EXAMPLE).unwrap();
+    let expected = PrivateKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    assert_eq!(Algorithm::Rsa, key.algorithm());
+    assert_eq!(expected.public_key().key_data, key.public_key().key_data);
+
+    let (rsa, expected_rsa) = (
+        key.key_data.rsa().unwrap(),
+        expected.key_data.rsa().unwrap(),
+    );
+    assert_eq!(expected_rsa.private.d, rsa.private.d);
+    assert_eq!(expected_rsa.private.iqmp, rsa.private.iqmp);
+    assert_eq!(expected_rsa.private.p, rsa.private.p);
+    assert_eq!(expected_rsa.private.q, rsa.private.q);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_ed25519_pkcs8() {
+    let key = PrivateKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let der = key.to_pkcs8_der().unwrap();
+    assert_eq!(PKCS8_ED25519_EXAMPLE, der.as_ref());
+}
+
+#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+#[test]
+fn encode_ecdsa_p256_pkcs8() {
+    let key = PrivateKey::from_openssh(OSSH_ECDSA_P256_EXAMPLE).unwrap();
+    let der = key.to_pkcs8_der().unwrap();
+    assert_eq!(PKCS8_ECDSA_P256_EXAMPLE, der.as_ref());
+}
+
+#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+#[test]
+fn encode_ecdsa_p384_pkcs8() {
+    let key = PrivateKey::from_openssh(OSSH_ECDSA_P384_EXAMPLE).unwrap();
+    let der = key.to_pkcs8_der().unwrap();
+    assert_eq!(PKCS8_ECDSA_P384_EXAMPLE, der.as_ref());
+}
+
+#[cfg(all(feature = "alloc", feature = "pkcs1"))]
+#[test]
+fn encode_rsa_3072_pkcs8() {
+    let key = PrivateKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    let der = key.to_pkcs8_der().unwrap();
+    assert_eq!(PKCS8_RSA_3072_EXAMPLE, der.as_ref());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn reject_dsa_pkcs8() {
+    let key = PrivateKey::from_openssh(include_str!("examples/id_dsa_1024")).unwrap();
+    assert_eq!(
+        key.to_pkcs8_der().unwrap_err(),
+        ssh_key::pkcs8::Error::KeyMalformed
+    );
+}
+
+#[test]
+fn pkcs8_error_conversions() {
+    use ssh_key::pkcs8::{der, spki};
+
+    fn parse(der_bytes: &[u8]) -> ssh_key::Result<PrivateKey> {
+        Ok(PrivateKey::from_pkcs8_der(der_bytes)?)
+    }
+
+    assert!(matches!(
+        parse(&PKCS8_ED25519_EXAMPLE[..10]),
+        Err(ssh_key::Error::Pkcs8(_))
+    ));
+
+    let err = der::Error::from(der::ErrorKind::Length {
+        tag: der::Tag::Sequence,
+    });
+    assert_eq!(
+        ssh_key::Error::from(err),
+        ssh_key::Error::Pkcs8(ssh_key::pkcs8::Error::Asn1(err))
+    );
+
+    assert_eq!(
+        ssh_key::Error::from(spki::Error::KeyMalformed),
+        ssh_key::Error::Pkcs8(ssh_key::pkcs8::Error::PublicKey(spki::Error::KeyMalformed))
+    );
+}
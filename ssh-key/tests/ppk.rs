@@ -0,0 +1,274 @@
+//! PuTTY private key (PPK) tests.
+
+#![cfg(feature = "ppk")]
+
+use base64ct::{Base64, Encoding};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+use ssh_key::{
+    ppk::{Argon2Params, Encryption, KeyDerivation, PpkFile, Version},
+    Algorithm, Error, PrivateKey,
+};
+
+/// DSA OpenSSH-formatted private key
+const OSSH_DSA_EXAMPLE: &str = include_str!("examples/id_dsa_1024");
+
+/// Ed25519 OpenSSH-formatted private key
+const OSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519");
+
+/// ECDSA/P-256 OpenSSH-formatted private key
+#[cfg(feature = "ecdsa")]
+const OSSH_ECDSA_P256_EXAMPLE: &str = include_str!("examples/id_ecdsa_p256");
+
+/// RSA (3072-bit) OpenSSH-formatted private key
+const OSSH_RSA_3072_EXAMPLE: &str = include_str!("examples/id_rsa_3072");
+
+/// RSA (2192-bit) OpenSSH-formatted private key, whose PPK private blob is a
+/// multiple of the AES block size
+const OSSH_RSA_2192_EXAMPLE: &str = include_str!("examples/id_rsa_2192");
+
+/// DSA PPK v2 private key
+const PPK_DSA_EXAMPLE: &str = include_str!("examples/id_dsa_1024.ppk");
+
+/// DSA PPK v2 private key encrypted with `aes256-cbc`
+const PPK_DSA_ENCRYPTED_EXAMPLE: &str = include_str!("examples/id_dsa_1024_aes256cbc.ppk");
+
+/// Ed25519 PPK v3 private key
+const PPK_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519.ppk");
+
+/// Ed25519 PPK v3 private key encrypted with `aes256-cbc` and Argon2id
+const PPK_ED25519_ENCRYPTED_EXAMPLE: &str = include_str!("examples/id_ed25519_aes256cbc.ppk");
+
+/// ECDSA/P-256 PPK v3 private key
+#[cfg(feature = "ecdsa")]
+const PPK_ECDSA_P256_EXAMPLE: &str = include_str!("examples/id_ecdsa_p256.ppk");
+
+/// RSA (3072-bit) PPK v2 private key
+const PPK_RSA_3072_EXAMPLE: &str = include_str!("examples/id_rsa_3072.ppk");
+
+/// Passphrase used for the encrypted examples
+const PASSPHRASE: &str = "hunter42";
+
+#[test]
+fn decode_dsa_ppk_v2() {
+    let ppk = PpkFile::decode(PPK_DSA_EXAMPLE).unwrap();
+    assert_eq!(Version::V2, ppk.version);
+    assert_eq!(Encryption::None, ppk.encryption);
+    assert!(!ppk.is_encrypted());
+
+    let key = ppk.decrypt("").unwrap();
+    let expected = PrivateKey::from_openssh(OSSH_DSA_EXAMPLE).unwrap();
+    assert_eq!(Algorithm::Dsa, key.algorithm());
+    assert_eq!(expected.public_key(), key.public_key());
+    assert_eq!(
+        expected.key_data.dsa().unwrap().private.as_bytes(),
+        key.key_data.dsa().unwrap().private.as_bytes()
+    );
+    assert_eq!("user@example.com", key.comment);
+}
+
+#[test]
+fn decode_dsa_ppk_v2_encrypted() {
+    let ppk = PpkFile::decode(PPK_DSA_ENCRYPTED_EXAMPLE).unwrap();
+    assert_eq!(Version::V2, ppk.version);
+    assert_eq!(Encryption::Aes256Cbc, ppk.encryption);
+    assert!(ppk.is_encrypted());
+
+    let expected = PrivateKey::from_openssh(OSSH_DSA_EXAMPLE).unwrap();
+    assert_eq!(expected.public_key(), ppk.public_key());
+
+    let key = ppk.decrypt(PASSPHRASE).unwrap();
+    assert_eq!(
+        expected.key_data.dsa().unwrap().private.as_bytes(),
+        key.key_data.dsa().unwrap().private.as_bytes()
+    );
+}
+
+#[test]
+fn decode_ed25519_ppk_v3() {
+    let key = PrivateKey::from_ppk(PPK_ED25519_EXAMPLE, "").unwrap();
+    let expected = PrivateKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    assert_eq!(Algorithm::Ed25519, key.algorithm());
+    assert_eq!(expected.public_key(), key.public_key());
+    assert_eq!(
+        expected.key_data.ed25519().unwrap().private.as_ref(),
+        key.key_data.ed25519().unwrap().private.as_ref()
+    );
+}
+
+#[test]
+fn decode_ed25519_ppk_v3_encrypted() {
+    let ppk = PpkFile::decode(PPK_ED25519_ENCRYPTED_EXAMPLE).unwrap();
+    assert_eq!(Version::V3, ppk.version);
+    assert_eq!(Encryption::Aes256Cbc, ppk.encryption);
+
+    let kdf = ppk.kdf.as_ref().unwrap();
+    assert_eq!(KeyDerivation::Argon2id, kdf.algorithm);
+    assert_eq!(8192, kdf.memory);
+    assert_eq!(13, kdf.passes);
+    assert_eq!(1, kdf.parallelism);
+
+    let key = ppk.decrypt(PASSPHRASE).unwrap();
+    let expected = PrivateKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    assert_eq!(
+        expected.key_data.ed25519().unwrap().private.as_ref(),
+        key.key_data.ed25519().unwrap().private.as_ref()
+    );
+}
+
+#[test]
+fn decode_ed25519_ppk_v3_wrong_passphrase() {
+    assert_eq!(
+        PrivateKey::from_ppk(PPK_ED25519_ENCRYPTED_EXAMPLE, "hunter2").unwrap_err(),
+        Error::Crypto
+    );
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_ecdsa_p256_ppk_v3() {
+    let key = PrivateKey::from_ppk(PPK_ECDSA_P256_EXAMPLE, "").unwrap();
+    let expected = PrivateKey::from_openssh(OSSH_ECDSA_P256_EXAMPLE).unwrap();
+    assert_eq!(expected.algorithm(), key.algorithm());
+    assert_eq!(expected.public_key(), key.public_key());
+    assert_eq!(
+        expected.key_data.ecdsa().unwrap().private_key_bytes(),
+        key.key_data.ecdsa().unwrap().private_key_bytes()
+    );
+}
+
+#[test]
+fn decode_rsa_3072_ppk_v2() {
+    let key = PrivateKey::from_ppk(PPK_RSA_3072_EXAMPLE, "").unwrap();
+    let expected = PrivateKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    assert_eq!(Algorithm::Rsa, key.algorithm());
+    assert_eq!(expected.public_key(), key.public_key());
+
+    let (rsa, expected_rsa) = (
+        key.key_data.rsa().unwrap(),
+        expected.key_data.rsa().unwrap(),
+    );
+    assert_eq!(expected_rsa.private.d, rsa.private.d);
+    assert_eq!(expected_rsa.private.iqmp, rsa.private.iqmp);
+    assert_eq!(expected_rsa.private.p, rsa.private.p);
+    assert_eq!(expected_rsa.private.q, rsa.private.q);
+}
+
+#[test]
+fn reject_tampered_comment() {
+    let tampered = PPK_ED25519_EXAMPLE.replace("user@example.com", "user@example.org");
+    assert_eq!(
+        PrivateKey::from_ppk(tampered, "").unwrap_err(),
+        Error::Crypto
+    );
+}
+
+#[test]
+fn reject_unknown_version() {
+    let input = PPK_ED25519_EXAMPLE.replace("PuTTY-User-Key-File-3", "PuTTY-User-Key-File-1");
+    assert_eq!(PpkFile::decode(input).unwrap_err(), Error::FormatEncoding);
+}
+
+#[test]
+fn decode_crlf_line_endings() {
+    let input = PPK_ED25519_EXAMPLE.replace('\n', "\r\n");
+    assert!(PrivateKey::from_ppk(input, "").is_ok());
+}
+
+#[test]
+fn encode_ed25519_ppk_v3() {
+    let key = PrivateKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    assert_eq!(PPK_ED25519_EXAMPLE, key.to_ppk().unwrap());
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn encode_ecdsa_p256_ppk_v3() {
+    let key = PrivateKey::from_openssh(OSSH_ECDSA_P256_EXAMPLE).unwrap();
+    assert_eq!(PPK_ECDSA_P256_EXAMPLE, key.to_ppk().unwrap());
+}
+
+#[test]
+fn encode_rsa_3072_ppk_v3() {
+    let key = PrivateKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    let ppk = PpkFile::new(&key).unwrap();
+    assert_eq!(Version::V3, ppk.version);
+    assert_eq!(Encryption::None, ppk.encryption);
+
+    let decoded = PrivateKey::from_ppk(ppk.encode().unwrap(), "").unwrap();
+    assert_eq!(key.public_key(), decoded.public_key());
+    assert_eq!(
+        key.key_data.rsa().unwrap().private.d,
+        decoded.key_data.rsa().unwrap().private.d
+    );
+}
+
+#[test]
+fn encode_dsa_ppk_v3_encrypted() {
+    let key = PrivateKey::from_openssh(OSSH_DSA_EXAMPLE).unwrap();
+    let encoded = key
+        .to_ppk_encrypted(ChaCha8Rng::from_seed([0; 32]), PASSPHRASE)
+        .unwrap();
+
+    let ppk = PpkFile::decode(&encoded).unwrap();
+    assert_eq!(Version::V3, ppk.version);
+    assert_eq!(Encryption::Aes256Cbc, ppk.encryption);
+
+    let kdf = ppk.kdf.as_ref().unwrap();
+    assert_eq!(KeyDerivation::Argon2id, kdf.algorithm);
+    assert_eq!(Argon2Params::DEFAULT_MEMORY, kdf.memory);
+    assert_eq!(Argon2Params::SALT_SIZE, kdf.salt.len());
+
+    assert_eq!(ppk.decrypt("hunter2").unwrap_err(), Error::Crypto);
+
+    let decoded = ppk.decrypt(PASSPHRASE).unwrap();
+    assert_eq!(key.public_key(), decoded.public_key());
+    assert_eq!(
+        key.key_data.dsa().unwrap().private.as_bytes(),
+        decoded.key_data.dsa().unwrap().private.as_bytes()
+    );
+}
+
+#[test]
+fn encrypted_padding() {
+    for (example, blob_len, padded_len) in [
+        (OSSH_ED25519_EXAMPLE, 36, 48),
+        (OSSH_RSA_2192_EXAMPLE, 704, 704),
+    ] {
+        let key = PrivateKey::from_openssh(example).unwrap();
+        assert_eq!(private_blob(&key.to_ppk().unwrap()).len(), blob_len);
+
+        let encrypted = key
+            .to_ppk_encrypted(ChaCha8Rng::from_seed([0; 32]), PASSPHRASE)
+            .unwrap();
+        assert_eq!(private_blob(&encrypted).len(), padded_len);
+
+        let decoded = PrivateKey::from_ppk(&encrypted, PASSPHRASE).unwrap();
+        assert_eq!(key.public_key(), decoded.public_key());
+    }
+}
+
+#[test]
+fn reencode_ppk_files() {
+    for example in [
+        PPK_DSA_EXAMPLE,
+        PPK_DSA_ENCRYPTED_EXAMPLE,
+        PPK_ED25519_EXAMPLE,
+        PPK_ED25519_ENCRYPTED_EXAMPLE,
+        PPK_RSA_3072_EXAMPLE,
+    ] {
+        let ppk = PpkFile::decode(example).unwrap();
+        assert_eq!(example, ppk.encode().unwrap());
+    }
+}
+
+/// Decode the `Private-Lines` block of a PPK file.
+fn private_blob(ppk: &str) -> Vec<u8> {
+    let mut lines = ppk.lines();
+    let nlines = lines
+        .find_map(|line| line.strip_prefix("Private-Lines: "))
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    Base64::decode_vec(&lines.take(nlines).collect::<String>()).unwrap()
+}
@@ -1,11 +1,20 @@
 //! SSH public key tests.
 
 use hex_literal::hex;
-use ssh_key::{Algorithm, PublicKey};
+use ssh_key::{
+    public::{BoundedRsaPublicKey, Ed25519PublicKey, KeyData, KeyDataRef, PublicKeyRef},
+    Algorithm, Encode, Error, PublicKey,
+};
 
 #[cfg(feature = "ecdsa")]
 use ssh_key::EcdsaCurve;
 
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+use {
+    sha2::{Sha256, Sha512},
+    ssh_key::Fingerprint,
+};
+
 /// DSA OpenSSH-formatted public key
 #[cfg(feature = "alloc")]
 const OSSH_DSA_EXAMPLE: &str = include_str!("examples/id_dsa_1024.pub");
@@ -26,13 +35,20 @@ const OSSH_ECDSA_P521_EXAMPLE: &str = include_str!("examples/id_ecdsa_p521.pub")
 const OSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519.pub");
 
 /// RSA (3072-bit) OpenSSH-formatted public key
-#[cfg(feature = "alloc")]
 const OSSH_RSA_3072_EXAMPLE: &str = include_str!("examples/id_rsa_3072.pub");
 
 /// RSA (4096-bit) OpenSSH-formatted public key
 #[cfg(feature = "alloc")]
 const OSSH_RSA_4096_EXAMPLE: &str = include_str!("examples/id_rsa_4096.pub");
 
+/// Security key (FIDO/U2F) ECDSA/P-256 OpenSSH-formatted public key
+#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+const OSSH_SK_ECDSA_P256_EXAMPLE: &str = include_str!("examples/id_sk_ecdsa_p256.pub");
+
+/// Security key (FIDO/U2F) Ed25519 OpenSSH-formatted public key
+#[cfg(feature = "alloc")]
+const OSSH_SK_ED25519_EXAMPLE: &str = include_str!("examples/id_sk_ed25519.pub");
+
 #[cfg(feature = "alloc")]
 #[test]
 fn decode_dsa_openssh() {
@@ -156,6 +172,162 @@ fn decode_ed25519_openssh() {
 
     #[cfg(feature = "alloc")]
     assert_eq!("user@example.com", ossh_key.comment);
+    assert_eq!("user@example.com", ossh_key.comment());
+}
+
+#[test]
+fn encode_ed25519_key_data_to_slice() {
+    let key_data = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE)
+        .unwrap()
+        .key_data;
+    let encoded_len = key_data.encoded_len().unwrap();
+    assert_eq!(encoded_len, 4 + 11 + 4 + 32);
+
+    let mut buf = [0u8; 64];
+    let mut out = &mut buf[..];
+    key_data.encode(&mut out).unwrap();
+    assert_eq!(out.len(), buf.len() - encoded_len);
+    assert_eq!(&buf[..4], &[0, 0, 0, 11]);
+    assert_eq!(&buf[4..15], b"ssh-ed25519");
+    assert_eq!(
+        &buf[19..encoded_len],
+        &hex!("b33eaef37ea2df7caa010defdea34e241f65f1b529a4f43ed14327f5c54aab62")
+    );
+
+    let mut short_buf = [0u8; 50];
+    assert_eq!(key_data.encode(&mut &mut short_buf[..]), Err(Error::Length));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_rsa_4096_key_data_to_vec() {
+    let key_data = PublicKey::from_openssh(OSSH_RSA_4096_EXAMPLE)
+        .unwrap()
+        .key_data;
+    let encoded = key_data.encode_vec().unwrap();
+    assert_eq!(encoded.len(), key_data.encoded_len().unwrap());
+    assert_eq!(encoded.capacity(), encoded.len());
+}
+
+#[test]
+fn decode_ed25519_key_data_from_bytes() {
+    let mut blob = [0u8; 52];
+    blob[..4].copy_from_slice(&[0, 0, 0, 11]);
+    blob[4..15].copy_from_slice(b"ssh-ed25519");
+    blob[15..19].copy_from_slice(&[0, 0, 0, 32]);
+    blob[19..51].copy_from_slice(&hex!(
+        "b33eaef37ea2df7caa010defdea34e241f65f1b529a4f43ed14327f5c54aab62"
+    ));
+
+    let key_data = KeyData::from_bytes(&blob[..51]).unwrap();
+    assert_eq!(
+        key_data,
+        PublicKey::from_openssh(OSSH_ED25519_EXAMPLE)
+            .unwrap()
+            .key_data
+    );
+
+    assert_eq!(KeyData::from_bytes(&blob), Err(Error::Length));
+    assert_eq!(KeyData::from_bytes(&blob[..50]), Err(Error::Length));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn key_data_bytes_round_trip() {
+    for openssh in [
+        OSSH_DSA_EXAMPLE,
+        OSSH_ED25519_EXAMPLE,
+        OSSH_RSA_4096_EXAMPLE,
+        OSSH_SK_ED25519_EXAMPLE,
+    ] {
+        let key_data = PublicKey::from_openssh(openssh).unwrap().key_data;
+        let bytes = key_data.to_bytes().unwrap();
+        assert_eq!(KeyData::from_bytes(&bytes).unwrap(), key_data);
+    }
+}
+
+#[test]
+fn new_public_key_with_comment() {
+    let key_data = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE)
+        .unwrap()
+        .key_data;
+    let public_key = PublicKey::new(key_data, "user@example.com").unwrap();
+
+    assert_eq!("user@example.com", public_key.comment());
+    assert_eq!(
+        PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap(),
+        public_key
+    );
+}
+
+#[test]
+fn key_eq_ignores_comment() {
+    let public_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let other_comment = PublicKey::new(public_key.key_data.clone(), "other@example.com").unwrap();
+    let other_key_data = KeyData::Ed25519(Ed25519PublicKey([0u8; 32]));
+    let other_key = PublicKey::new(other_key_data, "user@example.com").unwrap();
+
+    assert_ne!(public_key, other_comment);
+    assert!(public_key.key_eq(&other_comment));
+    assert!(!public_key.key_eq(&other_key));
+}
+
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn reject_overlong_comment_heapless() {
+    let comment = "x".repeat(257);
+    let input = OSSH_ED25519_EXAMPLE.replace("user@example.com", &comment);
+    assert_eq!(Err(Error::Length), PublicKey::from_openssh(&input));
+
+    let comment = "x".repeat(256);
+    let input = OSSH_ED25519_EXAMPLE.replace("user@example.com", &comment);
+    assert_eq!(comment, PublicKey::from_openssh(&input).unwrap().comment());
+}
+
+#[test]
+fn decode_ed25519_openssh_ref() {
+    let key = PublicKeyRef::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let expected = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+
+    assert_eq!(Algorithm::Ed25519, key.algorithm());
+    assert_eq!(expected.key_data.ed25519(), key.key_data.ed25519());
+    assert_eq!(expected.key_data, key.key_data.to_key_data().unwrap());
+    assert_eq!("user@example.com", key.comment);
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_ecdsa_p256_openssh_ref() {
+    let key = PublicKeyRef::from_openssh(OSSH_ECDSA_P256_EXAMPLE).unwrap();
+    let expected = PublicKey::from_openssh(OSSH_ECDSA_P256_EXAMPLE).unwrap();
+
+    assert_eq!(Algorithm::Ecdsa(EcdsaCurve::NistP256), key.algorithm());
+    assert_eq!(expected.key_data.ecdsa(), key.key_data.ecdsa());
+    assert_eq!("user@example.com", key.comment);
+}
+
+#[test]
+fn decode_rsa_3072_openssh_ref() {
+    let key = PublicKeyRef::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    assert_eq!(Algorithm::Rsa, key.algorithm());
+    assert!(matches!(key.key_data, KeyDataRef::Other { .. }));
+
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        PublicKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap(),
+        key.to_public_key().unwrap()
+    );
+    #[cfg(not(feature = "alloc"))]
+    assert_eq!(Err(Error::Algorithm), key.key_data.to_key_data());
+}
+
+#[test]
+fn reject_openssh_ref_algorithm_mismatch() {
+    let input = OSSH_ED25519_EXAMPLE.replacen("ssh-ed25519", "ssh-rsa", 1);
+    assert_eq!(
+        Err(Error::Algorithm),
+        PublicKeyRef::from_openssh(&input).map(|_| ())
+    );
 }
 
 #[cfg(feature = "alloc")]
@@ -184,6 +356,31 @@ fn decode_rsa_3072_openssh() {
     assert_eq!("user@example.com", ossh_key.comment);
 }
 
+#[test]
+fn decode_rsa_3072_openssh_heapless() {
+    let rsa_key = BoundedRsaPublicKey::<385>::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    assert_eq!(&hex!("010001"), rsa_key.e.as_bytes());
+    assert_eq!(385, rsa_key.n.as_bytes().len());
+    assert_eq!(384, rsa_key.n.as_positive_bytes().unwrap().len());
+
+    #[cfg(feature = "alloc")]
+    {
+        let ossh_key = PublicKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+        let expected = ossh_key.key_data.rsa().unwrap();
+        assert_eq!(expected, &ssh_key::public::RsaPublicKey::from(&rsa_key));
+        assert_eq!(rsa_key, BoundedRsaPublicKey::try_from(expected).unwrap());
+    }
+
+    assert_eq!(
+        BoundedRsaPublicKey::<384>::from_openssh(OSSH_RSA_3072_EXAMPLE),
+        Err(Error::Length)
+    );
+    assert_eq!(
+        BoundedRsaPublicKey::<385>::from_openssh(OSSH_ED25519_EXAMPLE),
+        Err(Error::Algorithm)
+    );
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn decode_rsa_4096_openssh() {
@@ -213,6 +410,43 @@ fn decode_rsa_4096_openssh() {
     assert_eq!("user@example.com", ossh_key.comment);
 }
 
+#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+#[test]
+fn decode_sk_ecdsa_p256_openssh() {
+    let ossh_key = PublicKey::from_openssh(OSSH_SK_ECDSA_P256_EXAMPLE).unwrap();
+    assert_eq!(
+        Algorithm::SkEcdsaSha2NistP256,
+        ossh_key.key_data.algorithm()
+    );
+
+    let sk_key = ossh_key.key_data.sk_ecdsa_p256().unwrap();
+    assert_eq!(EcdsaCurve::NistP256, sk_key.curve());
+    assert_eq!(
+        &hex!(
+            "047c1fd8730ce53457be8d924098ec3648830f92aa8a2363ac656fdd4521fa6313e511f1891b4e9e5aaf8e14
+             2d06ad15a66a4257f3f051d84e8a0e2f91ba807047"
+        ),
+        sk_key.ec_point.as_bytes(),
+    );
+    assert_eq!("ssh:", sk_key.application);
+    assert_eq!("user@example.com", ossh_key.comment);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_sk_ed25519_openssh() {
+    let ossh_key = PublicKey::from_openssh(OSSH_SK_ED25519_EXAMPLE).unwrap();
+    assert_eq!(Algorithm::SkEd25519, ossh_key.key_data.algorithm());
+
+    let sk_key = ossh_key.key_data.sk_ed25519().unwrap();
+    assert_eq!(
+        &hex!("b33eaef37ea2df7caa010defdea34e241f65f1b529a4f43ed14327f5c54aab62"),
+        sk_key.public_key.as_ref(),
+    );
+    assert_eq!("ssh:", sk_key.application);
+    assert_eq!("user@example.com", ossh_key.comment);
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn encode_dsa_openssh() {
@@ -255,9 +489,119 @@ fn encode_rsa_3072_openssh() {
     assert_eq!(OSSH_RSA_3072_EXAMPLE.trim_end(), &ossh_key.to_string());
 }
 
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+#[test]
+fn fingerprint_ed25519() {
+    let ossh_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+
+    // Expected values from `ssh-keygen -l -E sha256` and `-E sha512`
+    assert_eq!(
+        "UCUiLr7Pjs9wFFJMDByLgc3NrtdU344OgUM45wZPcIQ",
+        ossh_key.fingerprint_base64::<Sha256>().unwrap()
+    );
+    assert_eq!(
+        "8Hm4eNTiYk9l6WR85IqY+UK4AXajRf5auLD/QIxCZQLwGpDiEDiXZ0ZttPuhyG8IHsWbt2zu8G0+G6VIVXoHhA",
+        ossh_key.fingerprint_base64::<Sha512>().unwrap()
+    );
+}
+
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+#[test]
+fn fingerprint_rsa_3072() {
+    let ossh_key = PublicKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    assert_eq!(
+        "Fmxts/GcV77PakFnf1Ueki5mpU4ZjUQWGRjZGAo3n/I",
+        ossh_key.fingerprint_base64::<Sha256>().unwrap()
+    );
+}
+
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+#[test]
+fn fingerprint_trait() {
+    fn pin<K: Fingerprint>(key: &K) -> String {
+        key.fingerprint_base64::<Sha256>().unwrap()
+    }
+
+    let ossh_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let expected = "UCUiLr7Pjs9wFFJMDByLgc3NrtdU344OgUM45wZPcIQ";
+    assert_eq!(expected, pin(&ossh_key));
+    assert_eq!(expected, pin(&ossh_key.key_data));
+    assert_eq!(
+        Fingerprint::fingerprint::<Sha256>(&ossh_key).unwrap(),
+        ossh_key.fingerprint::<Sha256>().unwrap()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn reject_forged_length_prefix() {
+    // `ssh-rsa` followed by an `mpint` claiming to be 1 MiB long
+    let input = "ssh-rsa AAAAB3NzaC1yc2EAD///AQ== user@example.com";
+    assert_eq!(
+        ssh_key::Error::Length,
+        PublicKey::from_openssh(input).unwrap_err()
+    );
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn encode_rsa_4096_openssh() {
     let ossh_key = PublicKey::from_openssh(OSSH_RSA_4096_EXAMPLE).unwrap();
     assert_eq!(OSSH_RSA_4096_EXAMPLE.trim_end(), &ossh_key.to_string());
 }
+
+#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+#[test]
+fn encode_sk_ecdsa_p256_openssh() {
+    let ossh_key = PublicKey::from_openssh(OSSH_SK_ECDSA_P256_EXAMPLE).unwrap();
+    assert_eq!(OSSH_SK_ECDSA_P256_EXAMPLE.trim_end(), &ossh_key.to_string());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_sk_ed25519_openssh() {
+    let ossh_key = PublicKey::from_openssh(OSSH_SK_ED25519_EXAMPLE).unwrap();
+    assert_eq!(OSSH_SK_ED25519_EXAMPLE.trim_end(), &ossh_key.to_string());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_openssh_lines() {
+    let input = format!(
+        "# key inventory\n\n{}   \n  # indented comment\nssh-ed25519 AAAA\n{}",
+        OSSH_ED25519_EXAMPLE.trim_end(),
+        OSSH_RSA_3072_EXAMPLE
+    );
+
+    let keys = PublicKey::from_openssh_lines(input.as_bytes()).collect::<Vec<_>>();
+    assert_eq!(3, keys.len());
+    assert_eq!(
+        &PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap(),
+        keys[0].as_ref().unwrap()
+    );
+    assert!(keys[1].is_err());
+    assert_eq!(Algorithm::Rsa, keys[2].as_ref().unwrap().algorithm());
+
+    // Invalid UTF-8 is an I/O error which ends iteration
+    let mut keys = PublicKey::from_openssh_lines(&b"\xff\nssh-ed25519 AAAA\n"[..]);
+    assert!(matches!(keys.next(), Some(Err(Error::Io(_)))));
+    assert!(keys.next().is_none());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn openssh_file_round_trip() {
+    let key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let path = std::env::temp_dir().join(format!(
+        "ssh-key-test-{}-id_ed25519.pub",
+        std::process::id()
+    ));
+
+    key.write_openssh_file(&path).unwrap();
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let decoded = PublicKey::read_openssh_file(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(contents, OSSH_ED25519_EXAMPLE);
+    assert_eq!(decoded.unwrap(), key);
+}
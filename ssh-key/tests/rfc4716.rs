@@ -0,0 +1,122 @@
+//! RFC4716 ("SSH2 PUBLIC KEY") tests.
+
+#![cfg(feature = "alloc")]
+
+use ssh_key::{rfc4716::Ssh2PublicKey, Error, PublicKey};
+
+/// Ed25519 OpenSSH-formatted public key
+const OSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519.pub");
+
+/// ECDSA/P-256 OpenSSH-formatted public key
+#[cfg(feature = "ecdsa")]
+const OSSH_ECDSA_P256_EXAMPLE: &str = include_str!("examples/id_ecdsa_p256.pub");
+
+/// RSA (3072-bit) OpenSSH-formatted public key
+const OSSH_RSA_3072_EXAMPLE: &str = include_str!("examples/id_rsa_3072.pub");
+
+/// Ed25519 RFC4716 public key
+const RFC4716_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519.rfc4716");
+
+/// ECDSA/P-256 RFC4716 public key with `Subject`, continued `Comment`, and
+/// private-use headers
+#[cfg(feature = "ecdsa")]
+const RFC4716_ECDSA_P256_EXAMPLE: &str = include_str!("examples/id_ecdsa_p256.rfc4716");
+
+/// RSA (3072-bit) RFC4716 public key
+const RFC4716_RSA_3072_EXAMPLE: &str = include_str!("examples/id_rsa_3072.rfc4716");
+
+#[test]
+fn decode_ed25519_rfc4716() {
+    let key = PublicKey::from_rfc4716(RFC4716_ED25519_EXAMPLE).unwrap();
+    let expected = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    assert_eq!(expected, key);
+}
+
+#[test]
+fn decode_rsa_3072_rfc4716() {
+    let key = PublicKey::from_rfc4716(RFC4716_RSA_3072_EXAMPLE).unwrap();
+    let expected = PublicKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    assert_eq!(expected, key);
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_ecdsa_p256_rfc4716_with_headers() {
+    let file = Ssh2PublicKey::decode(RFC4716_ECDSA_P256_EXAMPLE).unwrap();
+    let expected = PublicKey::from_openssh(OSSH_ECDSA_P256_EXAMPLE).unwrap();
+    assert_eq!(expected.key_data, file.key_data);
+    assert_eq!(Some("user"), file.subject.as_deref());
+    assert_eq!(
+        Some("This is my public key for use on servers which I don't like."),
+        file.comment.as_deref()
+    );
+    assert_eq!(
+        &[(
+            "x-command".to_owned(),
+            "/home/me/bin/lock-in-guest.sh".to_owned()
+        )],
+        file.headers.as_slice()
+    );
+}
+
+#[test]
+fn decode_crlf_line_endings() {
+    let input = RFC4716_ED25519_EXAMPLE.replace('\n', "\r\n");
+    assert!(PublicKey::from_rfc4716(input).is_ok());
+}
+
+#[test]
+fn decode_case_insensitive_tags() {
+    let input = RFC4716_ED25519_EXAMPLE.replace("Comment:", "COMMENT:");
+    let key = PublicKey::from_rfc4716(input).unwrap();
+    assert_eq!("user@example.com", key.comment);
+}
+
+#[test]
+fn reject_missing_end_marker() {
+    let input = RFC4716_ED25519_EXAMPLE.replace("---- END SSH2 PUBLIC KEY ----", "");
+    assert_eq!(PublicKey::from_rfc4716(input).unwrap_err(), Error::Length);
+}
+
+#[test]
+fn reject_duplicate_comment() {
+    let input = RFC4716_ED25519_EXAMPLE.replace(
+        "Comment: \"user@example.com\"",
+        "Comment: one\nComment: two",
+    );
+    assert_eq!(
+        PublicKey::from_rfc4716(input).unwrap_err(),
+        Error::FormatEncoding
+    );
+}
+
+#[test]
+fn encode_ed25519_rfc4716() {
+    let key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    assert_eq!(RFC4716_ED25519_EXAMPLE, key.to_rfc4716().unwrap());
+}
+
+#[test]
+fn encode_rsa_3072_rfc4716() {
+    let key = PublicKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    assert_eq!(RFC4716_RSA_3072_EXAMPLE, key.to_rfc4716().unwrap());
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn encode_long_headers() {
+    let mut file = Ssh2PublicKey::decode(RFC4716_ECDSA_P256_EXAMPLE).unwrap();
+    file.comment = Some("é".repeat(100));
+
+    let encoded = file.encode().unwrap();
+    assert!(encoded.lines().all(|line| line.len() <= 72));
+    assert_eq!(file, Ssh2PublicKey::decode(&encoded).unwrap());
+}
+
+#[test]
+fn reject_invalid_header_tag() {
+    let mut file = Ssh2PublicKey::decode(RFC4716_ED25519_EXAMPLE).unwrap();
+    file.headers
+        .push(("x command".to_owned(), "foo".to_owned()));
+    assert_eq!(file.encode().unwrap_err(), Error::CharacterEncoding);
+}
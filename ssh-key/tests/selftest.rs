@@ -0,0 +1,8 @@
+//! Runtime self-test tests.
+
+#![cfg(feature = "selftest")]
+
+#[test]
+fn selftest() {
+    ssh_key::selftest().unwrap();
+}
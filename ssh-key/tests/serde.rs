@@ -0,0 +1,64 @@
+//! Serde tests.
+
+#![cfg(all(feature = "alloc", feature = "serde"))]
+
+use ssh_key::{LineEnding, PrivateKey, PublicKey};
+
+/// Ed25519 OpenSSH-formatted private key
+const OSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519");
+
+/// Ed25519 OpenSSH-formatted public key
+const OSSH_ED25519_PUB_EXAMPLE: &str = include_str!("examples/id_ed25519.pub");
+
+#[test]
+fn serde_json_public_key() {
+    let public_key = PublicKey::from_openssh(OSSH_ED25519_PUB_EXAMPLE).unwrap();
+    let json = serde_json::to_string(&public_key).unwrap();
+    assert_eq!(
+        json,
+        serde_json::to_string(OSSH_ED25519_PUB_EXAMPLE.trim_end()).unwrap()
+    );
+    assert_eq!(public_key, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn serde_json_private_key() {
+    let private_key = PrivateKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let json = serde_json::to_string(&private_key).unwrap();
+
+    let pem = private_key.to_openssh(LineEnding::LF).unwrap();
+    assert_eq!(json, serde_json::to_string(pem.as_str()).unwrap());
+
+    let decoded: PrivateKey = serde_json::from_str(&json).unwrap();
+    assert_eq!(private_key.public_key(), decoded.public_key());
+    assert_eq!(pem, decoded.to_openssh(LineEnding::LF).unwrap());
+}
+
+#[test]
+fn serde_cbor_public_key() {
+    let public_key = PublicKey::from_openssh(OSSH_ED25519_PUB_EXAMPLE).unwrap();
+    let cbor = serde_cbor::to_vec(&public_key).unwrap();
+    assert_eq!(public_key, serde_cbor::from_slice(&cbor).unwrap());
+}
+
+#[test]
+fn serde_cbor_private_key() {
+    let private_key = PrivateKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let cbor = serde_cbor::to_vec(&private_key).unwrap();
+
+    let bytes = private_key.to_bytes().unwrap();
+    assert_eq!(
+        cbor,
+        serde_cbor::to_vec(&serde_cbor::Value::Bytes(bytes.to_vec())).unwrap()
+    );
+
+    let decoded: PrivateKey = serde_cbor::from_slice(&cbor).unwrap();
+    assert_eq!(private_key.public_key(), decoded.public_key());
+    assert_eq!(bytes, decoded.to_bytes().unwrap());
+
+    // Trailing data after the key isn't allowed
+    let mut bytes = bytes.to_vec();
+    bytes.push(0);
+    let cbor = serde_cbor::to_vec(&serde_cbor::Value::Bytes(bytes)).unwrap();
+    assert!(serde_cbor::from_slice::<PrivateKey>(&cbor).is_err());
+}
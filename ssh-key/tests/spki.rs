@@ -0,0 +1,110 @@
+//! SPKI public key tests.
+
+#![cfg(feature = "pkcs8")]
+
+use hex_literal::hex;
+use ssh_key::{
+    pkcs8::{spki, DecodePublicKey},
+    public::KeyData,
+    Algorithm, PublicKey,
+};
+
+#[cfg(feature = "alloc")]
+use ssh_key::pkcs8::EncodePublicKey;
+
+/// Ed25519 OpenSSH-formatted public key
+const OSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519.pub");
+
+/// ECDSA/P-256 OpenSSH-formatted public key
+#[cfg(feature = "ecdsa")]
+const OSSH_ECDSA_P256_EXAMPLE: &str = include_str!("examples/id_ecdsa_p256.pub");
+
+/// RSA (3072-bit) OpenSSH-formatted public key
+#[cfg(all(feature = "alloc", feature = "pkcs1"))]
+const OSSH_RSA_3072_EXAMPLE: &str = include_str!("examples/id_rsa_3072.pub");
+
+/// Ed25519 SPKI public key, as defined in RFC8410
+const SPKI_ED25519_EXAMPLE: &[u8] = &hex!(
+    "302a300506032b6570032100b33eaef37ea2df7caa010defdea34e241f65f1b529a4f43ed14327f5c54aab62"
+);
+
+/// ECDSA/P-256 SPKI public key
+#[cfg(feature = "ecdsa")]
+const SPKI_ECDSA_P256_EXAMPLE: &[u8] = include_bytes!("examples/id_ecdsa_p256.spki.der");
+
+/// RSA (3072-bit) SPKI public key
+#[cfg(all(feature = "alloc", feature = "pkcs1"))]
+const SPKI_RSA_3072_EXAMPLE: &[u8] = include_bytes!("examples/id_rsa_3072.spki.der");
+
+#[test]
+fn decode_ed25519_spki() {
+    let key = PublicKey::from_public_key_der(SPKI_ED25519_EXAMPLE).unwrap();
+    let expected = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    assert_eq!(Algorithm::Ed25519, key.algorithm());
+    assert_eq!(expected.key_data, key.key_data);
+    assert_eq!("", key.comment());
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_ecdsa_p256_spki() {
+    let key_data = KeyData::from_public_key_der(SPKI_ECDSA_P256_EXAMPLE).unwrap();
+    let expected = PublicKey::from_openssh(OSSH_ECDSA_P256_EXAMPLE).unwrap();
+    assert_eq!(expected.key_data, key_data);
+}
+
+#[cfg(all(feature = "alloc", feature = "pkcs1"))]
+#[test]
+fn decode_rsa_3072_spki() {
+    let key_data = KeyData::from_public_key_der(SPKI_RSA_3072_EXAMPLE).unwrap();
+    let expected = PublicKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    assert_eq!(expected.key_data, key_data);
+}
+
+#[test]
+fn reject_unknown_algorithm_spki() {
+    // Ed448 public key
+    let mut der = [0u8; 69];
+    der[..12].copy_from_slice(&hex!("3043300506032b6571033a00"));
+
+    assert_eq!(
+        KeyData::from_public_key_der(&der).unwrap_err(),
+        spki::Error::OidUnknown {
+            oid: "1.3.101.113".parse().unwrap()
+        }
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_ed25519_spki() {
+    let key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let der = key.to_public_key_der().unwrap();
+    assert_eq!(SPKI_ED25519_EXAMPLE, der.as_ref());
+}
+
+#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+#[test]
+fn encode_ecdsa_p256_spki() {
+    let key = PublicKey::from_openssh(OSSH_ECDSA_P256_EXAMPLE).unwrap();
+    let der = key.key_data.to_public_key_der().unwrap();
+    assert_eq!(SPKI_ECDSA_P256_EXAMPLE, der.as_ref());
+}
+
+#[cfg(all(feature = "alloc", feature = "pkcs1"))]
+#[test]
+fn encode_rsa_3072_spki() {
+    let key = PublicKey::from_openssh(OSSH_RSA_3072_EXAMPLE).unwrap();
+    let der = key.key_data.to_public_key_der().unwrap();
+    assert_eq!(SPKI_RSA_3072_EXAMPLE, der.as_ref());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn reject_dsa_spki() {
+    let key = PublicKey::from_openssh(include_str!("examples/id_dsa_1024.pub")).unwrap();
+    assert_eq!(
+        key.to_public_key_der().unwrap_err(),
+        spki::Error::KeyMalformed
+    );
+}
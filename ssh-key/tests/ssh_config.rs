@@ -0,0 +1,80 @@
+//! Tests for parsing `ssh_config` files.
+
+#![cfg(feature = "alloc")]
+
+use ssh_key::{Error, SshConfig};
+
+const SSH_CONFIG_EXAMPLE: &str = include_str!("examples/ssh_config");
+
+#[test]
+fn resolve_example() {
+    let config = SshConfig::new(SSH_CONFIG_EXAMPLE).unwrap();
+
+    let github = config.resolve("github.com");
+    assert_eq!(github.host_name, Some("fallback.example.com"));
+    assert_eq!(github.host_key_alias, None);
+    assert_eq!(
+        github.identity_files,
+        [
+            "~/.ssh/id_global",
+            "~/.ssh/id_ed25519_git",
+            "~/.ssh/id with spaces"
+        ]
+    );
+
+    let bastion = config.resolve("BASTION");
+    assert_eq!(bastion.host_name, Some("bastion.example.com"));
+    assert_eq!(bastion.host_key_alias, Some("bastion-key"));
+    assert_eq!(bastion.known_hosts_name("bastion"), "bastion-key");
+
+    let www = config.resolve("www.example.com");
+    assert_eq!(
+        www.identity_files,
+        [
+            "~/.ssh/id_global",
+            "~/.ssh/id_example",
+            "~/.ssh/id with spaces"
+        ]
+    );
+    assert_eq!(
+        www.known_hosts_name("www.example.com"),
+        "fallback.example.com"
+    );
+
+    // Negated pattern, and `Match` sections are ignored
+    let internal = config.resolve("internal.example.com");
+    assert_eq!(
+        internal.identity_files,
+        ["~/.ssh/id_global", "~/.ssh/id with spaces"]
+    );
+}
+
+#[test]
+fn resolve_empty() {
+    let config = SshConfig::new("").unwrap();
+    let host_config = config.resolve("example.com");
+    assert_eq!(host_config.host_name, None);
+    assert!(host_config.identity_files.is_empty());
+    assert_eq!(host_config.known_hosts_name("example.com"), "example.com");
+}
+
+#[test]
+fn reject_malformed() {
+    assert_eq!(SshConfig::new("Host\n"), Err(Error::FormatEncoding));
+    assert_eq!(SshConfig::new("HostName a b\n"), Err(Error::FormatEncoding));
+    assert_eq!(
+        SshConfig::new("IdentityFile \"unterminated\n"),
+        Err(Error::FormatEncoding)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read_example_file() {
+    let identity_files = SshConfig::read_file("./tests/examples/ssh_config", |config| {
+        Ok(config.resolve("gitlab.com").identity_files.len())
+    })
+    .unwrap();
+
+    assert_eq!(identity_files, 3);
+}
@@ -0,0 +1,168 @@
+//! `SSHSIG` detached signature tests.
+
+#![cfg(feature = "sshsig")]
+
+use sha2::{Digest, Sha512};
+use ssh_key::{
+    private::KeypairData, sshsig::SshSig, Algorithm, Error, HashAlg, LineEnding, PrivateKey, Result,
+};
+
+/// DSA OpenSSH-formatted private key
+const OPENSSH_DSA_EXAMPLE: &str = include_str!("examples/id_dsa_1024");
+
+/// Ed25519 OpenSSH-formatted private key
+const OPENSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519");
+
+/// `ssh-keygen -Y sign -n file` signature of [`MSG`] by the DSA key
+const SSHSIG_DSA_EXAMPLE: &str = include_str!("examples/id_dsa_1024.sshsig");
+
+/// `ssh-keygen -Y sign -n git -O hashalg=sha256` signature of [`MSG`] by the
+/// Ed25519 key
+const SSHSIG_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519.sshsig");
+
+/// Signed message
+const MSG: &[u8] = b"hello world\n";
+
+/// Stand-in for a real Ed25519 signer, which this crate doesn't provide:
+/// computes SHA-512 over the private key followed by the signed data.
+fn test_signer(keypair: &KeypairData, signed_data: &[u8]) -> Result<(Algorithm, Vec<u8>)> {
+    let private_key = keypair.ed25519().ok_or(Error::Algorithm)?.private.as_ref();
+    let signature = Sha512::new()
+        .chain_update(private_key)
+        .chain_update(signed_data)
+        .finalize();
+    Ok((Algorithm::Ed25519, signature.to_vec()))
+}
+
+#[test]
+fn decode_ed25519_sshsig() {
+    let signature = SshSig::from_pem(SSHSIG_ED25519_EXAMPLE).unwrap();
+    let public_key = PrivateKey::from_openssh(OPENSSH_ED25519_EXAMPLE)
+        .unwrap()
+        .public_key();
+
+    assert_eq!(&public_key.key_data, signature.public_key());
+    assert_eq!("git", signature.namespace());
+    assert_eq!(HashAlg::Sha256, signature.hash_alg());
+    assert_eq!(Algorithm::Ed25519, signature.algorithm());
+    assert_eq!(64, signature.signature().len());
+    assert_eq!(
+        SSHSIG_ED25519_EXAMPLE,
+        signature.to_pem(LineEnding::LF).unwrap()
+    );
+
+    let expected_data = SshSig::signed_data("git", HashAlg::Sha256, MSG).unwrap();
+    public_key
+        .verify_detached_with_namespace("git", MSG, &signature, |sig, signed_data| {
+            assert_eq!(&signature, sig);
+            assert_eq!(expected_data, signed_data);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn decode_dsa_sshsig() {
+    let signature = SshSig::from_pem(SSHSIG_DSA_EXAMPLE).unwrap();
+    assert_eq!("file", signature.namespace());
+    assert_eq!(HashAlg::Sha512, signature.hash_alg());
+    assert_eq!(Algorithm::Dsa, signature.algorithm());
+    assert_eq!(
+        SSHSIG_DSA_EXAMPLE,
+        signature.to_pem(LineEnding::LF).unwrap()
+    );
+}
+
+#[test]
+fn reject_malformed_sshsig() {
+    let pem = SshSig::from_pem(SSHSIG_ED25519_EXAMPLE)
+        .unwrap()
+        .to_pem(LineEnding::LF)
+        .unwrap();
+
+    assert!(SshSig::from_pem(pem.replace("SSH SIGNATURE", "OPENSSH PRIVATE KEY")).is_err());
+    assert!(SshSig::from_pem(pem.replacen("U1NIU0lH", "U1NIU0lI", 1)).is_err());
+}
+
+#[test]
+fn reject_empty_namespace() {
+    assert_eq!(
+        Err(Error::Namespace),
+        SshSig::signed_data("", HashAlg::Sha512, MSG)
+    );
+}
+
+#[test]
+fn sign_and_verify() {
+    let private_key = PrivateKey::from_openssh(OPENSSH_ED25519_EXAMPLE).unwrap();
+    let public_key = private_key.public_key();
+    let verifier = |sig: &SshSig, signed_data: &[u8]| {
+        let (_, expected) = test_signer(&private_key.key_data, signed_data)?;
+        if sig.signature() == expected {
+            Ok(())
+        } else {
+            Err(Error::Crypto)
+        }
+    };
+
+    let signature = private_key
+        .sign_detached_with_namespace("file", HashAlg::Sha512, MSG, test_signer)
+        .unwrap();
+    assert_eq!(&public_key.key_data, signature.public_key());
+    assert_eq!("file", signature.namespace());
+    assert_eq!(HashAlg::Sha512, signature.hash_alg());
+    assert_eq!(Algorithm::Ed25519, signature.algorithm());
+
+    let pem = signature.to_pem(LineEnding::LF).unwrap();
+    let signature = SshSig::from_pem(pem).unwrap();
+    public_key
+        .verify_detached_with_namespace("file", MSG, &signature, verifier)
+        .unwrap();
+
+    assert_eq!(
+        Err(Error::Crypto),
+        public_key.verify_detached_with_namespace("file", b"goodbye world\n", &signature, verifier)
+    );
+    assert_eq!(
+        Err(Error::Namespace),
+        public_key.verify_detached_with_namespace("git", MSG, &signature, verifier)
+    );
+
+    let other_key = PrivateKey::from_openssh(OPENSSH_DSA_EXAMPLE)
+        .unwrap()
+        .public_key();
+    assert_eq!(
+        Err(Error::PublicKeyMismatch),
+        other_key.verify_detached_with_namespace("file", MSG, &signature, verifier)
+    );
+}
+
+#[test]
+fn reject_invalid_signer_output() {
+    let private_key = PrivateKey::from_openssh(OPENSSH_ED25519_EXAMPLE).unwrap();
+    assert_eq!(
+        Err(Error::Namespace),
+        private_key
+            .sign_detached_with_namespace("", HashAlg::Sha512, MSG, test_signer)
+            .map(|_| ())
+    );
+
+    // Signature algorithm doesn't match the key
+    assert_eq!(
+        Err(Error::Algorithm),
+        private_key
+            .sign_detached_with_namespace("file", HashAlg::Sha512, MSG, |_, _| {
+                Ok((Algorithm::Dsa, vec![0; 40]))
+            })
+            .map(|_| ())
+    );
+
+    // Signer errors are returned as-is
+    let dsa_key = PrivateKey::from_openssh(OPENSSH_DSA_EXAMPLE).unwrap();
+    assert_eq!(
+        Err(Error::Algorithm),
+        dsa_key
+            .sign_detached_with_namespace("file", HashAlg::Sha512, MSG, test_signer)
+            .map(|_| ())
+    );
+}
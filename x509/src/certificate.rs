@@ -1,10 +1,14 @@
-use crate::{name::Name, time::Validity};
+use crate::{
+    ext::{ExtensionItem, TypedExtension},
+    name::Name,
+    time::Validity,
+};
 
 use alloc::vec::Vec;
 
 use const_oid::AssociatedOid;
 use der::asn1::{BitString, UIntBytes};
-use der::{Decodable, Enumerated, Error, ErrorKind, Newtype, Sequence};
+use der::{Decodable, Decoder, Enumerated, Error, ErrorKind, Newtype, Sequence};
 use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
 
 /// Certificate `Version` as defined in [RFC 5280 Section 4.1].
@@ -120,6 +124,26 @@ impl<'a> TbsCertificate<'a> {
             .filter(|e| e.extn_id == T::OID)
             .map(|e| Ok((e.critical, T::from_der(e.extn_value)?)))
     }
+
+    /// Iterates over all extensions
+    ///
+    /// Each item contains the `extnID`, the `critical` flag, and the raw
+    /// `extnValue`, along with the decoded extension if it is known to this
+    /// crate. This makes it possible to reject unrecognized critical
+    /// extensions as required by [RFC 5280 Section 4.2] while still handling
+    /// known extensions as their associated types.
+    ///
+    /// Returns a decoding error for known extensions which fail to decode.
+    ///
+    /// [RFC 5280 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2
+    pub fn extensions_iter<'b: 'a>(
+        &'b self,
+    ) -> impl 'b + Iterator<Item = Result<ExtensionItem<'a>, Error>> {
+        self.extensions.as_deref().unwrap_or(&[]).iter().map(|e| {
+            let typed = TypedExtension::decode(e.extn_id, e.extn_value)?;
+            Ok((e.extn_id, e.critical, e.extn_value, typed))
+        })
+    }
 }
 
 /// X.509 certificates are defined in [RFC 5280 Section 4.1].
@@ -141,6 +165,20 @@ pub struct Certificate<'a> {
     pub signature: BitString<'a>,
 }
 
+impl<'a> Certificate<'a> {
+    /// Parse a DER-encoded certificate, accepting validity times encoded as
+    /// a `GeneralizedTime` with fractional seconds or an offset from UTC.
+    ///
+    /// RFC 5280 forbids these encodings, so [`Certificate::from_der`] rejects
+    /// them, but they're emitted by some non-conforming CAs. The times are
+    /// normalized to UTC, as with [`Decoder::with_lenient_generalized_time`].
+    pub fn from_der_lenient(bytes: &'a [u8]) -> der::Result<Self> {
+        let mut decoder = Decoder::new(bytes)?.with_lenient_generalized_time(true);
+        let result = decoder.decode()?;
+        decoder.finish(result)
+    }
+}
+
 /// `PkiPath` as defined by X.509 and referenced by [RFC 6066].
 ///
 /// This contains a series of certificates in validation order from the
@@ -1,14 +1,18 @@
 //! Certificate Revocation List types
 
-use crate::ext::Extensions;
+use crate::ext::pkix::{crl::CrlNumber, IssuingDistributionPoint};
+use crate::ext::{Extension, Extensions};
 use crate::name::Name;
 use crate::time::Time;
 use crate::Version;
 
 use alloc::vec::Vec;
+use core::{cmp::Ordering, fmt};
 
+use const_oid::db::rfc5280::{ID_CE_CRL_NUMBER, ID_CE_ISSUING_DISTRIBUTION_POINT};
+use const_oid::ObjectIdentifier;
 use der::asn1::{BitString, UIntBytes};
-use der::Sequence;
+use der::{Decodable, ErrorKind, Sequence};
 use spki::AlgorithmIdentifier;
 
 /// `CertificateList` as defined in [RFC 5280 Section 5.1].
@@ -84,3 +88,129 @@ pub struct TbsCertList<'a> {
     #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
     pub crl_extensions: Option<Extensions<'a>>,
 }
+
+impl<'a> TbsCertList<'a> {
+    /// Decode the CRL number extension, if present.
+    ///
+    /// Returns an error if the extension is present more than once.
+    pub fn crl_number(&self) -> der::Result<Option<CrlNumber<'a>>> {
+        self.find_extension(ID_CE_CRL_NUMBER)?
+            .map(|e| CrlNumber::from_der(e.extn_value))
+            .transpose()
+    }
+
+    /// Decode the issuing distribution point extension, which defines the
+    /// scope of the CRL, if present.
+    ///
+    /// Returns an error if the extension is present more than once.
+    pub fn issuing_distribution_point(&self) -> der::Result<Option<IssuingDistributionPoint<'a>>> {
+        self.find_extension(ID_CE_ISSUING_DISTRIBUTION_POINT)?
+            .map(|e| IssuingDistributionPoint::from_der(e.extn_value))
+            .transpose()
+    }
+
+    /// Check that this CRL can replace `prior`, a CRL previously accepted
+    /// for the same scope.
+    ///
+    /// As required by [RFC 5280 Section 5.2.3], the CRL number must strictly
+    /// increase between successive CRLs. The issuer names and issuing
+    /// distribution points must also be identical (compared by their DER
+    /// encodings). Caches of revocation information can use this to detect
+    /// a CRL being rolled back to an older version or substituted with a CRL
+    /// of a different issuer or scope.
+    ///
+    /// This does not verify the signature of either CRL.
+    ///
+    /// [RFC 5280 Section 5.2.3]: https://datatracker.ietf.org/doc/html/rfc5280#section-5.2.3
+    pub fn check_successor(&self, prior: &TbsCertList<'_>) -> Result<(), CrlSequenceError> {
+        if self.issuer != prior.issuer {
+            return Err(CrlSequenceError::IssuerMismatch);
+        }
+
+        if self.issuing_distribution_point()? != prior.issuing_distribution_point()? {
+            return Err(CrlSequenceError::ScopeMismatch);
+        }
+
+        match (self.crl_number()?, prior.crl_number()?) {
+            (Some(number), Some(prior_number)) => {
+                if cmp_uint(number.0, prior_number.0) != Ordering::Greater {
+                    return Err(CrlSequenceError::CrlNumberNotIncreasing);
+                }
+            }
+            _ => return Err(CrlSequenceError::MissingCrlNumber),
+        }
+
+        Ok(())
+    }
+
+    /// Find the CRL extension with the given `extnID`.
+    fn find_extension(&self, oid: ObjectIdentifier) -> der::Result<Option<&Extension<'a>>> {
+        let mut iter = self
+            .crl_extensions
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .filter(|e| e.extn_id == oid);
+
+        match (iter.next(), iter.next()) {
+            (extension, None) => Ok(extension),
+            (_, Some(_)) => Err(ErrorKind::Failed.into()),
+        }
+    }
+}
+
+/// Error returned by [`TbsCertList::check_successor`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CrlSequenceError {
+    /// The CRL number did not increase.
+    CrlNumberNotIncreasing,
+
+    /// An extension of either CRL could not be decoded.
+    Der(der::Error),
+
+    /// The CRLs were issued by different issuers.
+    IssuerMismatch,
+
+    /// Either CRL lacks a CRL number.
+    MissingCrlNumber,
+
+    /// The CRLs have different issuing distribution points.
+    ScopeMismatch,
+}
+
+impl fmt::Display for CrlSequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CrlNumberNotIncreasing => f.write_str("CRL number did not increase"),
+            Self::Der(err) => write!(f, "CRL extension error: {}", err),
+            Self::IssuerMismatch => f.write_str("CRL issuer does not match prior CRL"),
+            Self::MissingCrlNumber => f.write_str("CRL number missing"),
+            Self::ScopeMismatch => {
+                f.write_str("CRL issuing distribution point does not match prior CRL")
+            }
+        }
+    }
+}
+
+impl From<der::Error> for CrlSequenceError {
+    fn from(err: der::Error) -> CrlSequenceError {
+        CrlSequenceError::Der(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CrlSequenceError {}
+
+/// Compare two unsigned big endian integers.
+fn cmp_uint(a: UIntBytes<'_>, b: UIntBytes<'_>) -> Ordering {
+    let a = trim_leading_zeroes(a.as_bytes());
+    let b = trim_leading_zeroes(b.as_bytes());
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Strip any leading zero bytes.
+fn trim_leading_zeroes(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
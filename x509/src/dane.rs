@@ -0,0 +1,149 @@
+//! DNS-Based Authentication of Named Entities (DANE)
+
+use crate::Certificate;
+use alloc::vec::Vec;
+use core::fmt;
+use der::Encodable;
+
+#[cfg(feature = "digest")]
+use {der::ErrorKind, digest::Digest};
+
+/// Certificate usage field of a TLSA record as defined in
+/// [RFC 6698 Section 2.1.1].
+///
+/// [RFC 6698 Section 2.1.1]: https://datatracker.ietf.org/doc/html/rfc6698#section-2.1.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum CertificateUsage {
+    PkixTa = 0,
+    PkixEe = 1,
+    DaneTa = 2,
+    DaneEe = 3,
+}
+
+/// Selector field of a TLSA record as defined in [RFC 6698 Section 2.1.2].
+///
+/// [RFC 6698 Section 2.1.2]: https://datatracker.ietf.org/doc/html/rfc6698#section-2.1.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Selector {
+    /// Full certificate: the DER encoding of the `Certificate`.
+    Cert = 0,
+
+    /// The DER encoding of the certificate's `SubjectPublicKeyInfo`.
+    Spki = 1,
+}
+
+/// Matching type field of a TLSA record as defined in
+/// [RFC 6698 Section 2.1.3].
+///
+/// [RFC 6698 Section 2.1.3]: https://datatracker.ietf.org/doc/html/rfc6698#section-2.1.3
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum MatchingType {
+    /// Exact match on the selected content.
+    Full = 0,
+
+    /// SHA-256 hash of the selected content.
+    Sha256 = 1,
+
+    /// SHA-512 hash of the selected content.
+    Sha512 = 2,
+}
+
+impl MatchingType {
+    /// Size of the certificate association data for this matching type, if
+    /// it is a hash.
+    pub fn output_size(self) -> Option<usize> {
+        match self {
+            Self::Full => None,
+            Self::Sha256 => Some(32),
+            Self::Sha512 => Some(64),
+        }
+    }
+}
+
+/// TLSA resource record data as defined in [RFC 6698 Section 2.1].
+///
+/// The [`fmt::Display`] impl renders the presentation format used in zone
+/// files, e.g. `3 1 1 0D6F...`.
+///
+/// [RFC 6698 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc6698#section-2.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tlsa {
+    /// Certificate usage.
+    pub usage: CertificateUsage,
+
+    /// Selector.
+    pub selector: Selector,
+
+    /// Matching type.
+    pub matching_type: MatchingType,
+
+    /// Certificate association data.
+    pub data: Vec<u8>,
+}
+
+impl Tlsa {
+    /// Create a TLSA record containing the selected content of `cert`
+    /// verbatim, i.e. with [`MatchingType::Full`].
+    pub fn new(
+        cert: &Certificate<'_>,
+        usage: CertificateUsage,
+        selector: Selector,
+    ) -> der::Result<Self> {
+        Ok(Self {
+            usage,
+            selector,
+            matching_type: MatchingType::Full,
+            data: select(cert, selector)?,
+        })
+    }
+
+    /// Create a TLSA record containing a hash of the selected content of
+    /// `cert` computed with the digest function `D`.
+    ///
+    /// `D` must be the digest function identified by `matching_type`. Only
+    /// its output size is checked.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    pub fn new_hashed<D: Digest>(
+        cert: &Certificate<'_>,
+        usage: CertificateUsage,
+        selector: Selector,
+        matching_type: MatchingType,
+    ) -> der::Result<Self> {
+        if matching_type.output_size() != Some(<D as Digest>::output_size()) {
+            return Err(ErrorKind::Failed.into());
+        }
+
+        Ok(Self {
+            usage,
+            selector,
+            matching_type,
+            data: D::digest(select(cert, selector)?).to_vec(),
+        })
+    }
+}
+
+impl fmt::Display for Tlsa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} ",
+            self.usage as u8, self.selector as u8, self.matching_type as u8
+        )?;
+
+        for byte in &self.data {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Encode the content of `cert` chosen by `selector`.
+fn select(cert: &Certificate<'_>, selector: Selector) -> der::Result<Vec<u8>> {
+    match selector {
+        Selector::Cert => cert.to_vec(),
+        Selector::Spki => cert.tbs_certificate.subject_public_key_info.to_vec(),
+    }
+}
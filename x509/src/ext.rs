@@ -1,6 +1,7 @@
 //! Standardized X.509 Certificate Extensions
 
-use der::Sequence;
+use const_oid::AssociatedOid;
+use der::{Decodable, Sequence};
 use spki::ObjectIdentifier;
 
 pub mod pkix;
@@ -42,3 +43,87 @@ pub struct Extension<'a> {
 ///
 /// [RFC 5280 Section 4.1.2.9]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.9
 pub type Extensions<'a> = alloc::vec::Vec<Extension<'a>>;
+
+/// Item yielded by [`TbsCertificate::extensions_iter`][`crate::TbsCertificate::extensions_iter`].
+///
+/// Contains the `extnID`, the `critical` flag, the raw `extnValue`, and the
+/// decoded extension if it is known to this crate.
+pub type ExtensionItem<'a> = (ObjectIdentifier, bool, &'a [u8], Option<TypedExtension<'a>>);
+
+macro_rules! typed_extensions {
+    ($($(#[$attr:meta])* $variant:ident($ty:ty)),+ $(,)?) => {
+        /// Certificate extension decoded into the type identified by its
+        /// `extnID`.
+        ///
+        /// Covers the certificate extensions defined in [RFC 5280 Section 4.2].
+        ///
+        /// [RFC 5280 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2
+        #[derive(Clone, Debug, Eq, PartialEq)]
+        #[non_exhaustive]
+        pub enum TypedExtension<'a> {
+            $($(#[$attr])* $variant($ty),)+
+        }
+
+        impl<'a> TypedExtension<'a> {
+            /// Decode the `extnValue` of an extension with the given `extnID`.
+            ///
+            /// Returns `Ok(None)` if the `extnID` isn't one of the extensions
+            /// known to this crate.
+            pub fn decode(extn_id: ObjectIdentifier, extn_value: &'a [u8]) -> der::Result<Option<Self>> {
+                $(
+                    if extn_id == <$ty as AssociatedOid>::OID {
+                        return <$ty>::from_der(extn_value).map(|ext| Some(Self::$variant(ext)));
+                    }
+                )+
+
+                Ok(None)
+            }
+
+            /// Get the `extnID` of this extension.
+            pub fn extn_id(&self) -> ObjectIdentifier {
+                match self {
+                    $(Self::$variant(_) => <$ty as AssociatedOid>::OID,)+
+                }
+            }
+        }
+    };
+}
+
+typed_extensions! {
+    /// Authority key identifier.
+    AuthorityKeyIdentifier(pkix::AuthorityKeyIdentifier<'a>),
+    /// Subject key identifier.
+    SubjectKeyIdentifier(pkix::SubjectKeyIdentifier<'a>),
+    /// Key usage.
+    KeyUsage(pkix::KeyUsage),
+    /// Private key usage period.
+    PrivateKeyUsagePeriod(pkix::PrivateKeyUsagePeriod),
+    /// Certificate policies.
+    CertificatePolicies(pkix::CertificatePolicies<'a>),
+    /// Policy mappings.
+    PolicyMappings(pkix::PolicyMappings),
+    /// Subject alternative name.
+    SubjectAltName(pkix::SubjectAltName<'a>),
+    /// Issuer alternative name.
+    IssuerAltName(pkix::IssuerAltName<'a>),
+    /// Subject directory attributes.
+    SubjectDirectoryAttributes(pkix::SubjectDirectoryAttributes<'a>),
+    /// Basic constraints.
+    BasicConstraints(pkix::BasicConstraints),
+    /// Name constraints.
+    NameConstraints(pkix::NameConstraints<'a>),
+    /// Policy constraints.
+    PolicyConstraints(pkix::PolicyConstraints),
+    /// Extended key usage.
+    ExtendedKeyUsage(pkix::ExtendedKeyUsage),
+    /// CRL distribution points.
+    CrlDistributionPoints(pkix::CrlDistributionPoints<'a>),
+    /// Inhibit anyPolicy.
+    InhibitAnyPolicy(pkix::InhibitAnyPolicy),
+    /// Freshest CRL.
+    FreshestCrl(pkix::FreshestCrl<'a>),
+    /// Authority information access.
+    AuthorityInfoAccess(pkix::AuthorityInfoAccessSyntax<'a>),
+    /// Subject information access.
+    SubjectInfoAccess(pkix::SubjectInfoAccessSyntax<'a>),
+}
@@ -0,0 +1,142 @@
+//! Certificate identifiers
+
+use crate::crl::TbsCertList;
+use crate::name::Name;
+use crate::{Certificate, TbsCertificate};
+
+use der::asn1::{OctetString, UIntBytes};
+use der::Sequence;
+use spki::AlgorithmIdentifier;
+
+#[cfg(feature = "digest")]
+use {
+    der::Encodable,
+    digest::{Digest, Output},
+};
+
+/// `IssuerAndSerialNumber` as defined in [RFC 5652 Section 10.2.4].
+///
+/// ```text
+/// IssuerAndSerialNumber ::= SEQUENCE {
+///     issuer Name,
+///     serialNumber CertificateSerialNumber
+/// }
+/// ```
+///
+/// [RFC 5652 Section 10.2.4]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct IssuerAndSerialNumber<'a> {
+    pub issuer: Name<'a>,
+    pub serial_number: UIntBytes<'a>,
+}
+
+impl<'a> IssuerAndSerialNumber<'a> {
+    /// Does this identifier refer to the given certificate?
+    pub fn matches(&self, cert: &TbsCertificate<'_>) -> bool {
+        self.issuer == cert.issuer && self.serial_number == cert.serial_number
+    }
+
+    /// Is the certificate referred to by this identifier listed as revoked
+    /// in the given CRL?
+    ///
+    /// This only compares names and serial numbers: the caller is responsible
+    /// for verifying the CRL's signature.
+    pub fn is_revoked_by(&self, crl: &TbsCertList<'_>) -> bool {
+        self.issuer == crl.issuer
+            && crl
+                .revoked_certificates
+                .iter()
+                .flatten()
+                .any(|revoked| revoked.serial_number == self.serial_number)
+    }
+}
+
+impl<'a> From<&TbsCertificate<'a>> for IssuerAndSerialNumber<'a> {
+    fn from(cert: &TbsCertificate<'a>) -> Self {
+        Self {
+            issuer: cert.issuer.clone(),
+            serial_number: cert.serial_number,
+        }
+    }
+}
+
+impl<'a> From<&Certificate<'a>> for IssuerAndSerialNumber<'a> {
+    fn from(cert: &Certificate<'a>) -> Self {
+        Self::from(&cert.tbs_certificate)
+    }
+}
+
+/// `CertID` as defined in [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// CertID ::= SEQUENCE {
+///     hashAlgorithm       AlgorithmIdentifier,
+///     issuerNameHash      OCTET STRING, -- Hash of issuer's DN
+///     issuerKeyHash       OCTET STRING, -- Hash of issuer's public key
+///     serialNumber        CertificateSerialNumber
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct CertId<'a> {
+    pub hash_algorithm: AlgorithmIdentifier<'a>,
+    pub issuer_name_hash: OctetString<'a>,
+    pub issuer_key_hash: OctetString<'a>,
+    pub serial_number: UIntBytes<'a>,
+}
+
+impl<'a> CertId<'a> {
+    /// Create a new [`CertId`] from precomputed issuer hashes.
+    pub fn new(
+        hash_algorithm: AlgorithmIdentifier<'a>,
+        issuer_name_hash: &'a [u8],
+        issuer_key_hash: &'a [u8],
+        serial_number: UIntBytes<'a>,
+    ) -> der::Result<Self> {
+        Ok(Self {
+            hash_algorithm,
+            issuer_name_hash: OctetString::new(issuer_name_hash)?,
+            issuer_key_hash: OctetString::new(issuer_key_hash)?,
+            serial_number,
+        })
+    }
+
+    /// Compute the `issuerNameHash` and `issuerKeyHash` for the given issuer
+    /// certificate using the digest function `D`.
+    ///
+    /// The name hash is computed over the DER encoding of the issuer's subject
+    /// name, and the key hash over the value of the BIT STRING
+    /// `subjectPublicKey` (excluding the tag, length, and number of unused
+    /// bits), per RFC 6960.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    pub fn hash_issuer<D: Digest>(
+        issuer: &TbsCertificate<'_>,
+    ) -> der::Result<(Output<D>, Output<D>)> {
+        let name_hash = D::digest(issuer.subject.to_vec()?);
+        let key_hash = D::digest(issuer.subject_public_key_info.subject_public_key);
+        Ok((name_hash, key_hash))
+    }
+
+    /// Does this identifier refer to the certificate with the given serial
+    /// number, issued by `issuer`?
+    ///
+    /// `D` must be the digest function identified by `hash_algorithm`;
+    /// this is not checked.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    pub fn matches<D: Digest>(
+        &self,
+        issuer: &TbsCertificate<'_>,
+        serial_number: UIntBytes<'_>,
+    ) -> der::Result<bool> {
+        let (name_hash, key_hash) = Self::hash_issuer::<D>(issuer)?;
+
+        Ok(self.issuer_name_hash.as_bytes() == name_hash.as_slice()
+            && self.issuer_key_hash.as_bytes() == key_hash.as_slice()
+            && self.serial_number.as_bytes() == serial_number.as_bytes())
+    }
+}
@@ -19,11 +19,22 @@ pub use der;
 pub mod anchor;
 pub mod attr;
 pub mod crl;
+pub mod dane;
 pub mod ext;
+pub mod id;
 pub mod name;
 pub mod request;
 pub mod time;
+pub mod transparency;
 
 mod certificate;
+mod recovery;
+mod serial_number;
 
 pub use certificate::{Certificate, PkiPath, TbsCertificate, Version};
+pub use recovery::RecoveredCertificate;
+pub use serial_number::SerialNumber;
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+pub use rand_core;
@@ -1,10 +1,15 @@
 //! Name-related definitions as defined in X.501 (and updated by RFC 5280).
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use crate::attr::AttributeTypeAndValue;
 
-use der::{asn1::SetOfVec, Decodable, Encodable, Newtype};
+use const_oid::db::rfc4519::{
+    COMMON_NAME, COUNTRY_NAME, DOMAIN_COMPONENT, LOCALITY_NAME, ORGANIZATIONAL_UNIT_NAME,
+    ORGANIZATION_NAME, ST,
+};
+use der::asn1::{Any, Ia5String, ObjectIdentifier, PrintableString, SetOfVec};
+use der::{Decodable, Encodable, ErrorKind, Newtype, Tag};
 
 /// X.501 Name as defined in [RFC 5280 Section 4.1.2.4]. X.501 Name is used to represent distinguished names.
 ///
@@ -161,3 +166,138 @@ impl core::fmt::Display for RelativeDistinguishedName<'_> {
         Ok(())
     }
 }
+
+/// Builder for X.501 [`Name`]s.
+///
+/// Each attribute is added as its own RelativeDistinguishedName unless it is
+/// added with [`NameBuilder::and`], which groups it with the previous one
+/// (i.e. `+` in [RFC 4514] strings).
+///
+/// String values are encoded as `PrintableString` when possible and as
+/// `UTF8String` otherwise, except for `countryName` (which must be a two
+/// character `PrintableString`) and `domainComponent` (an `IA5String`).
+///
+/// ```
+/// use der::{Decodable, Encodable};
+/// use x509::name::{Name, NameBuilder};
+///
+/// let builder = NameBuilder::new()
+///     .country("US")
+///     .organization("Example Org")
+///     .common_name("example.com");
+///
+/// let name = builder.build()?;
+/// assert_eq!(name.to_string(), "C=US,O=Example Org,CN=example.com");
+///
+/// let der = name.to_vec()?;
+/// assert_eq!(Name::from_der(&der)?, name);
+/// # Ok::<(), der::Error>(())
+/// ```
+///
+/// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NameBuilder {
+    rdns: Vec<Vec<(ObjectIdentifier, String)>>,
+}
+
+impl NameBuilder {
+    /// Create a builder for an empty name.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an attribute as a new RelativeDistinguishedName.
+    pub fn attribute(mut self, oid: ObjectIdentifier, value: impl Into<String>) -> Self {
+        self.rdns.push(Vec::new());
+        self.and(oid, value)
+    }
+
+    /// Add an attribute to the previous RelativeDistinguishedName, creating
+    /// a multi-valued RDN.
+    ///
+    /// Starts a new RelativeDistinguishedName if there is no previous one.
+    pub fn and(mut self, oid: ObjectIdentifier, value: impl Into<String>) -> Self {
+        match self.rdns.last_mut() {
+            Some(rdn) => rdn.push((oid, value.into())),
+            None => self.rdns.push(alloc::vec![(oid, value.into())]),
+        }
+
+        self
+    }
+
+    /// Add a `commonName` (`CN`) attribute.
+    pub fn common_name(self, value: impl Into<String>) -> Self {
+        self.attribute(COMMON_NAME, value)
+    }
+
+    /// Add a `countryName` (`C`) attribute.
+    pub fn country(self, value: impl Into<String>) -> Self {
+        self.attribute(COUNTRY_NAME, value)
+    }
+
+    /// Add a `domainComponent` (`DC`) attribute.
+    pub fn domain_component(self, value: impl Into<String>) -> Self {
+        self.attribute(DOMAIN_COMPONENT, value)
+    }
+
+    /// Add a `localityName` (`L`) attribute.
+    pub fn locality(self, value: impl Into<String>) -> Self {
+        self.attribute(LOCALITY_NAME, value)
+    }
+
+    /// Add an `organizationName` (`O`) attribute.
+    pub fn organization(self, value: impl Into<String>) -> Self {
+        self.attribute(ORGANIZATION_NAME, value)
+    }
+
+    /// Add an `organizationalUnitName` (`OU`) attribute.
+    pub fn organizational_unit(self, value: impl Into<String>) -> Self {
+        self.attribute(ORGANIZATIONAL_UNIT_NAME, value)
+    }
+
+    /// Add a `stateOrProvinceName` (`ST`) attribute.
+    pub fn state_or_province(self, value: impl Into<String>) -> Self {
+        self.attribute(ST, value)
+    }
+
+    /// Build the [`Name`], which borrows its attribute values from this
+    /// builder.
+    ///
+    /// It can be used directly in other structures (e.g. a `TbsCertificate`)
+    /// or encoded as DER with [`Encodable::to_vec`].
+    pub fn build(&self) -> Result<Name<'_>, der::Error> {
+        self.rdns
+            .iter()
+            .map(|rdn| {
+                let atvs = rdn
+                    .iter()
+                    .map(|(oid, value)| {
+                        Ok(AttributeTypeAndValue {
+                            oid: *oid,
+                            value: Any::new(value_tag(oid, value)?, value.as_bytes())?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, der::Error>>()?;
+
+                Ok(RelativeDistinguishedName(atvs.try_into()?))
+            })
+            .collect::<Result<Vec<_>, der::Error>>()
+            .map(RdnSequence)
+    }
+}
+
+/// Select the string type used to encode the value of an attribute.
+fn value_tag(oid: &ObjectIdentifier, value: &str) -> Result<Tag, der::Error> {
+    let printable = PrintableString::new(value).is_ok();
+
+    match *oid {
+        COUNTRY_NAME if printable && value.len() == 2 => Ok(Tag::PrintableString),
+        COUNTRY_NAME => Err(ErrorKind::Value {
+            tag: Tag::PrintableString,
+        }
+        .into()),
+        DOMAIN_COMPONENT => Ia5String::new(value).map(|_| Tag::Ia5String),
+        _ if printable => Ok(Tag::PrintableString),
+        _ => Ok(Tag::Utf8String),
+    }
+}
@@ -0,0 +1,201 @@
+//! Recovery decoding of certificates with BER-encoded lengths.
+
+use alloc::vec::Vec;
+
+use der::{Decodable, Encodable, Error, ErrorKind, Length, Result, Tag};
+
+use crate::Certificate;
+
+/// Maximum nesting depth of constructed values accepted during recovery.
+const MAX_DEPTH: usize = 32;
+
+/// Certificate recovered from an encoding which uses BER lengths.
+///
+/// Some legacy issuers (e.g. certain smartcards) produce certificates with
+/// indefinite lengths or non-minimal length encodings, which are valid BER
+/// but are rejected when decoding a [`Certificate`] as DER.
+/// [`RecoveredCertificate::from_ber`] re-encodes such certificates with
+/// definite, minimal lengths so they can be decoded.
+///
+/// Only lengths are recovered: any other non-canonical encoding is still
+/// rejected. Note that the signature was computed by the issuer over its own
+/// encoding of the `TBSCertificate`, so it may not verify over the
+/// re-encoded DER of a certificate which [`is not canonical`].
+///
+/// [`is not canonical`]: RecoveredCertificate::is_canonical
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveredCertificate {
+    der: Vec<u8>,
+    canonical: bool,
+}
+
+impl RecoveredCertificate {
+    /// Recover a certificate from its BER (or DER) encoding.
+    pub fn from_ber(bytes: &[u8]) -> Result<Self> {
+        let mut reader = BerReader {
+            input: bytes,
+            pos: 0,
+            canonical: true,
+        };
+
+        let mut der = Vec::with_capacity(bytes.len());
+        reader.recover_value(&mut der, 0)?;
+
+        if reader.pos != bytes.len() {
+            return Err(reader.error(ErrorKind::TrailingData {
+                decoded: reader.position(),
+                remaining: Length::try_from(bytes.len() - reader.pos)?,
+            }));
+        }
+
+        let recovered = Self {
+            der,
+            canonical: reader.canonical,
+        };
+
+        recovered.certificate()?;
+        Ok(recovered)
+    }
+
+    /// Decode the recovered [`Certificate`].
+    pub fn certificate(&self) -> Result<Certificate<'_>> {
+        Certificate::from_der(&self.der)
+    }
+
+    /// Was the certificate already canonically encoded as DER?
+    pub fn is_canonical(&self) -> bool {
+        self.canonical
+    }
+
+    /// Borrow the recovered DER encoding of the certificate.
+    pub fn as_der(&self) -> &[u8] {
+        &self.der
+    }
+}
+
+/// Reader which re-encodes BER values as DER.
+struct BerReader<'a> {
+    input: &'a [u8],
+    pos: usize,
+    canonical: bool,
+}
+
+impl<'a> BerReader<'a> {
+    /// Re-encode the next value as DER, appending it to `out`.
+    fn recover_value(&mut self, out: &mut Vec<u8>, depth: usize) -> Result<()> {
+        let tag_byte = self.read_byte()?;
+        let tag = Tag::try_from(tag_byte).map_err(|e| self.error(e.kind()))?;
+        let len = self.read_length()?;
+
+        let mut content = Vec::new();
+
+        if tag.is_constructed() {
+            if depth >= MAX_DEPTH {
+                return Err(self.error(ErrorKind::Overlength));
+            }
+
+            match len {
+                Some(len) => {
+                    let end = self.pos.checked_add(len).ok_or(ErrorKind::Overflow)?;
+                    self.check_available(len)?;
+
+                    while self.pos < end {
+                        self.recover_value(&mut content, depth + 1)?;
+                    }
+
+                    if self.pos != end {
+                        return Err(self.error(ErrorKind::Length { tag }));
+                    }
+                }
+                None => {
+                    // Indefinite length: values until the end-of-contents octets
+                    while self.peek_bytes(2)? != [0, 0] {
+                        self.recover_value(&mut content, depth + 1)?;
+                    }
+
+                    self.pos += 2;
+                }
+            }
+        } else {
+            // Indefinite lengths are only valid for constructed values
+            let len = len.ok_or_else(|| self.error(ErrorKind::Length { tag }))?;
+            content.extend_from_slice(self.read_bytes(len)?);
+        }
+
+        let mut len_buf = [0u8; 5];
+        out.push(tag_byte);
+        out.extend_from_slice(Length::try_from(content.len())?.encode_to_slice(&mut len_buf)?);
+        out.extend_from_slice(&content);
+        Ok(())
+    }
+
+    /// Read a length, returning `None` for indefinite lengths.
+    ///
+    /// Lengths which aren't minimally encoded are accepted, but mark the
+    /// input as non-canonical.
+    fn read_length(&mut self) -> Result<Option<usize>> {
+        match self.read_byte()? {
+            0x80 => {
+                self.canonical = false;
+                Ok(None)
+            }
+            byte if byte < 0x80 => Ok(Some(byte.into())),
+            byte => {
+                let nbytes = usize::from(byte & 0x7F);
+
+                if nbytes > 4 {
+                    return Err(self.error(ErrorKind::Overlength));
+                }
+
+                let bytes = self.read_bytes(nbytes)?;
+                let leading_zero = bytes[0] == 0;
+                let len = bytes
+                    .iter()
+                    .fold(0usize, |len, &byte| (len << 8) | usize::from(byte));
+
+                if len < 0x80 || leading_zero {
+                    self.canonical = false;
+                }
+
+                Ok(Some(len))
+            }
+        }
+    }
+
+    fn read_byte(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let bytes = self.peek_bytes(len)?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn peek_bytes(&self, len: usize) -> Result<&'a [u8]> {
+        self.check_available(len)?;
+        Ok(&self.input[self.pos..][..len])
+    }
+
+    /// Check that at least `len` more bytes of input are available.
+    fn check_available(&self, len: usize) -> Result<()> {
+        if self.input.len() - self.pos >= len {
+            return Ok(());
+        }
+
+        let expected_len = self.pos.checked_add(len).ok_or(ErrorKind::Overflow)?;
+
+        Err(self.error(ErrorKind::Incomplete {
+            expected_len: Length::try_from(expected_len)?,
+            actual_len: Length::try_from(self.input.len())?,
+        }))
+    }
+
+    fn position(&self) -> Length {
+        Length::try_from(self.pos).unwrap_or(Length::MAX)
+    }
+
+    fn error(&self, kind: ErrorKind) -> Error {
+        kind.at(self.position())
+    }
+}
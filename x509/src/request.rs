@@ -1,14 +1,17 @@
 //! PKCS#10 Certification Request types
 
 use crate::ext::Extension;
-use crate::{attr::Attributes, name::Name};
+use crate::{
+    attr::{Attribute, Attributes},
+    name::Name,
+};
 
 use alloc::vec::Vec;
 
 use const_oid::db::rfc5912::ID_EXTENSION_REQ;
 use const_oid::{AssociatedOid, ObjectIdentifier};
-use der::asn1::BitString;
-use der::{Decodable, Enumerated, Newtype, Sequence};
+use der::asn1::{Any, BitString};
+use der::{Decodable, Encodable, Enumerated, ErrorKind, Newtype, Sequence, Tag};
 use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
 
 /// Version identifier for certification request information.
@@ -81,6 +84,169 @@ pub struct CertReq<'a> {
     pub signature: BitString<'a>,
 }
 
+impl<'a> CertReq<'a> {
+    /// Decode a certification request from untrusted input, enforcing the
+    /// given [`DecodeLimits`].
+    ///
+    /// Returns an [`ErrorKind::Overlength`] error if any limit is exceeded.
+    pub fn from_der_with_limits(bytes: &'a [u8], limits: DecodeLimits) -> der::Result<Self> {
+        if bytes.len() > limits.max_len {
+            return Err(ErrorKind::Overlength.into());
+        }
+
+        let req = Self::from_der(bytes)?;
+        let attributes = &req.info.attributes;
+
+        if attributes.len() > limits.max_attributes {
+            return Err(ErrorKind::Overlength.into());
+        }
+
+        let mut extensions = 0usize;
+
+        for attribute in attributes.iter().filter(|a| a.oid == ExtensionReq::OID) {
+            for value in attribute.values.iter() {
+                let ExtensionReq(req_extensions) = value.decode_into()?;
+                extensions = extensions.saturating_add(req_extensions.len());
+
+                if extensions > limits.max_extensions {
+                    return Err(ErrorKind::Overlength.into());
+                }
+            }
+        }
+
+        Ok(req)
+    }
+
+    /// Compute a canonical DER encoding of this certification request.
+    ///
+    /// Attributes which share a type, and extensions within an
+    /// `extensionRequest` attribute which share an `extnID`, are handled
+    /// according to the given [`DuplicatePolicy`]. `SET OF` values are always
+    /// encoded in DER order.
+    ///
+    /// The resulting encoding is intended for deduplicating and caching
+    /// requests: when anything is removed, the signature will no longer
+    /// verify.
+    pub fn canonicalize(&self, policy: DuplicatePolicy) -> der::Result<Vec<u8>> {
+        let mut attributes: Vec<&Attribute<'a>> = Vec::new();
+
+        for attribute in self.info.attributes.iter() {
+            if attributes.iter().any(|a| a.oid == attribute.oid) {
+                match policy {
+                    DuplicatePolicy::Reject => return Err(Tag::Set.value_error()),
+                    DuplicatePolicy::KeepFirst => continue,
+                }
+            }
+
+            attributes.push(attribute);
+        }
+
+        // Re-encode extension requests with duplicate extensions removed
+        let mut extension_reqs = Vec::new();
+
+        for attribute in attributes.iter().filter(|a| a.oid == ExtensionReq::OID) {
+            for value in attribute.values.iter() {
+                let ExtensionReq(extensions) = value.decode_into()?;
+                let mut unique: Vec<Extension<'_>> = Vec::new();
+
+                for extension in extensions {
+                    if unique.iter().any(|e| e.extn_id == extension.extn_id) {
+                        match policy {
+                            DuplicatePolicy::Reject => return Err(Tag::Sequence.value_error()),
+                            DuplicatePolicy::KeepFirst => continue,
+                        }
+                    }
+
+                    unique.push(extension);
+                }
+
+                extension_reqs.push(ExtensionReq(unique).to_vec()?);
+            }
+        }
+
+        let mut extension_reqs = extension_reqs.iter();
+        let mut canonical = Vec::with_capacity(attributes.len());
+
+        for attribute in attributes {
+            let values = if attribute.oid == ExtensionReq::OID {
+                attribute
+                    .values
+                    .iter()
+                    .filter_map(|_| extension_reqs.next())
+                    .map(|der| Any::from_der(der))
+                    .collect::<der::Result<Vec<_>>>()?
+                    .try_into()?
+            } else {
+                attribute.values.clone()
+            };
+
+            canonical.push(Attribute {
+                oid: attribute.oid,
+                values,
+            });
+        }
+
+        let info = CertReqInfo {
+            attributes: canonical.try_into()?,
+            ..self.info.clone()
+        };
+
+        CertReq {
+            info,
+            algorithm: self.algorithm,
+            signature: self.signature,
+        }
+        .to_vec()
+    }
+}
+
+/// Limits applied by [`CertReq::from_der_with_limits`] when decoding
+/// untrusted certification requests, e.g. those submitted to a public CA.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DecodeLimits {
+    /// Maximum size of the DER-encoded request in bytes.
+    pub max_len: usize,
+
+    /// Maximum number of attributes.
+    pub max_attributes: usize,
+
+    /// Maximum number of requested extensions, across all `extensionRequest`
+    /// attributes.
+    pub max_extensions: usize,
+}
+
+impl DecodeLimits {
+    /// Default maximum size of the DER-encoded request: 64 KiB.
+    pub const DEFAULT_MAX_LEN: usize = 0x10000;
+
+    /// Default maximum number of attributes.
+    pub const DEFAULT_MAX_ATTRIBUTES: usize = 16;
+
+    /// Default maximum number of requested extensions.
+    pub const DEFAULT_MAX_EXTENSIONS: usize = 32;
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self {
+            max_len: Self::DEFAULT_MAX_LEN,
+            max_attributes: Self::DEFAULT_MAX_ATTRIBUTES,
+            max_extensions: Self::DEFAULT_MAX_EXTENSIONS,
+        }
+    }
+}
+
+/// How [`CertReq::canonicalize`] handles duplicate attributes and extensions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DuplicatePolicy {
+    /// Return an error if a duplicate is found.
+    Reject,
+
+    /// Keep the first occurrence (in DER order for attributes and in
+    /// encoded order for extensions) and discard the rest.
+    KeepFirst,
+}
+
 impl<'a> TryFrom<&'a [u8]> for CertReq<'a> {
     type Error = der::Error;
 
@@ -0,0 +1,119 @@
+//! Certificate serial numbers
+
+use der::{asn1::UIntBytes, Result, Tag};
+
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
+
+/// Certificate serial number as defined in [RFC 5280 Section 4.1.2.2].
+///
+/// ```text
+/// CertificateSerialNumber  ::=  INTEGER
+/// ```
+///
+/// Serial numbers are positive integers of at most 20 octets when DER encoded.
+///
+/// [RFC 5280 Section 4.1.2.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct SerialNumber {
+    /// Big endian bytes, right-aligned.
+    bytes: [u8; Self::MAX_LEN],
+
+    /// Number of significant bytes.
+    len: usize,
+}
+
+impl SerialNumber {
+    /// Maximum length of a serial number in octets.
+    pub const MAX_LEN: usize = 20;
+
+    /// Minimum length of a randomly generated serial number in octets.
+    ///
+    /// The CA/Browser Forum Baseline Requirements call for at least 64 bits
+    /// of output from a CSPRNG. One bit of the leading octet is cleared to
+    /// keep the serial number positive, so 9 octets are needed.
+    pub const MIN_RANDOM_LEN: usize = 9;
+
+    /// Create a serial number from the given big endian bytes.
+    ///
+    /// Leading zeroes are stripped. Returns an error if the value is zero or
+    /// does not fit in [`SerialNumber::MAX_LEN`] octets when DER encoded.
+    pub fn new(bytes: &[u8]) -> Result<Self> {
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        let bytes = &bytes[start..];
+
+        // A leading octet with the high bit set needs an extra zero octet
+        let encoded_len = match bytes.first() {
+            Some(&b) if b & 0x80 != 0 => bytes.len() + 1,
+            Some(_) => bytes.len(),
+            None => return Err(Tag::Integer.value_error()),
+        };
+
+        if encoded_len > Self::MAX_LEN {
+            return Err(Tag::Integer.value_error());
+        }
+
+        let mut buf = [0u8; Self::MAX_LEN];
+        buf[Self::MAX_LEN - bytes.len()..].copy_from_slice(bytes);
+
+        Ok(Self {
+            bytes: buf,
+            len: bytes.len(),
+        })
+    }
+
+    /// Generate a random serial number of `len` octets.
+    ///
+    /// `len` must be between [`SerialNumber::MIN_RANDOM_LEN`] and
+    /// [`SerialNumber::MAX_LEN`], ensuring at least 64 bits of entropy.
+    ///
+    /// The output is determined entirely by `rng`, so tests can use a seeded
+    /// RNG to obtain reproducible serial numbers.
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    pub fn generate(mut rng: impl CryptoRng + RngCore, len: usize) -> Result<Self> {
+        if !(Self::MIN_RANDOM_LEN..=Self::MAX_LEN).contains(&len) {
+            return Err(Tag::Integer.length_error());
+        }
+
+        let mut buf = [0u8; Self::MAX_LEN];
+
+        loop {
+            rng.fill_bytes(&mut buf[..len]);
+
+            // Clear the high bit so the serial number is positive
+            buf[0] &= 0x7f;
+
+            // Zero isn't a valid serial number
+            if buf[..len].iter().any(|&b| b != 0) {
+                return Self::new(&buf[..len]);
+            }
+        }
+    }
+
+    /// Borrow the big endian bytes of this serial number with leading zeroes
+    /// stripped.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[Self::MAX_LEN - self.len..]
+    }
+
+    /// Borrow this serial number as a [`UIntBytes`], e.g. for use as the
+    /// `serial_number` of a [`TbsCertificate`][`crate::TbsCertificate`].
+    pub fn as_uint_bytes(&self) -> UIntBytes<'_> {
+        UIntBytes::new(self.as_bytes()).expect("serial number is at most 20 bytes")
+    }
+}
+
+impl AsRef<[u8]> for SerialNumber {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl TryFrom<UIntBytes<'_>> for SerialNumber {
+    type Error = der::Error;
+
+    fn try_from(bytes: UIntBytes<'_>) -> Result<Self> {
+        Self::new(bytes.as_bytes())
+    }
+}
@@ -0,0 +1,235 @@
+//! Certificate Transparency (CT) log entries as defined in [RFC 6962].
+//!
+//! These structures use the TLS presentation language rather than ASN.1, so
+//! they are serialized with `to_vec` methods instead of [`der::Encodable`].
+//!
+//! [RFC 6962]: https://datatracker.ietf.org/doc/html/rfc6962
+
+use crate::{Certificate, TbsCertificate};
+use alloc::vec::Vec;
+use const_oid::ObjectIdentifier;
+use der::{Encodable, ErrorKind, Result};
+
+#[cfg(feature = "digest")]
+use digest::{Digest, Output};
+
+/// OID of the precertificate poison extension as defined in
+/// [RFC 6962 Section 3.1].
+///
+/// [RFC 6962 Section 3.1]: https://datatracker.ietf.org/doc/html/rfc6962#section-3.1
+pub const CT_PRECERT_POISON: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.4.1.11129.2.4.3");
+
+/// `Version` of a [`MerkleTreeLeaf`]: `v1`.
+const VERSION_V1: u8 = 0;
+
+/// `MerkleLeafType` of a [`MerkleTreeLeaf`]: `timestamped_entry`.
+const LEAF_TYPE_TIMESTAMPED_ENTRY: u8 = 0;
+
+/// Log entry as found in the `signed_entry` of a [`TimestampedEntry`].
+///
+/// ```text
+/// enum { x509_entry(0), precert_entry(1), (65535) } LogEntryType;
+///
+/// opaque ASN.1Cert<1..2^24-1>;
+///
+/// struct {
+///   opaque issuer_key_hash[32];
+///   TBSCertificate tbs_certificate;
+/// } PreCert;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LogEntry {
+    /// `x509_entry`: DER encoding of the logged certificate.
+    X509(Vec<u8>),
+
+    /// `precert_entry`: issuer key hash and `TBSCertificate` of the logged
+    /// precertificate.
+    Precert {
+        /// SHA-256 hash of the issuer's DER-encoded `SubjectPublicKeyInfo`.
+        issuer_key_hash: [u8; 32],
+
+        /// DER encoding of the `TBSCertificate`, without the poison
+        /// extension.
+        tbs_certificate: Vec<u8>,
+    },
+}
+
+impl LogEntry {
+    /// Create an `x509_entry` for the given certificate.
+    pub fn x509(cert: &Certificate<'_>) -> Result<Self> {
+        Ok(Self::X509(cert.to_vec()?))
+    }
+
+    /// Create a `precert_entry` for the given precertificate
+    /// `TBSCertificate`, removing the [`CT_PRECERT_POISON`] extension.
+    ///
+    /// `issuer_key_hash` is the SHA-256 hash of the DER-encoded
+    /// `SubjectPublicKeyInfo` of the certificate issuer.
+    pub fn precert(
+        issuer_key_hash: [u8; 32],
+        tbs_certificate: &TbsCertificate<'_>,
+    ) -> Result<Self> {
+        let mut tbs_certificate = tbs_certificate.clone();
+
+        if let Some(extensions) = &mut tbs_certificate.extensions {
+            extensions.retain(|ext| ext.extn_id != CT_PRECERT_POISON);
+        }
+
+        if tbs_certificate
+            .extensions
+            .as_ref()
+            .map_or(false, Vec::is_empty)
+        {
+            tbs_certificate.extensions = None;
+        }
+
+        Ok(Self::Precert {
+            issuer_key_hash,
+            tbs_certificate: tbs_certificate.to_vec()?,
+        })
+    }
+
+    /// Get the `LogEntryType` of this entry.
+    pub fn entry_type(&self) -> u16 {
+        match self {
+            Self::X509(_) => 0,
+            Self::Precert { .. } => 1,
+        }
+    }
+
+    /// Append the encoding of this entry's `LogEntryType` and
+    /// `signed_entry` to `out`.
+    fn encode(&self, out: &mut Vec<u8>) -> Result<()> {
+        out.extend_from_slice(&self.entry_type().to_be_bytes());
+
+        match self {
+            Self::X509(cert) => encode_opaque(out, cert, LenSize::U24),
+            Self::Precert {
+                issuer_key_hash,
+                tbs_certificate,
+            } => {
+                out.extend_from_slice(issuer_key_hash);
+                encode_opaque(out, tbs_certificate, LenSize::U24)
+            }
+        }
+    }
+}
+
+/// `TimestampedEntry` as defined in [RFC 6962 Section 3.4].
+///
+/// ```text
+/// struct {
+///     uint64 timestamp;
+///     LogEntryType entry_type;
+///     select(entry_type) {
+///         case x509_entry: ASN.1Cert;
+///         case precert_entry: PreCert;
+///     } signed_entry;
+///     CtExtensions extensions;
+/// } TimestampedEntry;
+/// ```
+///
+/// [RFC 6962 Section 3.4]: https://datatracker.ietf.org/doc/html/rfc6962#section-3.4
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimestampedEntry {
+    /// Timestamp of the SCT in milliseconds since the UNIX epoch.
+    pub timestamp: u64,
+
+    /// Logged certificate or precertificate.
+    pub entry: LogEntry,
+
+    /// `CtExtensions` of the SCT, currently always empty.
+    pub extensions: Vec<u8>,
+}
+
+impl TimestampedEntry {
+    /// Serialize this [`TimestampedEntry`].
+    pub fn to_vec(&self) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+        self.encode(&mut out)?;
+        Ok(out)
+    }
+
+    /// Append the encoding of this entry to `out`.
+    fn encode(&self, out: &mut Vec<u8>) -> Result<()> {
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        self.entry.encode(out)?;
+        encode_opaque(out, &self.extensions, LenSize::U16)
+    }
+}
+
+/// `MerkleTreeLeaf` as defined in [RFC 6962 Section 3.4].
+///
+/// ```text
+/// struct {
+///     Version version;
+///     MerkleLeafType leaf_type;
+///     select (leaf_type) {
+///         case timestamped_entry: TimestampedEntry;
+///     }
+/// } MerkleTreeLeaf;
+/// ```
+///
+/// Only `v1` leaves of type `timestamped_entry` are defined.
+///
+/// [RFC 6962 Section 3.4]: https://datatracker.ietf.org/doc/html/rfc6962#section-3.4
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleTreeLeaf {
+    /// Timestamped log entry.
+    pub timestamped_entry: TimestampedEntry,
+}
+
+impl MerkleTreeLeaf {
+    /// Serialize this [`MerkleTreeLeaf`].
+    pub fn to_vec(&self) -> Result<Vec<u8>> {
+        let mut out = Vec::from([VERSION_V1, LEAF_TYPE_TIMESTAMPED_ENTRY]);
+        self.timestamped_entry.encode(&mut out)?;
+        Ok(out)
+    }
+
+    /// Compute the Merkle tree leaf hash of this leaf as defined in
+    /// [RFC 6962 Section 2.1], i.e. `D(0x00 || MerkleTreeLeaf)`.
+    ///
+    /// Logs use SHA-256 as `D`.
+    ///
+    /// [RFC 6962 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc6962#section-2.1
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    pub fn leaf_hash<D: Digest>(&self) -> Result<Output<D>> {
+        Ok(D::new()
+            .chain_update([0])
+            .chain_update(self.to_vec()?)
+            .finalize())
+    }
+}
+
+impl From<TimestampedEntry> for MerkleTreeLeaf {
+    fn from(timestamped_entry: TimestampedEntry) -> MerkleTreeLeaf {
+        MerkleTreeLeaf { timestamped_entry }
+    }
+}
+
+/// Size of the length prefix of an `opaque` vector.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum LenSize {
+    /// `opaque<0..2^16-1>`
+    U16 = 2,
+
+    /// `opaque<0..2^24-1>`
+    U24 = 3,
+}
+
+/// Append `data` to `out` prefixed with its big endian length.
+fn encode_opaque(out: &mut Vec<u8>, data: &[u8], len_size: LenSize) -> Result<()> {
+    let len_size = len_size as usize;
+    let len = u32::try_from(data.len()).map_err(|_| ErrorKind::Overflow)?;
+
+    if len >= 1 << (8 * len_size) {
+        return Err(ErrorKind::Overflow.into());
+    }
+
+    out.extend_from_slice(&len.to_be_bytes()[(4 - len_size)..]);
+    out.extend_from_slice(data);
+    Ok(())
+}
@@ -151,6 +151,62 @@ fn reencode_cert() {
     assert_eq!(&parsed_coverage_tbs.extensions[4..], encoded_extensions);
 }
 
+#[test]
+fn reject_trailing_data() {
+    let mut bytes = Vec::from(&include_bytes!("examples/GoodCACert.crt")[..]);
+    bytes.push(0);
+
+    let err = Certificate::from_der(&bytes).unwrap_err();
+    assert!(matches!(err.kind(), der::ErrorKind::TrailingData { .. }));
+}
+
+#[test]
+fn decode_lenient_validity() {
+    // GoodCACert.crt with its validity replaced by:
+    //   SEQUENCE {
+    //     UTCTime 01/01/2010 08:30:00 GMT
+    //     GeneralizedTime 20301231103000.123+0200
+    //   }
+    let original = include_bytes!("examples/GoodCACert.crt");
+    let validity = hex!("301E170D3130303130313038333030305A170D3330313233313038333030305A");
+    let lenient_validity = hex!(
+        "3028170D3130303130313038333030305A"
+        "181732303330313233313130333030302E3132332B30323030"
+    );
+    let pos = original
+        .windows(validity.len())
+        .position(|window| window == validity)
+        .unwrap();
+
+    let mut bytes = Vec::from(&original[..pos]);
+    bytes.extend_from_slice(&lenient_validity);
+    bytes.extend_from_slice(&original[(pos + validity.len())..]);
+
+    // Fix up the lengths of the `Certificate` and `TBSCertificate`
+    let delta = (lenient_validity.len() - validity.len()) as u16;
+    for len_pos in [2, 6] {
+        let len = u16::from_be_bytes([bytes[len_pos], bytes[len_pos + 1]]) + delta;
+        bytes[len_pos..(len_pos + 2)].copy_from_slice(&len.to_be_bytes());
+    }
+
+    assert!(Certificate::from_der(&bytes).is_err());
+
+    let cert = Certificate::from_der_lenient(&bytes).unwrap();
+    let expected = Certificate::from_der(original).unwrap();
+    assert_eq!(
+        cert.tbs_certificate.subject,
+        expected.tbs_certificate.subject
+    );
+    assert_eq!(
+        cert.tbs_certificate.validity.not_before,
+        expected.tbs_certificate.validity.not_before
+    );
+    assert_eq!(
+        cert.tbs_certificate.validity.not_after.to_date_time(),
+        expected.tbs_certificate.validity.not_after.to_date_time()
+    );
+}
+
 #[test]
 fn decode_oversized_oids() {
     let o1parse = ObjectIdentifier::from_der(&hex!(
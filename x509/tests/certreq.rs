@@ -1,8 +1,12 @@
 //! Certification request (`CertReq`) tests
 
-use der::{Encodable, Tag, Tagged};
+use der::{asn1::Any, Decodable, Encodable, Tag, Tagged};
 use hex_literal::hex;
-use x509::request::{CertReq, Version};
+use x509::{
+    attr::Attribute,
+    ext::Extension,
+    request::{CertReq, DecodeLimits, DuplicatePolicy, ExtensionReq, Version},
+};
 
 const RSA_KEY: &[u8] = &hex!("3082010A0282010100BF59F7FE716DDE47C73579CA846EFA8D30AB3612E0D6A524204A72CA8E50C9F459513DF0D73331BED3D7A2DA7A362719E471EE6A9D87827D1024ED44605AB9B48F3B808C5E173B9F3EC4003D57F1718489F5C7A0421C46FBD527A40AB4BA6B9DB16A545D1ECF6E2A5633BD80594EBA4AFEE71F63E1D357C64E9A3FF6B83746A885C373F3527987E4C2B4AF7FE4D4EA16405E5E15285DD938823AA18E2634BAFE847A761CAFABB0401D3FA03A07A9D097CBB0C77156CCFE36131DADF1C109C2823972F0AF21A35F358E788304C0C78B951739D91FABFFD07AA8CD4F69746B3D0EB4587469F9D39F4FBDC761200DFB27DAF69562311D8B191B7EEFAAE2F8D6F8EB0203010001");
 const RSA_SIG: &[u8] = &hex!("2B053CFE81C6542176BD70B373A5FC8DC1F1806A5AB10D25E36690EED1DF57AD5F18EC0CCF165F000245B14157141224B431EC6715EFE937F66B892D11EDF8858EDF67ACCAE9701A2244BECA80705D7CC292BAD9B02001E4572EE492B08473D5AF59CC83DDA1DE5C2BF470FD784495070A9C5AF8EA9A4060C1DBC5C4690CC8DF6D528C55D82EC9C0DF3046BBCAE7542025D7EE170788C9C234132703290A31AC2700E55339590226D5E582EC61869862769FD85B45F287FFDD6DB530995D31F94D7D2C26EF3F48A182C3026CC698F382A72F1A11E3C689953055DAC0DFEBE9CDB163CA3AF33FFC4DA0F6B84B9D7CDD4321CCECD4BAC528DEFF9715FFD9D4731E");
@@ -74,6 +78,48 @@ fn decode_rsa_2048_der() {
     assert_eq!(cr.signature.as_bytes().unwrap(), RSA_SIG);
 }
 
+#[test]
+fn reject_trailing_data() {
+    let mut bytes = RSA_2048_DER_EXAMPLE.to_vec();
+    bytes.push(0);
+
+    let err = CertReq::from_der(&bytes).unwrap_err();
+    assert!(matches!(err.kind(), der::ErrorKind::TrailingData { .. }));
+}
+
+#[test]
+fn decode_with_limits() {
+    let default = DecodeLimits::default();
+    assert!(CertReq::from_der_with_limits(RSA_2048_DER_EXAMPLE, default).is_ok());
+
+    let limits = [
+        DecodeLimits {
+            max_len: RSA_2048_DER_EXAMPLE.len() - 1,
+            ..default
+        },
+        DecodeLimits {
+            max_attributes: 0,
+            ..default
+        },
+        DecodeLimits {
+            max_extensions: EXTENSIONS.len() - 1,
+            ..default
+        },
+    ];
+
+    for limits in limits {
+        let err = CertReq::from_der_with_limits(RSA_2048_DER_EXAMPLE, limits).unwrap_err();
+        assert_eq!(der::ErrorKind::Overlength, err.kind());
+    }
+
+    let limits = DecodeLimits {
+        max_len: RSA_2048_DER_EXAMPLE.len(),
+        max_attributes: 1,
+        max_extensions: EXTENSIONS.len(),
+    };
+    assert!(CertReq::from_der_with_limits(RSA_2048_DER_EXAMPLE, limits).is_ok());
+}
+
 // The following tests currently fail because of a bug in the `der` crate;
 // specifically, the `IMPLICIT` tagging on `CertReqInfo::attributes`.
 
@@ -83,3 +129,72 @@ fn encode_rsa_2048_der() {
     let cr_encoded = cr.to_vec().unwrap();
     assert_eq!(RSA_2048_DER_EXAMPLE, cr_encoded.as_slice());
 }
+
+#[test]
+fn canonicalize_rsa_2048_der() {
+    let cr = CertReq::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+
+    for policy in [DuplicatePolicy::Reject, DuplicatePolicy::KeepFirst] {
+        let canonical = cr.canonicalize(policy).unwrap();
+        assert_eq!(RSA_2048_DER_EXAMPLE, canonical.as_slice());
+    }
+}
+
+#[test]
+fn canonicalize_duplicate_extensions() {
+    let mut cr = CertReq::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let attribute = cr.info.attributes.get(0).unwrap();
+    let ExtensionReq(mut extensions) = attribute.values.get(0).unwrap().decode_into().unwrap();
+
+    // Repeat `basicConstraints` with a different value
+    extensions.push(Extension {
+        extn_id: "2.5.29.19".parse().unwrap(),
+        critical: true,
+        extn_value: &hex!("30030101FF"),
+    });
+
+    let value = ExtensionReq(extensions).to_vec().unwrap();
+    let values = vec![Any::from_der(&value).unwrap()].try_into().unwrap();
+    let attributes = vec![Attribute {
+        oid: attribute.oid,
+        values,
+    }];
+    cr.info.attributes = attributes.try_into().unwrap();
+
+    assert!(cr.canonicalize(DuplicatePolicy::Reject).is_err());
+
+    let canonical = cr.canonicalize(DuplicatePolicy::KeepFirst).unwrap();
+    assert_eq!(RSA_2048_DER_EXAMPLE, canonical.as_slice());
+}
+
+#[test]
+fn canonicalize_duplicate_attributes() {
+    let mut cr = CertReq::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let mut attributes = cr.info.attributes.clone().into_vec();
+
+    // Two `challengePassword` attributes with different values
+    let challenge_password = "1.2.840.113549.1.9.7".parse().unwrap();
+    let passwords = [hex!("0C0161"), hex!("0C0162")];
+    for password in &passwords {
+        attributes.push(Attribute {
+            oid: challenge_password,
+            values: vec![Any::from_der(password).unwrap()].try_into().unwrap(),
+        });
+    }
+    cr.info.attributes = attributes.try_into().unwrap();
+
+    assert!(cr.canonicalize(DuplicatePolicy::Reject).is_err());
+
+    let canonical = cr.canonicalize(DuplicatePolicy::KeepFirst).unwrap();
+    let canonical = CertReq::from_der(&canonical).unwrap();
+    assert_eq!(canonical.info.attributes.len(), 2);
+    assert_eq!(
+        canonical
+            .info
+            .attributes
+            .iter()
+            .filter(|a| a.oid == challenge_password)
+            .count(),
+        1
+    );
+}
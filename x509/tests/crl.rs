@@ -1,5 +1,8 @@
+use const_oid::db::rfc5280::{ID_CE_CRL_NUMBER, ID_CE_ISSUING_DISTRIBUTION_POINT};
+use const_oid::ObjectIdentifier;
 use der::Decodable;
-use x509::crl::CertificateList;
+use x509::crl::{CertificateList, CrlSequenceError, TbsCertList};
+use x509::ext::Extension;
 
 #[test]
 fn decode_crl() {
@@ -15,3 +18,89 @@ fn decode_crl() {
     assert_eq!(2, crl.tbs_cert_list.crl_extensions.unwrap().len());
     assert_eq!(4, crl.tbs_cert_list.revoked_certificates.unwrap().len());
 }
+
+/// Replace the value of the CRL extension with the given `extnID`, adding it
+/// if not present.
+fn set_crl_extension<'a>(
+    tbs: &mut TbsCertList<'a>,
+    extn_id: ObjectIdentifier,
+    extn_value: &'a [u8],
+) {
+    let extensions = tbs.crl_extensions.get_or_insert_with(Vec::new);
+
+    match extensions.iter_mut().find(|e| e.extn_id == extn_id) {
+        Some(extension) => extension.extn_value = extn_value,
+        None => extensions.push(Extension {
+            extn_id,
+            critical: true,
+            extn_value,
+        }),
+    }
+}
+
+#[test]
+fn check_crl_successor() {
+    let prior = CertificateList::from_der(include_bytes!("examples/GoodCACRL.crl"))
+        .unwrap()
+        .tbs_cert_list;
+    assert_eq!(&[1], prior.crl_number().unwrap().unwrap().0.as_bytes());
+    assert_eq!(None, prior.issuing_distribution_point().unwrap());
+
+    let mut next = prior.clone();
+    set_crl_extension(&mut next, ID_CE_CRL_NUMBER, &[0x02, 0x01, 0x02]);
+    next.check_successor(&prior).unwrap();
+
+    // Rollback and replay
+    assert_eq!(
+        Err(CrlSequenceError::CrlNumberNotIncreasing),
+        prior.check_successor(&next)
+    );
+    assert_eq!(
+        Err(CrlSequenceError::CrlNumberNotIncreasing),
+        prior.check_successor(&prior)
+    );
+
+    // Multi-byte CRL numbers compare numerically
+    let mut large = prior.clone();
+    set_crl_extension(&mut large, ID_CE_CRL_NUMBER, &[0x02, 0x02, 0x01, 0x00]);
+    large.check_successor(&next).unwrap();
+
+    // Substitution with a CRL from another issuer
+    let other = CertificateList::from_der(include_bytes!("examples/tscpbcasha256.crl"))
+        .unwrap()
+        .tbs_cert_list;
+    assert_eq!(
+        Err(CrlSequenceError::IssuerMismatch),
+        other.check_successor(&prior)
+    );
+
+    // Substitution with a CRL of narrower scope
+    let mut scoped = next.clone();
+    set_crl_extension(
+        &mut scoped,
+        ID_CE_ISSUING_DISTRIBUTION_POINT,
+        &[0x30, 0x03, 0x81, 0x01, 0xff],
+    );
+    assert!(
+        scoped
+            .issuing_distribution_point()
+            .unwrap()
+            .unwrap()
+            .only_contains_user_certs
+    );
+    assert_eq!(
+        Err(CrlSequenceError::ScopeMismatch),
+        scoped.check_successor(&prior)
+    );
+
+    let mut unnumbered = next.clone();
+    unnumbered
+        .crl_extensions
+        .as_mut()
+        .unwrap()
+        .retain(|e| e.extn_id != ID_CE_CRL_NUMBER);
+    assert_eq!(
+        Err(CrlSequenceError::MissingCrlNumber),
+        unnumbered.check_successor(&prior)
+    );
+}
@@ -0,0 +1,97 @@
+//! DANE TLSA record tests
+use der::{Decodable, Encodable};
+use x509::dane::{CertificateUsage, MatchingType, Selector, Tlsa};
+use x509::Certificate;
+
+#[cfg(feature = "digest")]
+use {
+    hex_literal::hex,
+    sha2::{Sha256, Sha512},
+};
+
+#[test]
+fn tlsa_full() {
+    let der_encoded_cert = include_bytes!("examples/eca.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+
+    let tlsa = Tlsa::new(&cert, CertificateUsage::DaneTa, Selector::Cert).unwrap();
+    assert_eq!(tlsa.matching_type, MatchingType::Full);
+    assert_eq!(tlsa.data, der_encoded_cert);
+
+    let tlsa = Tlsa::new(&cert, CertificateUsage::DaneEe, Selector::Spki).unwrap();
+    let spki = cert
+        .tbs_certificate
+        .subject_public_key_info
+        .to_vec()
+        .unwrap();
+    assert_eq!(tlsa.data, spki);
+    assert!(tlsa.to_string().starts_with("3 1 0 30"));
+}
+
+/// Expected values computed with `sha256sum eca.der` and
+/// `openssl x509 -pubkey -noout | openssl pkey -pubin -outform der | sha512sum`
+#[cfg(feature = "digest")]
+#[test]
+fn tlsa_hashed() {
+    let der_encoded_cert = include_bytes!("examples/eca.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+
+    let tlsa = Tlsa::new_hashed::<Sha256>(
+        &cert,
+        CertificateUsage::DaneTa,
+        Selector::Cert,
+        MatchingType::Sha256,
+    )
+    .unwrap();
+    assert_eq!(
+        tlsa.data,
+        hex!("343ff530f246b00c71616a5d42541d551114baf74b7f786c7b42527586c6b22a")
+    );
+    assert_eq!(
+        tlsa.to_string(),
+        "2 0 1 343FF530F246B00C71616A5D42541D551114BAF74B7F786C7B42527586C6B22A"
+    );
+
+    let tlsa = Tlsa::new_hashed::<Sha256>(
+        &cert,
+        CertificateUsage::DaneEe,
+        Selector::Spki,
+        MatchingType::Sha256,
+    )
+    .unwrap();
+    assert_eq!(
+        tlsa.data,
+        hex!("505236b952680b4394707f99d84e040fc94be791fc24d1af78f2eecb0c958308")
+    );
+
+    let tlsa = Tlsa::new_hashed::<Sha512>(
+        &cert,
+        CertificateUsage::DaneEe,
+        Selector::Spki,
+        MatchingType::Sha512,
+    )
+    .unwrap();
+    assert_eq!(
+        tlsa.data,
+        hex!(
+            "9daaa735eb02d897b70099684effde89a28438121c5d022797ca0adc3d4cbe1b
+             f101d5185135c5d0536d08fee21a4e742bd0de684f65db0c16760af1f243329a"
+        )
+    );
+
+    // Digest doesn't match the matching type
+    assert!(Tlsa::new_hashed::<Sha256>(
+        &cert,
+        CertificateUsage::DaneEe,
+        Selector::Spki,
+        MatchingType::Sha512,
+    )
+    .is_err());
+    assert!(Tlsa::new_hashed::<Sha256>(
+        &cert,
+        CertificateUsage::DaneEe,
+        Selector::Spki,
+        MatchingType::Full,
+    )
+    .is_err());
+}
@@ -0,0 +1,97 @@
+//! Certificate identifier tests
+use der::{Decodable, Encodable};
+use hex_literal::hex;
+use x509::crl::CertificateList;
+use x509::id::{CertId, IssuerAndSerialNumber};
+use x509::Certificate;
+
+/// `CertID` extracted from `openssl ocsp -issuer eca.pem -cert eca.pem -no_nonce -reqout req.der`
+#[cfg(feature = "digest")]
+const ECA_CERT_ID_SHA1: &[u8] = &hex!("303a300906052b0e03021a050004148f15482b902affd0c042065495cb25e7d3b049ce0414335ba56f7a55602b814b2614cc79bf4aba8b32bd020101");
+
+/// Same as above, with `-sha256`
+#[cfg(feature = "digest")]
+const ECA_CERT_ID_SHA256: &[u8] = &hex!("3056300d06096086480165030402010500042044728253c054f4d88ed47a18434d8efae1f7cd38eafc2f000057261d8ab559c204204fc167ddcb187407592ccffbb8cde4f376404084071e107b8d8d59bd984d56ad020101");
+
+#[test]
+fn issuer_and_serial_number() {
+    let der_encoded_cert = include_bytes!("examples/GoodCACert.crt");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    let id = IssuerAndSerialNumber::from(&cert);
+    assert!(id.matches(&cert.tbs_certificate));
+
+    let reencoded = id.to_vec().unwrap();
+    let decoded = IssuerAndSerialNumber::from_der(&reencoded).unwrap();
+    assert_eq!(id, decoded);
+
+    let der_encoded_cert = include_bytes!("examples/eca.der");
+    let other = Certificate::from_der(der_encoded_cert).unwrap();
+    assert!(!id.matches(&other.tbs_certificate));
+}
+
+#[test]
+fn issuer_and_serial_number_revocation() {
+    let der_encoded_crl = include_bytes!("examples/GoodCACRL.crl");
+    let crl = CertificateList::from_der(der_encoded_crl).unwrap();
+    let tbs = &crl.tbs_cert_list;
+
+    for revoked in tbs.revoked_certificates.as_ref().unwrap() {
+        let id = IssuerAndSerialNumber {
+            issuer: tbs.issuer.clone(),
+            serial_number: revoked.serial_number,
+        };
+        assert!(id.is_revoked_by(tbs));
+    }
+
+    // Good CA's own certificate is issued by the trust anchor, not Good CA
+    let der_encoded_cert = include_bytes!("examples/GoodCACert.crt");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    assert_eq!(cert.tbs_certificate.subject, tbs.issuer);
+    assert!(!IssuerAndSerialNumber::from(&cert).is_revoked_by(tbs));
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn cert_id_sha1() {
+    let der_encoded_cert = include_bytes!("examples/eca.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    let tbs = &cert.tbs_certificate;
+    let expected = CertId::from_der(ECA_CERT_ID_SHA1).unwrap();
+
+    let (name_hash, key_hash) = CertId::hash_issuer::<sha1::Sha1>(tbs).unwrap();
+    let cert_id = CertId::new(
+        expected.hash_algorithm,
+        &name_hash,
+        &key_hash,
+        tbs.serial_number,
+    )
+    .unwrap();
+
+    assert_eq!(expected, cert_id);
+    assert_eq!(ECA_CERT_ID_SHA1, cert_id.to_vec().unwrap());
+    assert!(cert_id
+        .matches::<sha1::Sha1>(tbs, tbs.serial_number)
+        .unwrap());
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn cert_id_sha256() {
+    let der_encoded_cert = include_bytes!("examples/eca.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    let tbs = &cert.tbs_certificate;
+    let cert_id = CertId::from_der(ECA_CERT_ID_SHA256).unwrap();
+
+    assert!(cert_id
+        .matches::<sha2::Sha256>(tbs, tbs.serial_number)
+        .unwrap());
+    assert!(!cert_id
+        .matches::<sha1::Sha1>(tbs, tbs.serial_number)
+        .unwrap());
+
+    let der_encoded_cert = include_bytes!("examples/GoodCACert.crt");
+    let other = Certificate::from_der(der_encoded_cert).unwrap();
+    assert!(!cert_id
+        .matches::<sha2::Sha256>(&other.tbs_certificate, tbs.serial_number)
+        .unwrap());
+}
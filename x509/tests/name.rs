@@ -5,7 +5,7 @@ use der::asn1::{OctetString, SetOfVec, Utf8String};
 use der::{Any, Decodable, Encodable, Tag, Tagged};
 use hex_literal::hex;
 use x509::attr::AttributeTypeAndValue;
-use x509::name::{Name, RdnSequence, RelativeDistinguishedName};
+use x509::name::{Name, NameBuilder, RdnSequence, RelativeDistinguishedName};
 
 #[test]
 fn decode_name() {
@@ -334,3 +334,48 @@ fn rdns_serde() {
         }
     }
 }
+
+#[test]
+fn build_name() {
+    let der = NameBuilder::new()
+        .country("US")
+        .organization("Test Certificates 2011")
+        .common_name("Good CA")
+        .build()
+        .unwrap()
+        .to_vec()
+        .unwrap();
+
+    // Matches the encoding in `decode_name`
+    assert_eq!(
+        der,
+        hex!("3040310B3009060355040613025553311F301D060355040A1316546573742043657274696669636174657320323031313110300E06035504031307476F6F64204341")
+    );
+}
+
+#[test]
+fn build_name_string_types() {
+    let builder = NameBuilder::new()
+        .domain_component("com")
+        .common_name("caf\u{e9}")
+        .and(const_oid::db::rfc4519::UID, "jdoe");
+
+    let name = builder.build().unwrap();
+    assert_eq!(Name::from_der(&name.to_vec().unwrap()).unwrap(), name);
+    assert_eq!(name.0.len(), 2);
+
+    let dc = name.0[0].0.get(0).unwrap();
+    assert_eq!(dc.value.tag(), Tag::Ia5String);
+
+    let atvs = name.0[1].0.iter().collect::<Vec<_>>();
+    assert_eq!(atvs.len(), 2);
+    assert_eq!(atvs[0].value.tag(), Tag::Utf8String);
+    assert_eq!(atvs[1].value.tag(), Tag::PrintableString);
+    assert_eq!(name.to_string(), "DC=com,CN=café+UID=jdoe");
+}
+
+#[test]
+fn build_name_invalid_country() {
+    assert!(NameBuilder::new().country("USA").build().is_err());
+    assert!(NameBuilder::new().country("\u{e9}\u{e9}").build().is_err());
+}
@@ -5,12 +5,13 @@ use hex_literal::hex;
 use x509::ext::pkix::crl::dp::{DistributionPoint, ReasonFlags, Reasons};
 use x509::ext::pkix::name::{DistributionPointName, GeneralName, GeneralNames};
 use x509::ext::pkix::*;
-use x509::ext::Extensions;
+use x509::ext::{Extension, Extensions, TypedExtension};
 use x509::name::Name;
 use x509::{Certificate, Version};
 
 use const_oid::db::rfc5280::*;
 use const_oid::db::rfc5912::ID_CE_CERTIFICATE_POLICIES;
+use const_oid::ObjectIdentifier;
 
 fn spin_over_exts<'a>(exts: Extensions<'a>) {
     let i = exts.iter();
@@ -1195,11 +1196,20 @@ fn decode_idp() {
     let err = idp.err().unwrap();
     assert_eq!(ErrorKind::Noncanonical { tag: Tag::Boolean }, err.kind());
 
-    // Tag on second RDN in first name is TeletexString (20) instead of PrintableString (19) (and TeletexString is not supported)
+    // Tag on second RDN in first name is TeletexString (20) instead of PrintableString (19).
+    // Attribute values are decoded as `Any`, so legacy string types are retained verbatim.
     let idp =
-        IssuingDistributionPoint::from_der(&hex!("30820168A0820161A082015DA4753073310B3009060355040613025553311F301D060355040A14165465737420436572746966696361746573203230313731183016060355040B130F696E64697265637443524C204341353129302706035504031320696E6469726563742043524C20666F7220696E64697265637443524C20434136A4753073310B3009060355040613025553311F301D060355040A13165465737420436572746966696361746573203230313731183016060355040B130F696E64697265637443524C204341353129302706035504031320696E6469726563742043524C20666F7220696E64697265637443524C20434137A46D306B310B3009060355040613025553311F301D060355040A13165465737420436572746966696361746573203230313731183016060355040B130F696E64697265637443524C204341353121301F0603550403131843524C3120666F7220696E64697265637443524C204341358401FF"));
-    let err = idp.err().unwrap();
-    assert_eq!(ErrorKind::TagUnknown { byte: 20u8.into() }, err.kind());
+        IssuingDistributionPoint::from_der(&hex!("30820168A0820161A082015DA4753073310B3009060355040613025553311F301D060355040A14165465737420436572746966696361746573203230313731183016060355040B130F696E64697265637443524C204341353129302706035504031320696E6469726563742043524C20666F7220696E64697265637443524C20434136A4753073310B3009060355040613025553311F301D060355040A13165465737420436572746966696361746573203230313731183016060355040B130F696E64697265637443524C204341353129302706035504031320696E6469726563742043524C20666F7220696E64697265637443524C20434137A46D306B310B3009060355040613025553311F301D060355040A13165465737420436572746966696361746573203230313731183016060355040B130F696E64697265637443524C204341353121301F0603550403131843524C3120666F7220696E64697265637443524C204341358401FF")).unwrap();
+    match idp.distribution_point.unwrap() {
+        DistributionPointName::FullName(gns) => match gns.get(0).unwrap() {
+            GeneralName::DirectoryName(name) => {
+                let atav = name.0[1].0.get(0).unwrap();
+                assert_eq!(atav.value.tag(), Tag::TeletexString);
+            }
+            _ => panic!("expected a directory name"),
+        },
+        _ => panic!("expected a full name"),
+    }
 
     // Length on second RDN in first name indicates more bytes than are present
     let idp =
@@ -1212,3 +1222,62 @@ fn decode_idp() {
         err.kind()
     );
 }
+
+#[test]
+fn extensions_iter() {
+    let der_encoded_cert = include_bytes!("examples/GoodCACert.crt");
+    let mut cert = Certificate::from_der(der_encoded_cert).unwrap();
+
+    let mut count = 0;
+    for ext in cert.tbs_certificate.extensions_iter() {
+        let (oid, _critical, value, typed) = ext.unwrap();
+        let typed = typed.unwrap();
+        assert_eq!(oid, typed.extn_id());
+
+        match typed {
+            TypedExtension::AuthorityKeyIdentifier(akid) => {
+                assert_eq!(value, akid.to_vec().unwrap())
+            }
+            TypedExtension::SubjectKeyIdentifier(skid) => {
+                assert_eq!(value, skid.to_vec().unwrap())
+            }
+            TypedExtension::KeyUsage(ku) => assert_eq!(value, ku.to_vec().unwrap()),
+            TypedExtension::CertificatePolicies(cp) => assert_eq!(value, cp.to_vec().unwrap()),
+            TypedExtension::BasicConstraints(bc) => assert!(bc.ca),
+            other => panic!("unexpected extension: {:?}", other),
+        }
+
+        count += 1;
+    }
+    assert_eq!(count, 5);
+
+    // Unknown extensions are passed through undecoded
+    let unknown_oid = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.99999.1");
+    let exts = cert.tbs_certificate.extensions.as_mut().unwrap();
+    exts.push(Extension {
+        extn_id: unknown_oid,
+        critical: true,
+        extn_value: &[0x05, 0x00],
+    });
+
+    let unknown_critical: Vec<_> = cert
+        .tbs_certificate
+        .extensions_iter()
+        .map(Result::unwrap)
+        .filter(|(_, critical, _, typed)| *critical && typed.is_none())
+        .map(|(oid, _, value, _)| (oid, value))
+        .collect();
+    assert_eq!(unknown_critical, [(unknown_oid, &[0x05u8, 0x00][..])]);
+
+    // Known extensions must decode successfully
+    let exts = cert.tbs_certificate.extensions.as_mut().unwrap();
+    exts.push(Extension {
+        extn_id: ID_CE_BASIC_CONSTRAINTS,
+        critical: true,
+        extn_value: &[0x05, 0x00],
+    });
+    assert!(cert
+        .tbs_certificate
+        .extensions_iter()
+        .any(|ext| ext.is_err()));
+}
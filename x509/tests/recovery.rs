@@ -0,0 +1,74 @@
+//! Certificate recovery tests
+
+use der::{Decodable, ErrorKind};
+use x509::{Certificate, RecoveredCertificate};
+
+const CERT_DER: &[u8] = include_bytes!("examples/GoodCACert.crt");
+
+/// Re-encode `CERT_DER`'s outermost `SEQUENCE` with the given length bytes.
+fn with_outer_length(length: &[u8], trailer: &[u8]) -> Vec<u8> {
+    // Skip the tag and the 3-byte DER length (`82 XX XX`)
+    let content = &CERT_DER[4..];
+
+    let mut ber = vec![0x30];
+    ber.extend_from_slice(length);
+    ber.extend_from_slice(content);
+    ber.extend_from_slice(trailer);
+    ber
+}
+
+#[test]
+fn recover_der() {
+    let recovered = RecoveredCertificate::from_ber(CERT_DER).unwrap();
+    assert!(recovered.is_canonical());
+    assert_eq!(recovered.as_der(), CERT_DER);
+    assert_eq!(
+        recovered.certificate().unwrap(),
+        Certificate::from_der(CERT_DER).unwrap()
+    );
+}
+
+#[test]
+fn recover_indefinite_length() {
+    let ber = with_outer_length(&[0x80], &[0, 0]);
+    assert!(Certificate::from_der(&ber).is_err());
+
+    let recovered = RecoveredCertificate::from_ber(&ber).unwrap();
+    assert!(!recovered.is_canonical());
+    assert_eq!(recovered.as_der(), CERT_DER);
+}
+
+#[test]
+fn recover_non_minimal_length() {
+    let len = (CERT_DER.len() - 4) as u32;
+    let mut length = vec![0x84];
+    length.extend_from_slice(&len.to_be_bytes());
+
+    let ber = with_outer_length(&length, &[]);
+    assert!(Certificate::from_der(&ber).is_err());
+
+    let recovered = RecoveredCertificate::from_ber(&ber).unwrap();
+    assert!(!recovered.is_canonical());
+    assert_eq!(recovered.as_der(), CERT_DER);
+}
+
+#[test]
+fn reject_malformed() {
+    // Missing end-of-contents octets
+    let ber = with_outer_length(&[0x80], &[]);
+    assert!(matches!(
+        RecoveredCertificate::from_ber(&ber).unwrap_err().kind(),
+        ErrorKind::Incomplete { .. }
+    ));
+
+    // Indefinite length primitive value
+    assert!(RecoveredCertificate::from_ber(&[0x04, 0x80, 0x00, 0x00]).is_err());
+
+    // Trailing data
+    let mut der = CERT_DER.to_vec();
+    der.push(0);
+    assert!(matches!(
+        RecoveredCertificate::from_ber(&der).unwrap_err().kind(),
+        ErrorKind::TrailingData { .. }
+    ));
+}
@@ -0,0 +1,66 @@
+//! Certificate serial number tests
+use der::{asn1::UIntBytes, Decodable};
+use hex_literal::hex;
+use x509::{Certificate, SerialNumber};
+
+#[cfg(feature = "rand_core")]
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[test]
+fn serial_number_new() {
+    let serial = SerialNumber::new(&hex!("00 00 01 02 03")).unwrap();
+    assert_eq!(&hex!("01 02 03"), serial.as_bytes());
+    assert_eq!(&hex!("01 02 03"), serial.as_uint_bytes().as_bytes());
+
+    // 20 octets with the high bit clear
+    let max = [0x7f; 20];
+    assert_eq!(&max, SerialNumber::new(&max).unwrap().as_bytes());
+
+    // Zero
+    assert!(SerialNumber::new(&[]).is_err());
+    assert!(SerialNumber::new(&[0, 0]).is_err());
+
+    // 21 octets when encoded
+    assert!(SerialNumber::new(&[0x80; 20]).is_err());
+    assert!(SerialNumber::new(&[0x01; 21]).is_err());
+}
+
+#[test]
+fn serial_number_ordering() {
+    let a = SerialNumber::new(&hex!("ff")).unwrap();
+    let b = SerialNumber::new(&hex!("01 00")).unwrap();
+    assert!(a < b);
+}
+
+#[test]
+fn serial_number_from_certificate() {
+    let der_encoded_cert = include_bytes!("examples/GoodCACert.crt");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    let serial = SerialNumber::try_from(cert.tbs_certificate.serial_number).unwrap();
+    assert_eq!(cert.tbs_certificate.serial_number, serial.as_uint_bytes());
+
+    let too_long = UIntBytes::new(&[0xff; 20]).unwrap();
+    assert!(SerialNumber::try_from(too_long).is_err());
+}
+
+#[cfg(feature = "rand_core")]
+#[test]
+fn serial_number_generate() {
+    for len in SerialNumber::MIN_RANDOM_LEN..=SerialNumber::MAX_LEN {
+        let serial = SerialNumber::generate(ChaCha8Rng::seed_from_u64(42), len).unwrap();
+        assert!(serial.as_bytes().len() <= len);
+        assert!(serial.as_bytes()[0] != 0);
+        assert_eq!(serial, SerialNumber::new(serial.as_bytes()).unwrap());
+    }
+
+    // Deterministic given the same seed
+    let a = SerialNumber::generate(ChaCha8Rng::seed_from_u64(1), 16).unwrap();
+    let b = SerialNumber::generate(ChaCha8Rng::seed_from_u64(1), 16).unwrap();
+    let c = SerialNumber::generate(ChaCha8Rng::seed_from_u64(2), 16).unwrap();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    // Insufficient entropy / too long
+    assert!(SerialNumber::generate(ChaCha8Rng::seed_from_u64(42), 8).is_err());
+    assert!(SerialNumber::generate(ChaCha8Rng::seed_from_u64(42), 21).is_err());
+}
@@ -0,0 +1,112 @@
+//! Certificate Transparency log entry tests
+use der::{Decodable, Encodable, ErrorKind};
+use x509::ext::Extension;
+use x509::transparency::{LogEntry, MerkleTreeLeaf, TimestampedEntry, CT_PRECERT_POISON};
+use x509::Certificate;
+
+#[cfg(feature = "digest")]
+use {hex_literal::hex, sha2::Sha256};
+
+const TIMESTAMP: u64 = 1_600_000_000_000;
+
+#[test]
+fn x509_leaf() {
+    let der_encoded_cert = include_bytes!("examples/eca.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+
+    let leaf = MerkleTreeLeaf::from(TimestampedEntry {
+        timestamp: TIMESTAMP,
+        entry: LogEntry::x509(&cert).unwrap(),
+        extensions: Vec::new(),
+    });
+    let encoded = leaf.to_vec().unwrap();
+
+    // version, leaf_type, timestamp, entry_type, ASN.1Cert length
+    assert_eq!(
+        &encoded[..15],
+        &[0, 0, 0, 0, 0x01, 0x74, 0x87, 0x6e, 0x80, 0x00, 0, 0, 0, 0x03, 0x5b]
+    );
+    assert_eq!(&encoded[15..(15 + 859)], &der_encoded_cert[..]);
+
+    // empty CtExtensions
+    assert_eq!(&encoded[(15 + 859)..], &[0, 0]);
+}
+
+#[test]
+fn precert_strips_poison() {
+    let der_encoded_cert = include_bytes!("examples/eca.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    let expected_tbs = cert.tbs_certificate.to_vec().unwrap();
+
+    let mut precert = cert.tbs_certificate.clone();
+    precert.extensions.as_mut().unwrap().push(Extension {
+        extn_id: CT_PRECERT_POISON,
+        critical: true,
+        extn_value: &[0x05, 0x00],
+    });
+
+    let entry = LogEntry::precert([0x42; 32], &precert).unwrap();
+    assert_eq!(entry.entry_type(), 1);
+    assert_eq!(
+        entry,
+        LogEntry::Precert {
+            issuer_key_hash: [0x42; 32],
+            tbs_certificate: expected_tbs.clone(),
+        }
+    );
+
+    let encoded = TimestampedEntry {
+        timestamp: TIMESTAMP,
+        entry,
+        extensions: Vec::new(),
+    }
+    .to_vec()
+    .unwrap();
+
+    assert_eq!(&encoded[8..10], &[0, 1]);
+    assert_eq!(&encoded[10..42], &[0x42; 32]);
+    let len = expected_tbs.len();
+    assert_eq!(&encoded[42..45], &(len as u32).to_be_bytes()[1..]);
+    assert_eq!(&encoded[45..(45 + len)], &expected_tbs[..]);
+}
+
+/// Expected value computed with
+/// `sha256(0x00 || MerkleTreeLeaf)` over the encoding checked in `x509_leaf`
+#[cfg(feature = "digest")]
+#[test]
+fn leaf_hash() {
+    let der_encoded_cert = include_bytes!("examples/eca.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+
+    let leaf = MerkleTreeLeaf::from(TimestampedEntry {
+        timestamp: TIMESTAMP,
+        entry: LogEntry::x509(&cert).unwrap(),
+        extensions: Vec::new(),
+    });
+
+    assert_eq!(
+        leaf.leaf_hash::<Sha256>().unwrap()[..],
+        hex!("602add954fe213e49d6f6fedbde4f02d7d5e293e6168f7b96f49b376d5b7cb2d")
+    );
+}
+
+#[test]
+fn reject_oversized_extensions() {
+    let der_encoded_cert = include_bytes!("examples/eca.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    let mut entry = TimestampedEntry {
+        timestamp: 1,
+        entry: LogEntry::x509(&cert).unwrap(),
+        extensions: vec![0; 0xFFFF],
+    };
+    assert_eq!(
+        entry.to_vec().unwrap().len(),
+        8 + 2 + 3 + der_encoded_cert.len() + 2 + 0xFFFF
+    );
+
+    entry.extensions.push(0);
+    assert_eq!(
+        entry.to_vec().err().map(|e| e.kind()),
+        Some(ErrorKind::Overflow)
+    );
+}